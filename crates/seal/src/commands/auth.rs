@@ -0,0 +1,60 @@
+use std::fmt::Write as _;
+
+use anyhow::Result;
+use seal_forge::RemoteRepo;
+use seal_project::{DEFAULT_REMOTE, ForgeKind, ProjectWorkspace, ReleaseConfig};
+
+use crate::ExitStatus;
+use crate::commands::forge::{forge_kind, github_client, github_token_command};
+use crate::printer::Printer;
+
+/// Report the GitHub token for the repository behind `remote`, where it was found, and its
+/// scopes.
+pub async fn auth_status(remote: Option<&str>, printer: Printer) -> Result<ExitStatus> {
+    let mut stdout = printer.stdout_important();
+
+    let workspace = ProjectWorkspace::discover()?;
+    let config = workspace.config();
+
+    let remote = remote
+        .or_else(|| config.release.as_ref().map(ReleaseConfig::remote))
+        .unwrap_or(DEFAULT_REMOTE);
+    let repository = seal_git::open(workspace.root())?;
+    let remote_repo = RemoteRepo::parse(&repository.remote_url(remote)?)?;
+
+    let kind = forge_kind(config, &remote_repo)?;
+    if kind != ForgeKind::GitHub {
+        anyhow::bail!(
+            "`seal auth status` only supports GitHub, but Git remote `{remote}` is hosted on {kind}"
+        );
+    }
+
+    let host = remote_repo.host.clone();
+    let Some(token) = seal_github::discover_token(&host, github_token_command(config))? else {
+        writeln!(stdout, "Not logged in to {host}")?;
+        writeln!(
+            printer.stdout(),
+            "Set {}, log in with `gh auth login`, or set `github.token-command`",
+            seal_github::token_env_vars(&host).join(" or ")
+        )?;
+        return Ok(ExitStatus::Failure);
+    };
+
+    let credentials = seal_github::Credentials::Token(token.secret().to_string());
+    let info = github_client(config, remote_repo, credentials, None)
+        .token_info()
+        .await?;
+
+    writeln!(stdout, "Logged in to {host} as {}", info.login)?;
+    writeln!(stdout, "Token source: {}", token.source())?;
+    match info.scopes {
+        Some(scopes) if scopes.is_empty() => writeln!(stdout, "Token scopes: none")?,
+        Some(scopes) => writeln!(stdout, "Token scopes: {}", scopes.join(", "))?,
+        None => writeln!(
+            stdout,
+            "Token scopes: not reported; fine-grained and GitHub App tokens have permissions instead"
+        )?,
+    }
+
+    Ok(ExitStatus::Success)
+}
@@ -1,26 +1,127 @@
 use std::fmt::Write as _;
 use std::io;
-use std::sync::Arc;
+use std::time::Duration;
 
-use anyhow::{Context, Result};
+use anyhow::{Context, Result, bail};
 use seal_bump::{VersionBump, calculate_version_file_changes};
 use seal_command::CommandWrapper;
+use seal_forge::PullRequestOptions;
 use seal_fs::FileResolver;
-use seal_github::{GitHubPullRequestOptions, GitHubService};
-use seal_project::{PreCommitFailure, ProjectWorkspace, get_current_branch};
+use seal_git::{CommitOptions, Repository, Signature};
+use seal_project::{
+    ChangelogConfig, CommitIdentity, CommitSigning, PreCommitFailure, ProjectWorkspace,
+    ReleaseCommand, ReleaseConfig, ReleaseHook, ReleaseHooksConfig,
+};
 
 use seal_cli::BumpArgs;
 
 use crate::ExitStatus;
+use crate::commands::forge::forge_client;
 use crate::printer::Printer;
+use crate::settings::ForgeSettings;
 
-/// A command with metadata about whether it's a pre-commit command.
+/// A command with the behavior to apply when it fails.
 struct TaggedCommand {
     command: CommandWrapper,
-    is_pre_commit: bool,
+    on_failure: PreCommitFailure,
 }
 
-pub async fn bump(args: &BumpArgs, printer: Printer) -> Result<ExitStatus> {
+impl TaggedCommand {
+    fn run(
+        &self,
+        stdout: &mut dyn std::fmt::Write,
+        printer: Printer,
+        root: &std::path::Path,
+    ) -> Result<()> {
+        match self.on_failure {
+            PreCommitFailure::Abort => self.command.execute(stdout, &mut printer.stderr(), root),
+            PreCommitFailure::Continue => {
+                let result =
+                    self.command
+                        .execute_with_result(stdout, &mut printer.stderr(), root)?;
+                if !result.success {
+                    let failure_info = result.failure_info(self.command.timeout());
+                    writeln!(
+                        stdout,
+                        "Warning: Command `{}` failed{failure_info}, continuing...",
+                        self.command.as_string()
+                    )?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// A Git operation performed as part of the release.
+enum GitStep {
+    CreateBranch(String),
+    StageAll,
+    Commit {
+        message: String,
+        options: CommitOptions,
+    },
+    Push {
+        remote: String,
+        branch: String,
+    },
+}
+
+impl GitStep {
+    /// The `git` command line equivalent to this step.
+    fn as_string(&self) -> String {
+        match self {
+            Self::CreateBranch(branch) => format!("git checkout -b {branch}"),
+            Self::StageAll => "git add -A".to_string(),
+            Self::Commit { message, options } => match &options.signing_format {
+                Some(format) => format!("git -c gpg.format={format} commit -S -m {message}"),
+                None => format!("git commit -m {message}"),
+            },
+            Self::Push { remote, branch } => format!("git push {remote} {branch}"),
+        }
+    }
+
+    fn run(&self, stdout: &mut dyn std::fmt::Write, repository: &dyn Repository) -> Result<()> {
+        writeln!(stdout, "Executing command: `{}`", self.as_string())?;
+        match self {
+            Self::CreateBranch(branch) => repository.create_branch(branch)?,
+            Self::StageAll => repository.stage_all()?,
+            Self::Commit { message, options } => repository.commit(message, options)?,
+            Self::Push { remote, branch } => repository.push(remote, branch)?,
+        }
+        Ok(())
+    }
+}
+
+/// A step of the release that runs after the files are updated.
+enum ReleaseStep {
+    Command(TaggedCommand),
+    /// A Git operation, which always aborts the release when it fails.
+    Git(GitStep),
+}
+
+impl ReleaseStep {
+    fn as_string(&self) -> String {
+        match self {
+            Self::Command(tagged) => tagged.command.as_string(),
+            Self::Git(step) => step.as_string(),
+        }
+    }
+
+    fn run(
+        &self,
+        stdout: &mut dyn std::fmt::Write,
+        printer: Printer,
+        repository: &dyn Repository,
+    ) -> Result<()> {
+        match self {
+            Self::Command(tagged) => tagged.run(stdout, printer, repository.root()),
+            Self::Git(step) => step.run(stdout, repository),
+        }
+    }
+}
+
+pub async fn bump(args: &BumpArgs, forge: &ForgeSettings, printer: Printer) -> Result<ExitStatus> {
     let mut stdout = printer.stdout();
 
     let version_bump: VersionBump = args
@@ -30,6 +131,7 @@ pub async fn bump(args: &BumpArgs, printer: Printer) -> Result<ExitStatus> {
 
     let workspace = ProjectWorkspace::discover()?;
     let config = workspace.config();
+    let repository = seal_git::open(workspace.root())?;
 
     let Some(release_config) = config.release.as_ref() else {
         return Err(anyhow::anyhow!(
@@ -39,6 +141,18 @@ pub async fn bump(args: &BumpArgs, printer: Printer) -> Result<ExitStatus> {
     };
 
     let current_version_string = &release_config.current_version;
+    let hooks = release_config.hooks.as_ref();
+
+    // `pre-bump` hooks check that a release can start, so they run before the new version is
+    // known, and a failing check stops the release before anything is computed or changed. A dry
+    // run lists them with the other commands instead.
+    let pre_bump_env = [("SEAL_PREVIOUS_VERSION", current_version_string.clone())];
+    let mut pre_bump_commands = hook_commands(hooks, ReleaseHook::PreBump, &pre_bump_env)?;
+    if !args.dry_run {
+        for tagged in std::mem::take(&mut pre_bump_commands) {
+            tagged.run(&mut stdout, printer, workspace.root())?;
+        }
+    }
 
     let new_version = seal_bump::calculate_new_version(current_version_string, &version_bump)?;
 
@@ -49,6 +163,11 @@ pub async fn bump(args: &BumpArgs, printer: Printer) -> Result<ExitStatus> {
         "Bumping version from {current_version_string} to {new_version_string}"
     )?;
 
+    let remote = args
+        .remote
+        .clone()
+        .unwrap_or_else(|| release_config.remote().to_string());
+
     let branch_name = release_config
         .branch_name
         .as_ref()
@@ -59,6 +178,21 @@ pub async fn bump(args: &BumpArgs, printer: Printer) -> Result<ExitStatus> {
         .as_ref()
         .map(|message| message.as_str().replace("{version}", &new_version_string));
 
+    if branch_name.is_some() || commit_message.is_some() {
+        let tag = config.changelog.as_ref().map_or_else(
+            || ChangelogConfig::default().tag(&new_version_string),
+            |changelog_config| changelog_config.tag(&new_version_string),
+        );
+        preflight_checks(
+            release_config,
+            repository.as_ref(),
+            &remote,
+            branch_name.as_deref(),
+            commit_message.as_deref(),
+            &tag,
+        )?;
+    }
+
     writeln!(stdout)?;
 
     let version_files = release_config.version_files.as_deref().unwrap_or(&[]);
@@ -69,20 +203,7 @@ pub async fn bump(args: &BumpArgs, printer: Printer) -> Result<ExitStatus> {
 
     let file_resolver = FileResolver::new(workspace.root().clone());
 
-    #[cfg(feature = "integration-test")]
-    let github_client: Arc<dyn GitHubService> = {
-        #[cfg(any(test, feature = "integration-test"))]
-        use seal_github::MockGithubClient;
-        Arc::new(MockGithubClient::new())
-    };
-    #[cfg(not(feature = "integration-test"))]
-    let github_client: Arc<dyn GitHubService> = {
-        use seal_github::{GitHubClient, get_git_remote_url, parse_github_repo};
-
-        let repo_url = get_git_remote_url(workspace.root())?;
-        let (owner, repo) = parse_github_repo(&repo_url)?;
-        Arc::new(GitHubClient::new(owner, repo)?)
-    };
+    let forge = forge_client(config, repository.as_ref(), &remote, forge)?;
 
     let mut file_changes = calculate_version_file_changes(
         workspace.root(),
@@ -99,7 +220,7 @@ pub async fn bump(args: &BumpArgs, printer: Printer) -> Result<ExitStatus> {
                 workspace.root(),
                 &new_version_string,
                 changelog_config,
-                &github_client,
+                &forge,
             )
             .await
             .context("Failed to prepare changelog")?;
@@ -125,10 +246,10 @@ pub async fn bump(args: &BumpArgs, printer: Printer) -> Result<ExitStatus> {
         let base = if let Some(base) = &pull_request_config.base {
             base.clone()
         } else {
-            get_current_branch(workspace.root())?
+            repository.current_branch()?
         };
 
-        Some(GitHubPullRequestOptions {
+        Some(PullRequestOptions {
             title: if let Some(title) = &pull_request_config.title {
                 title.replace("{version}", &new_version_string)
             } else {
@@ -163,61 +284,101 @@ pub async fn bump(args: &BumpArgs, printer: Printer) -> Result<ExitStatus> {
     for change in &file_changes {
         writeln!(
             stdout,
-            "  - Update `{}`",
+            "  - {} `{}`",
+            if change.is_deletion() {
+                "Delete"
+            } else {
+                "Update"
+            },
             file_resolver.relative_path(change.path()).display()
         )?;
     }
 
     writeln!(stdout)?;
 
-    let mut commands: Vec<TaggedCommand> = Vec::new();
+    let mut hook_env = vec![
+        ("SEAL_VERSION", new_version_string.clone()),
+        ("SEAL_PREVIOUS_VERSION", current_version_string.clone()),
+    ];
+    if let Some(branch) = &branch_name {
+        hook_env.push(("SEAL_BRANCH", branch.clone()));
+    }
+
+    let mut commands: Vec<_> = hook_commands(hooks, ReleaseHook::PostFiles, &hook_env)?
+        .into_iter()
+        .map(ReleaseStep::Command)
+        .collect();
 
     if let Some(branch) = &branch_name {
-        commands.push(TaggedCommand {
-            command: CommandWrapper::create_branch(branch),
-            is_pre_commit: false,
-        });
+        commands.push(ReleaseStep::Git(GitStep::CreateBranch(branch.clone())));
     }
 
     if let Some(message) = &commit_message {
-        commands.push(TaggedCommand {
-            command: CommandWrapper::git_add_all(),
-            is_pre_commit: false,
-        });
-
-        if let Some(pre_commit_cmds) = release_config.pre_commit_commands.as_ref() {
-            for cmd in pre_commit_cmds {
-                commands.push(TaggedCommand {
-                    command: CommandWrapper::custom(cmd),
-                    is_pre_commit: true,
-                });
-            }
-            commands.push(TaggedCommand {
-                command: CommandWrapper::git_add_all(),
-                is_pre_commit: false,
+        commands.push(ReleaseStep::Git(GitStep::StageAll));
+
+        let mut pre_commit_commands = Vec::new();
+        for cmd in release_config.pre_commit_commands.iter().flatten() {
+            pre_commit_commands.push(TaggedCommand {
+                command: with_env(release_command(cmd)?, &hook_env),
+                on_failure: release_config.on_pre_commit_failure,
             });
         }
+        pre_commit_commands.extend(hook_commands(hooks, ReleaseHook::PreCommit, &hook_env)?);
+
+        if !pre_commit_commands.is_empty() {
+            commands.extend(pre_commit_commands.into_iter().map(ReleaseStep::Command));
+            commands.push(ReleaseStep::Git(GitStep::StageAll));
+        }
 
-        commands.push(TaggedCommand {
-            command: CommandWrapper::git_commit(message),
-            is_pre_commit: false,
-        });
+        commands.push(ReleaseStep::Git(GitStep::Commit {
+            message: message.clone(),
+            options: commit_options(release_config),
+        }));
+        commands.extend(
+            hook_commands(hooks, ReleaseHook::PostCommit, &hook_env)?
+                .into_iter()
+                .map(ReleaseStep::Command),
+        );
     }
 
     if release_config.push {
         if let Some(branch) = &branch_name {
-            commands.push(TaggedCommand {
-                command: CommandWrapper::git_push_branch(branch),
-                is_pre_commit: false,
-            });
+            commands.push(ReleaseStep::Git(GitStep::Push {
+                remote: remote.clone(),
+                branch: branch.clone(),
+            }));
+            commands.extend(
+                hook_commands(hooks, ReleaseHook::PostPush, &hook_env)?
+                    .into_iter()
+                    .map(ReleaseStep::Command),
+            );
         }
     }
 
-    if !args.dry_run && !commands.is_empty() {
+    let post_pull_request_commands = if pull_request.is_some() {
+        hook_commands(hooks, ReleaseHook::PostPullRequest, &hook_env)?
+    } else {
+        Vec::new()
+    };
+
+    let has_commands = !pre_bump_commands.is_empty()
+        || !commands.is_empty()
+        || !post_pull_request_commands.is_empty();
+
+    if has_commands {
         writeln!(stdout, "Commands to be executed:")?;
 
-        for tagged in &commands {
-            writeln!(stdout, "  `{}`", tagged.command.as_string())?;
+        let command_lines = pre_bump_commands
+            .iter()
+            .map(|tagged| tagged.command.as_string())
+            .chain(commands.iter().map(ReleaseStep::as_string))
+            .chain(
+                post_pull_request_commands
+                    .iter()
+                    .map(|tagged| tagged.command.as_string()),
+            );
+        for command_line in command_lines {
+            writeln!(stdout, "  `{command_line}`")?;
         }
 
         writeln!(stdout)?;
@@ -259,42 +420,31 @@ pub async fn bump(args: &BumpArgs, printer: Printer) -> Result<ExitStatus> {
     }
 
     if pull_request.is_some() {
-        github_client.ensure_authenticated()?;
+        forge.ensure_authenticated()?;
     }
 
     writeln!(stdout, "Updating files...")?;
 
     file_changes.apply()?;
 
-    let on_failure = release_config.on_pre_commit_failure;
-
-    for tagged in &commands {
-        if tagged.is_pre_commit && on_failure == PreCommitFailure::Continue {
-            let result = tagged
-                .command
-                .execute_with_result(&mut stdout, workspace.root())?;
-            if !result.success {
-                let exit_info = result
-                    .exit_code
-                    .map(|code| format!(" (exit code {code})"))
-                    .unwrap_or_default();
-                writeln!(
-                    stdout,
-                    "Warning: Command `{}` failed{exit_info}, continuing...",
-                    tagged.command.as_string()
-                )?;
-            }
-        } else {
-            tagged.command.execute(&mut stdout, workspace.root())?;
-        }
+    for step in &commands {
+        step.run(&mut stdout, printer, repository.as_ref())?;
     }
 
     if let Some(pull_request) = pull_request {
-        let pull_request = github_client
+        let pull_request = forge
             .create_or_update_pull_request(pull_request)
             .await
-            .context("Failed to create or update GitHub pull request")?;
+            .context("Failed to create or update the release pull request")?;
         writeln!(stdout, "Pull request: {}", pull_request.url)?;
+
+        for tagged in post_pull_request_commands {
+            let tagged = TaggedCommand {
+                command: tagged.command.with_env("SEAL_PR_URL", &pull_request.url),
+                on_failure: tagged.on_failure,
+            };
+            tagged.run(&mut stdout, printer, workspace.root())?;
+        }
     }
 
     writeln!(stdout, "Successfully bumped to {new_version_string}")?;
@@ -302,6 +452,216 @@ pub async fn bump(args: &BumpArgs, printer: Printer) -> Result<ExitStatus> {
     Ok(ExitStatus::Success)
 }
 
+/// Check that the repository is in a state to start a release from, before anything is changed.
+///
+/// All failing checks are reported together, each with the setting that overrides it.
+fn preflight_checks(
+    release_config: &ReleaseConfig,
+    repository: &dyn Repository,
+    remote: &str,
+    branch_name: Option<&str>,
+    commit_message: Option<&str>,
+    tag: &str,
+) -> Result<()> {
+    let mut problems = Vec::new();
+
+    if !release_config.allow_dirty && repository.is_dirty()? {
+        problems.push(
+            "The working tree has uncommitted changes (set `release.allow-dirty = true` to release anyway)"
+                .to_string(),
+        );
+    }
+
+    if commit_message.is_some() {
+        match release_config.sign {
+            CommitSigning::Disabled => {}
+            CommitSigning::Gpg => {
+                if repository.config_value("user.signingkey")?.is_none()
+                    && !has_gpg_secret_key(repository)?
+                {
+                    problems.push(
+                        "`release.sign = \"gpg\"` requires `user.signingkey` or a GnuPG secret key"
+                            .to_string(),
+                    );
+                }
+            }
+            CommitSigning::Ssh => {
+                if repository.config_value("user.signingkey")?.is_none() {
+                    problems.push(
+                        "`release.sign = \"ssh\"` requires `user.signingkey` to be set to an SSH key"
+                            .to_string(),
+                    );
+                }
+            }
+        }
+    }
+
+    let has_remote = repository.remote_exists(remote)?;
+    if (release_config.push || release_config.pull_request.is_some()) && !has_remote {
+        problems.push(format!(
+            "Git remote `{remote}` does not exist (add it with `git remote add` or set `release.remote`)"
+        ));
+    }
+
+    if release_config.allow_branches.is_some() {
+        match repository.current_branch() {
+            Ok(current_branch) if !release_config.allows_branch(&current_branch) => {
+                problems.push(format!(
+                    "Current branch `{current_branch}` does not match `release.allow-branches`"
+                ));
+            }
+            Ok(_) => {}
+            Err(_) => problems
+                .push("HEAD is not on a branch, but `release.allow-branches` is set".to_string()),
+        }
+    }
+
+    if let Some(branch) = branch_name
+        && !release_config.allow_existing_branch
+    {
+        if repository.branch_exists(branch)? {
+            problems.push(format!(
+                "Release branch `{branch}` already exists locally (set `release.allow-existing-branch = true` to release anyway)"
+            ));
+        } else if release_config.push
+            && has_remote
+            && repository.remote_branch_exists(remote, branch)?
+        {
+            problems.push(format!(
+                "Release branch `{branch}` already exists on `{remote}` (set `release.allow-existing-branch = true` to release anyway)"
+            ));
+        }
+    }
+
+    if !release_config.allow_existing_tag && repository.tag_exists(tag)? {
+        problems.push(format!(
+            "Tag `{tag}` already exists (set `release.allow-existing-tag = true` to release anyway)"
+        ));
+    }
+
+    // The upstream is compared as of the last fetch, since fetching could need credentials.
+    if !release_config.allow_behind
+        && let Ok(current_branch) = repository.current_branch()
+        && let Some(upstream) = repository.upstream(&current_branch)?
+        && repository.reference_exists(&upstream)?
+    {
+        let behind = repository.commits_behind(&upstream)?;
+        if behind > 0 {
+            let upstream = upstream.strip_prefix("refs/remotes/").unwrap_or(&upstream);
+            problems.push(format!(
+                "The current branch is {behind} commit(s) behind `{upstream}` as of the last fetch (set `release.allow-behind = true` to release anyway)"
+            ));
+        }
+    }
+
+    if problems.is_empty() {
+        return Ok(());
+    }
+
+    let mut message = "Pre-flight checks failed:".to_string();
+    for problem in problems {
+        write!(message, "\n  - {problem}")?;
+    }
+    bail!(message)
+}
+
+/// The signing and identity to create the release commit with.
+fn commit_options(release_config: &ReleaseConfig) -> CommitOptions {
+    let signature = |identity: &CommitIdentity| Signature {
+        name: identity.name.clone(),
+        email: identity.email.clone(),
+    };
+
+    CommitOptions {
+        signing_format: release_config.sign.git_format().map(ToString::to_string),
+        author: release_config.author.as_ref().map(signature),
+        committer: release_config
+            .committer
+            .as_ref()
+            .or(release_config.author.as_ref())
+            .map(signature),
+    }
+}
+
+/// Whether the `gpg` program Git signs with has any secret keys.
+fn has_gpg_secret_key(repository: &dyn Repository) -> Result<bool> {
+    let program = repository
+        .config_value("gpg.program")?
+        .unwrap_or_else(|| "gpg".to_string());
+
+    let Ok(output) = std::process::Command::new(&program)
+        .args(["--list-secret-keys", "--with-colons"])
+        .current_dir(repository.root())
+        .output()
+    else {
+        return Ok(false);
+    };
+
+    Ok(output.status.success()
+        && String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .any(|line| line.starts_with("sec:")))
+}
+
+/// Build the commands configured for a release hook.
+fn hook_commands(
+    hooks: Option<&ReleaseHooksConfig>,
+    hook: ReleaseHook,
+    env: &[(&str, String)],
+) -> Result<Vec<TaggedCommand>> {
+    let Some(hooks) = hooks else {
+        return Ok(Vec::new());
+    };
+
+    hooks
+        .commands(hook)
+        .iter()
+        .map(|command| {
+            Ok(TaggedCommand {
+                command: with_env(release_command(command)?, env),
+                on_failure: hooks.on_failure(hook),
+            })
+        })
+        .collect()
+}
+
+fn with_env(command: CommandWrapper, env: &[(&str, String)]) -> CommandWrapper {
+    env.iter().fold(command, |command, (key, value)| {
+        command.with_env(*key, value)
+    })
+}
+
+/// Build the command to run for a configured release command.
+fn release_command(command: &ReleaseCommand) -> Result<CommandWrapper> {
+    let wrapper = match command {
+        ReleaseCommand::Simple(command) => CommandWrapper::custom(command)?,
+        ReleaseCommand::Detailed(options) => {
+            let mut wrapper = if options.shell {
+                CommandWrapper::shell(&options.command)
+            } else if let Some(args) = &options.args {
+                let mut command_with_args = vec![options.command.clone()];
+                command_with_args.extend(args.iter().cloned());
+                CommandWrapper::new(command_with_args)
+            } else {
+                CommandWrapper::custom(&options.command)?
+            };
+
+            if let Some(cwd) = &options.cwd {
+                wrapper = wrapper.with_current_directory(cwd);
+            }
+            for (key, value) in &options.env {
+                wrapper = wrapper.with_env(key, value);
+            }
+            if let Some(timeout) = options.timeout {
+                wrapper = wrapper.with_timeout(Duration::from_secs(timeout));
+            }
+            wrapper
+        }
+    };
+
+    Ok(wrapper.with_streamed_output())
+}
+
 fn confirm_changes(stdout: &mut impl std::fmt::Write) -> Result<bool> {
     write!(stdout, "Proceed with these changes? (y/n):")?;
 
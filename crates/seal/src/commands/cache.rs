@@ -0,0 +1,63 @@
+use std::fmt::Write as _;
+
+use anyhow::{Context, Result};
+use seal_cache::Cache;
+
+use crate::ExitStatus;
+use crate::printer::Printer;
+use crate::settings::CacheSettings;
+
+/// Remove every entry in the cache.
+pub fn cache_clean(settings: &CacheSettings, printer: Printer) -> Result<ExitStatus> {
+    let cache = Cache::from_settings(settings.cache_dir.clone())?;
+
+    if !cache.root().exists() {
+        writeln!(
+            printer.stdout_important(),
+            "No cache found at `{}`",
+            cache.root().display()
+        )?;
+        return Ok(ExitStatus::Success);
+    }
+
+    writeln!(
+        printer.stdout(),
+        "Clearing cache at `{}`",
+        cache.root().display()
+    )?;
+    let removal = cache
+        .clear()
+        .with_context(|| format!("Failed to clear cache at `{}`", cache.root().display()))?;
+
+    let files = if removal.num_files == 1 {
+        "file"
+    } else {
+        "files"
+    };
+    writeln!(
+        printer.stdout_important(),
+        "Removed {} {files} ({})",
+        removal.num_files,
+        human_readable_bytes(removal.total_bytes)
+    )?;
+    Ok(ExitStatus::Success)
+}
+
+/// Format a byte count with a binary unit, e.g. `1.5KiB`.
+#[allow(clippy::cast_precision_loss)]
+fn human_readable_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 5] = ["B", "KiB", "MiB", "GiB", "TiB"];
+
+    let mut size = bytes as f64;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+
+    if unit == 0 {
+        format!("{bytes}{}", UNITS[0])
+    } else {
+        format!("{size:.1}{}", UNITS[unit])
+    }
+}
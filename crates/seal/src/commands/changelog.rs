@@ -0,0 +1,91 @@
+use std::fmt::Write as _;
+use std::io;
+
+use anyhow::{Context, Result, bail};
+use seal_fs::FileResolver;
+use seal_project::ProjectWorkspace;
+
+use crate::ExitStatus;
+use crate::printer::Printer;
+
+/// Write a changelog fragment, prompting for any value that was not passed.
+pub fn changelog_add(
+    number: Option<u64>,
+    kind: Option<String>,
+    message: Option<String>,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let mut stdout = printer.stdout();
+
+    let workspace = ProjectWorkspace::discover()?;
+    let config = workspace.config();
+
+    let Some(changelog_config) = config.changelog.as_ref() else {
+        bail!(
+            "No changelog configuration found in discovered workspace at `{}`",
+            workspace.root().display()
+        );
+    };
+    let Some(fragments_dir) = changelog_config.fragments_dir.as_ref() else {
+        bail!("`changelog.fragments-dir` must be set to add changelog fragments");
+    };
+
+    let types = seal_changelog::fragment_types(changelog_config);
+    if types.is_empty() {
+        bail!("`changelog.section-labels` must map at least one label to use as a fragment type");
+    }
+
+    let number = match number {
+        Some(number) => number,
+        None => prompt(&mut stdout, "Pull request number")?
+            .parse()
+            .context("The pull request number must be a positive integer")?,
+    };
+
+    let kind = match kind {
+        Some(kind) => kind,
+        None => prompt(&mut stdout, &format!("Type ({})", types.join(", ")))?,
+    };
+    if !types.contains(&kind.as_str()) {
+        bail!(
+            "Unknown fragment type `{kind}`, expected one of: {}",
+            types.join(", ")
+        );
+    }
+
+    let message = match message {
+        Some(message) => message,
+        None => prompt(&mut stdout, "Changelog entry")?,
+    };
+    if message.trim().is_empty() {
+        bail!("The changelog entry must not be empty");
+    }
+
+    let path = seal_changelog::add_fragment(
+        &workspace.root().join(fragments_dir),
+        number,
+        &kind,
+        &message,
+    )?;
+
+    let file_resolver = FileResolver::new(workspace.root().clone());
+    writeln!(
+        printer.stdout_important(),
+        "Created `{}`",
+        file_resolver.relative_path(&path).display()
+    )?;
+
+    Ok(ExitStatus::Success)
+}
+
+/// Ask for a value on stdin.
+fn prompt(stdout: &mut impl std::fmt::Write, question: &str) -> Result<String> {
+    write!(stdout, "{question}: ")?;
+
+    io::Write::flush(&mut io::stdout())?;
+
+    let mut input = String::new();
+    io::stdin().read_line(&mut input)?;
+
+    Ok(input.trim().to_string())
+}
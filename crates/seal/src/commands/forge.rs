@@ -0,0 +1,185 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use seal_cache::Cache;
+use seal_forge::{ForgeService, RecordingClient, RemoteRepo, ReplayClient};
+use seal_git::Repository;
+use seal_project::{Config, ForgeKind};
+
+use crate::settings::{ForgeFixtures, ForgeSettings};
+
+/// Create a client for the forge hosting the repository behind `remote`.
+///
+/// A replayed fixtures file stands in for the forge entirely, without resolving the remote.
+pub(crate) fn forge_client(
+    config: &Config,
+    repository: &dyn Repository,
+    remote: &str,
+    settings: &ForgeSettings,
+) -> Result<Arc<dyn ForgeService>> {
+    match &settings.fixtures {
+        Some(ForgeFixtures::Replay(path)) => {
+            tracing::debug!("Replaying forge fixtures from `{}`", path.display());
+            Ok(Arc::new(ReplayClient::new(path)?))
+        }
+        Some(ForgeFixtures::Record(path)) => {
+            tracing::debug!("Recording forge fixtures to `{}`", path.display());
+            let client = remote_forge_client(config, repository, remote, settings.cache.clone())?;
+            Ok(Arc::new(RecordingClient::new(client, path)))
+        }
+        None => remote_forge_client(config, repository, remote, settings.cache.clone()),
+    }
+}
+
+#[cfg(feature = "integration-test")]
+#[allow(clippy::unnecessary_wraps)]
+fn remote_forge_client(
+    _config: &Config,
+    _repository: &dyn Repository,
+    _remote: &str,
+    _cache: Option<Cache>,
+) -> Result<Arc<dyn ForgeService>> {
+    Ok(Arc::new(seal_github::MockGithubClient::new()))
+}
+
+/// Create a client for the forge API, storing GitHub API responses in `cache`, if any.
+#[cfg(not(feature = "integration-test"))]
+fn remote_forge_client(
+    config: &Config,
+    repository: &dyn Repository,
+    remote: &str,
+    cache: Option<Cache>,
+) -> Result<Arc<dyn ForgeService>> {
+    let repo_url = repository.remote_url(remote)?;
+    let remote_repo = RemoteRepo::parse(&repo_url)?;
+    let kind = forge_kind(config, &remote_repo)?;
+    tracing::debug!("Using {kind} for Git remote `{remote}`");
+
+    if remote_repo.has_nested_owner() && kind != ForgeKind::GitLab {
+        anyhow::bail!("{kind} repositories cannot be nested in groups, got `{repo_url}`");
+    }
+
+    let base_url = config
+        .forge
+        .as_ref()
+        .and_then(|forge| forge.url.clone())
+        .unwrap_or_else(|| remote_repo.web_url.clone());
+
+    Ok(match kind {
+        ForgeKind::GitHub => {
+            let credentials = seal_github::Credentials::Discover {
+                host: remote_repo.host.clone(),
+                token_command: github_token_command(config).map(ToString::to_string),
+            };
+            Arc::new(github_client(config, remote_repo, credentials, cache))
+        }
+        ForgeKind::GitLab => Arc::new(
+            seal_gitlab::GitLabClient::new(&base_url, &remote_repo.path())?
+                .with_base(pull_request_base(config))
+                .with_changed_files(matches_changed_files(config)),
+        ),
+        ForgeKind::Gitea => Arc::new(
+            seal_gitea::GiteaClient::new(&base_url, &remote_repo.owner, &remote_repo.name)?
+                .with_base(pull_request_base(config))
+                .with_changed_files(matches_changed_files(config)),
+        ),
+    })
+}
+
+/// The forge hosting `remote_repo`, from `forge.kind`, `github.api-url`, or the remote's host.
+///
+/// Remotes on unrecognized hosts are on GitHub when `GITHUB_API_URL` is set, as it is in GitHub
+/// Actions on GitHub Enterprise Server.
+pub(crate) fn forge_kind(config: &Config, remote_repo: &RemoteRepo) -> Result<ForgeKind> {
+    if let Some(kind) = config.forge.as_ref().and_then(|forge| forge.kind) {
+        Ok(kind)
+    } else if config
+        .github
+        .as_ref()
+        .is_some_and(|github| github.api_url.is_some())
+    {
+        Ok(ForgeKind::GitHub)
+    } else if let Some(kind) = detect_forge(&remote_repo.host) {
+        Ok(kind)
+    } else if seal_github::github_api_url_from_env().is_some() {
+        Ok(ForgeKind::GitHub)
+    } else {
+        anyhow::bail!(
+            "Could not detect the forge for host `{}`; set `forge.kind` in `seal.toml`",
+            remote_repo.host
+        )
+    }
+}
+
+/// The `github.token-command` setting, if any.
+pub(crate) fn github_token_command(config: &Config) -> Option<&str> {
+    config
+        .github
+        .as_ref()
+        .and_then(|github| github.token_command.as_deref())
+}
+
+/// Create a client for the GitHub repository `remote_repo`, authenticated with `credentials`.
+///
+/// Changelogs list the pull requests merged into the configured pull request base, or else into
+/// the repository's default branch.
+pub(crate) fn github_client(
+    config: &Config,
+    remote_repo: RemoteRepo,
+    credentials: seal_github::Credentials,
+    cache: Option<Cache>,
+) -> seal_github::GitHubClient {
+    let github = config.github.as_ref();
+    let api_url = seal_github::github_api_url(
+        &remote_repo.host,
+        github.and_then(|github| github.api_url.as_deref()),
+    );
+    let retry = github
+        .and_then(|github| github.max_retry_wait)
+        .map(|seconds| seal_github::RetryConfig::new(std::time::Duration::from_secs(seconds)))
+        .unwrap_or_default();
+
+    seal_github::GitHubClient::new(
+        &api_url,
+        remote_repo.owner,
+        remote_repo.name,
+        credentials,
+        retry,
+        cache,
+    )
+    .with_base(pull_request_base(config))
+}
+
+/// The `release.pull-request.base` setting, if any.
+fn pull_request_base(config: &Config) -> Option<String> {
+    config
+        .release
+        .as_ref()
+        .and_then(|release| release.pull_request.as_ref())
+        .and_then(|pull_request| pull_request.base.clone())
+}
+
+/// Whether changelog areas match pull requests by their changed files.
+///
+/// GitLab and Gitea list changed files with a request per pull request, so they are only read
+/// when an area has `paths`.
+#[cfg(not(feature = "integration-test"))]
+fn matches_changed_files(config: &Config) -> bool {
+    config
+        .changelog
+        .as_ref()
+        .is_some_and(|changelog| changelog.areas().iter().any(|area| !area.paths.is_empty()))
+}
+
+/// Detect the forge from the host of a remote repository.
+fn detect_forge(host: &str) -> Option<ForgeKind> {
+    if host == "github.com" || host.starts_with("github.") {
+        Some(ForgeKind::GitHub)
+    } else if host == "gitlab.com" || host.starts_with("gitlab.") {
+        Some(ForgeKind::GitLab)
+    } else if host == "codeberg.org" || host.starts_with("gitea.") || host.starts_with("forgejo.") {
+        Some(ForgeKind::Gitea)
+    } else {
+        None
+    }
+}
@@ -1,22 +1,21 @@
 use std::fmt::Write as _;
-use std::sync::Arc;
 
 use anyhow::Result;
 use seal_changelog::DEFAULT_CHANGELOG_PATH;
 use seal_fs::FileResolver;
-use seal_github::GitHubService;
-use seal_project::ProjectWorkspace;
+use seal_project::{DEFAULT_REMOTE, ProjectWorkspace, ReleaseConfig};
 
 use crate::ExitStatus;
+use crate::commands::forge::forge_client;
 use crate::printer::Printer;
-
-const MAX_PRS: usize = 100;
+use crate::settings::ForgeSettings;
 
 pub async fn generate_changelog(
     dry_run: bool,
     printer: Printer,
     overwrite: Option<bool>,
-    max_prs: Option<usize>,
+    remote: Option<&str>,
+    forge: &ForgeSettings,
 ) -> Result<ExitStatus> {
     let mut stdout = printer.stdout();
 
@@ -35,27 +34,16 @@ pub async fn generate_changelog(
         .clone()
         .unwrap_or_else(|| workspace.root().join(DEFAULT_CHANGELOG_PATH));
 
-    #[cfg(feature = "integration-test")]
-    let github_client: Arc<dyn GitHubService> = {
-        #[cfg(any(test, feature = "integration-test"))]
-        use seal_github::MockGithubClient;
-        Arc::new(MockGithubClient::new())
-    };
-    #[cfg(not(feature = "integration-test"))]
-    let github_client: Arc<dyn GitHubService> = {
-        use seal_github::{GitHubClient, get_git_remote_url, parse_github_repo};
+    let remote = remote
+        .or_else(|| config.release.as_ref().map(ReleaseConfig::remote))
+        .unwrap_or(DEFAULT_REMOTE);
+    tracing::debug!("Resolving the forge repository from Git remote `{remote}`");
 
-        let repo_url = get_git_remote_url(workspace.root())?;
-        let (owner, repo) = parse_github_repo(&repo_url)?;
-        Arc::new(GitHubClient::new(owner, repo)?)
-    };
+    let repository = seal_git::open(workspace.root())?;
+    let forge = forge_client(config, repository.as_ref(), remote, forge)?;
 
-    let changelog_content = seal_changelog::generate_full_changelog(
-        changelog_config,
-        &github_client,
-        max_prs.unwrap_or(MAX_PRS),
-    )
-    .await?;
+    let changelog_content =
+        seal_changelog::generate_full_changelog(workspace.root(), changelog_config, &forge).await?;
 
     let file_resolver = FileResolver::new(workspace.root().clone());
 
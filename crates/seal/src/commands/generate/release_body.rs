@@ -2,12 +2,18 @@ use std::fmt::Write as _;
 
 use anyhow::Result;
 use seal_changelog::DEFAULT_CHANGELOG_PATH;
+use seal_cli::ReleaseFormat;
 use seal_project::ProjectWorkspace;
 
 use crate::ExitStatus;
 use crate::printer::Printer;
 
-pub async fn generate_release_body(printer: Printer) -> Result<ExitStatus> {
+pub async fn generate_release_body(
+    version: Option<&str>,
+    all: bool,
+    output_format: ReleaseFormat,
+    printer: Printer,
+) -> Result<ExitStatus> {
     let mut stdout = printer.stdout();
 
     let workspace = ProjectWorkspace::discover()?;
@@ -24,10 +30,40 @@ pub async fn generate_release_body(printer: Printer) -> Result<ExitStatus> {
     }
 
     let changelog_content = fs_err::read_to_string(&changelog_path)?;
-    let release_body = seal_changelog::create_release_body(&changelog_content)?;
 
-    let json = serde_json::to_string_pretty(&release_body)?;
-    writeln!(stdout, "{json}")?;
+    if all {
+        let release_bodies = seal_changelog::create_all_release_bodies(&changelog_content)?;
+
+        match output_format {
+            ReleaseFormat::Json => {
+                writeln!(stdout, "{}", serde_json::to_string_pretty(&release_bodies)?)?;
+            }
+            ReleaseFormat::Markdown => {
+                let sections: Vec<String> = release_bodies
+                    .iter()
+                    .map(|release_body| {
+                        format!("## {}\n\n{}", release_body.title, release_body.body)
+                    })
+                    .collect();
+                writeln!(stdout, "{}", sections.join("\n\n"))?;
+            }
+        }
+
+        return Ok(ExitStatus::Success);
+    }
+
+    let release_body = if let Some(version) = version {
+        seal_changelog::create_release_body_for_version(&changelog_content, version)?
+    } else {
+        seal_changelog::create_release_body(&changelog_content)?
+    };
+
+    match output_format {
+        ReleaseFormat::Json => {
+            writeln!(stdout, "{}", serde_json::to_string_pretty(&release_body)?)?;
+        }
+        ReleaseFormat::Markdown => writeln!(stdout, "{}", release_body.body)?,
+    }
 
     Ok(ExitStatus::Success)
 }
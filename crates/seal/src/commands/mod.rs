@@ -1,11 +1,18 @@
+mod auth;
 mod bump;
+mod cache;
+mod changelog;
+mod forge;
 mod generate;
 mod help;
 mod seal_self;
 mod validate;
 
+pub use auth::auth_status;
 pub use bump::bump;
+pub use cache::cache_clean;
+pub use changelog::changelog_add;
 pub use generate::{generate_changelog, generate_release_body};
 pub use help::help;
 pub use seal_self::self_version;
-pub use validate::{validate_config, validate_project};
+pub use validate::{validate_changelog, validate_config, validate_project};
@@ -1,9 +1,10 @@
 use std::fmt::Write as _;
 use std::path::PathBuf;
 
-use anyhow::Result;
+use anyhow::{Result, bail};
+use seal_changelog::DEFAULT_CHANGELOG_PATH;
 use seal_fs::FileResolver;
-use seal_project::ProjectWorkspace;
+use seal_project::{ChangelogConfig, ProjectWorkspace};
 
 use crate::{ExitStatus, printer::Printer};
 
@@ -47,3 +48,50 @@ pub fn validate_project(project_path: Option<PathBuf>, printer: Printer) -> Resu
     }
     Ok(ExitStatus::Success)
 }
+
+/// Lint the changelog's headings, versions, and sections
+/// If `changelog_file` is None, uses the configured changelog path
+pub fn validate_changelog(changelog_file: Option<PathBuf>, printer: Printer) -> Result<ExitStatus> {
+    let workspace = ProjectWorkspace::discover()?;
+    let config = workspace.config();
+
+    let default_config = ChangelogConfig::default();
+    let changelog_config = config.changelog.as_ref().unwrap_or(&default_config);
+
+    let changelog_path = workspace.root().join(
+        changelog_file
+            .or_else(|| changelog_config.changelog_path.clone())
+            .unwrap_or_else(|| PathBuf::from(DEFAULT_CHANGELOG_PATH)),
+    );
+
+    let file_resolver = FileResolver::new(workspace.root().clone());
+    let relative_path = file_resolver.relative_path(&changelog_path);
+
+    if !changelog_path.exists() {
+        bail!("Changelog not found at `{}`", relative_path.display());
+    }
+
+    let changelog = fs_err::read_to_string(&changelog_path)?;
+    let current_version = config
+        .release
+        .as_ref()
+        .map(|release| release.current_version.as_str());
+
+    let diagnostics =
+        seal_changelog::lint_changelog(&changelog, changelog_config, current_version)?;
+    if !diagnostics.is_empty() {
+        let diagnostics: Vec<String> = diagnostics.iter().map(ToString::to_string).collect();
+        bail!(
+            "Changelog `{}` is invalid:\n\n{}",
+            relative_path.display(),
+            diagnostics.join("\n\n")
+        );
+    }
+
+    writeln!(
+        printer.stdout_important(),
+        "Changelog `{}` is valid",
+        relative_path.display()
+    )?;
+    Ok(ExitStatus::Success)
+}
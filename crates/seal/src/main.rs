@@ -3,7 +3,10 @@ use std::process::ExitCode;
 use anyhow::Result;
 use clap::Parser;
 use owo_colors::OwoColorize;
-use seal_cli::{Cli, ColorChoice, Commands, GenerateCommand, SelfCommand, ValidateCommand};
+use seal_cli::{
+    AuthCommand, CacheCommand, ChangelogCommand, Cli, ColorChoice, Commands, GenerateCommand,
+    SelfCommand, ValidateCommand,
+};
 use seal_logging::SealFormat;
 use tracing_subscriber::{EnvFilter, Layer, layer::SubscriberExt, util::SubscriberInitExt};
 
@@ -12,7 +15,10 @@ mod printer;
 mod settings;
 mod version;
 
-use crate::{printer::Printer, settings::GlobalSettings};
+use crate::{
+    printer::Printer,
+    settings::{CacheSettings, ForgeSettings, GlobalSettings},
+};
 
 #[derive(Debug, Clone, Copy)]
 enum Level {
@@ -57,7 +63,6 @@ pub(crate) enum ExitStatus {
     Success,
 
     /// The command failed due to an error in the user input.
-    #[expect(unused)]
     Failure,
 
     /// The command failed with an unexpected error.
@@ -110,6 +115,8 @@ async fn main() -> ExitCode {
 async fn run(cli: Cli) -> Result<ExitStatus> {
     // Resolve the global settings.
     let globals = GlobalSettings::resolve(&cli.top_level.global_args);
+    let cache = CacheSettings::resolve(&cli.top_level.cache_args);
+    let forge = ForgeSettings::resolve(&cli.top_level.global_args, &cache);
 
     // Setup logging based on verbosity level.
     let log_level = match globals.verbose {
@@ -150,15 +157,43 @@ async fn run(cli: Cli) -> Result<ExitStatus> {
                 commands::validate_config(config_file, printer)
             }
             ValidateCommand::Project { project } => commands::validate_project(project, printer),
+            ValidateCommand::Changelog { changelog_file } => {
+                commands::validate_changelog(changelog_file, printer)
+            }
         },
-        Commands::Bump(bump_args) => commands::bump(&bump_args, printer).await,
+        Commands::Bump(bump_args) => commands::bump(&bump_args, &forge, printer).await,
         Commands::Generate(generate_ns) => match generate_ns.command {
             GenerateCommand::Changelog {
                 dry_run,
-                max_prs,
                 overwrite,
-            } => commands::generate_changelog(dry_run, printer, overwrite, max_prs).await,
-            GenerateCommand::Release => commands::generate_release_body(printer).await,
+                remote,
+            } => {
+                commands::generate_changelog(dry_run, printer, overwrite, remote.as_deref(), &forge)
+                    .await
+            }
+            GenerateCommand::Release {
+                version,
+                all,
+                output_format,
+            } => {
+                commands::generate_release_body(version.as_deref(), all, output_format, printer)
+                    .await
+            }
+        },
+        Commands::Changelog(changelog_ns) => match changelog_ns.command {
+            ChangelogCommand::Add {
+                number,
+                kind,
+                message,
+            } => commands::changelog_add(number, kind, message, printer),
+        },
+        Commands::Auth(auth_ns) => match auth_ns.command {
+            AuthCommand::Status { remote } => {
+                commands::auth_status(remote.as_deref(), printer).await
+            }
+        },
+        Commands::Cache(cache_ns) => match cache_ns.command {
+            CacheCommand::Clean => commands::cache_clean(&cache, printer),
         },
         Commands::Help(args) => commands::help(
             args.command.unwrap_or_default().as_slice(),
@@ -1,4 +1,7 @@
-use seal_cli::{ColorChoice, GlobalArgs};
+use std::path::PathBuf;
+
+use seal_cache::Cache;
+use seal_cli::{CacheArgs, ColorChoice, GlobalArgs};
 
 /// The resolved global settings to use for any invocation of the CLI.
 #[derive(Debug, Clone)]
@@ -24,3 +27,72 @@ impl GlobalSettings {
         }
     }
 }
+
+/// The resolved settings for creating forge clients.
+#[derive(Debug, Clone)]
+pub(crate) struct ForgeSettings {
+    /// The cache for forge API responses.
+    pub(crate) cache: Option<Cache>,
+    pub(crate) fixtures: Option<ForgeFixtures>,
+}
+
+/// A fixtures file standing in for, or recording, the forge.
+#[derive(Debug, Clone)]
+pub(crate) enum ForgeFixtures {
+    /// Answer forge requests from the file.
+    Replay(PathBuf),
+    /// Forward forge requests and record the responses into the file.
+    Record(PathBuf),
+}
+
+impl ForgeSettings {
+    /// Resolve the [`ForgeSettings`] from the CLI and environment.
+    pub(crate) fn resolve(args: &GlobalArgs, cache: &CacheSettings) -> Self {
+        let fixtures = args.forge_fixtures.clone().map(|path| {
+            if args.record_fixtures {
+                ForgeFixtures::Record(path)
+            } else {
+                ForgeFixtures::Replay(path)
+            }
+        });
+
+        Self {
+            cache: cache.forge_cache(),
+            fixtures,
+        }
+    }
+}
+
+/// The resolved cache settings to use for any invocation of the CLI.
+#[derive(Debug, Clone)]
+pub(crate) struct CacheSettings {
+    pub(crate) no_cache: bool,
+    pub(crate) refresh: bool,
+    pub(crate) cache_dir: Option<PathBuf>,
+}
+
+impl CacheSettings {
+    /// Resolve the [`CacheSettings`] from the CLI and environment.
+    pub(crate) fn resolve(args: &CacheArgs) -> Self {
+        Self {
+            no_cache: args.no_cache,
+            refresh: args.refresh,
+            cache_dir: args.cache_dir.clone(),
+        }
+    }
+
+    /// The cache for forge API responses, unless it is disabled or there is no cache directory.
+    pub(crate) fn forge_cache(&self) -> Option<Cache> {
+        if self.no_cache {
+            return None;
+        }
+
+        match Cache::from_settings(self.cache_dir.clone()) {
+            Ok(cache) => Some(cache.with_refresh(self.refresh)),
+            Err(err) => {
+                tracing::debug!("Not caching forge API responses: {err}");
+                None
+            }
+        }
+    }
+}
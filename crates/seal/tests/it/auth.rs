@@ -0,0 +1,138 @@
+use assert_cmd::Command;
+
+use crate::{common::TestContext, seal_snapshot};
+
+/// A context for a repository with a GitHub `origin` remote and the given configuration.
+fn github_context(config: &str) -> TestContext {
+    let context = TestContext::new();
+    context.seal_toml(config);
+    context.init_git();
+    context.git(&[
+        "remote",
+        "add",
+        "origin",
+        "https://github.com/owner/repo.git",
+    ]);
+    context
+}
+
+/// An `auth status` command that cannot find a token in the developer's environment.
+fn auth_status(context: &TestContext) -> Command {
+    let mut command = context.command();
+    command
+        .arg("auth")
+        .arg("status")
+        .env_remove("GITHUB_TOKEN")
+        .env_remove("GH_TOKEN")
+        .env_remove("GH_ENTERPRISE_TOKEN")
+        .env_remove("GITHUB_ENTERPRISE_TOKEN")
+        .env("GH_CONFIG_DIR", context.root.join("gh"))
+        .env("GIT_CONFIG_GLOBAL", context.root.join("gitconfig"))
+        .env("GIT_CONFIG_NOSYSTEM", "1");
+    command
+}
+
+#[test]
+fn auth_status_not_logged_in() {
+    let context = github_context(
+        r#"
+[release]
+current-version = "1.0.0"
+"#,
+    );
+
+    seal_snapshot!(context.filters(), &mut auth_status(&context), @r"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    Not logged in to github.com
+    Set GITHUB_TOKEN or GH_TOKEN, log in with `gh auth login`, or set `github.token-command`
+
+    ----- stderr -----
+    ");
+}
+
+#[test]
+fn auth_status_token_command_fails() {
+    let context = github_context(
+        r#"
+[release]
+current-version = "1.0.0"
+
+[github]
+token-command = "git config --get seal.missing-token"
+"#,
+    );
+
+    seal_snapshot!(context.filters(), &mut auth_status(&context), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: `github.token-command` (`git config --get seal.missing-token`) failed with exit code 1
+    ");
+}
+
+#[test]
+fn auth_status_requires_github() {
+    let context = github_context(
+        r#"
+[release]
+current-version = "1.0.0"
+
+[forge]
+kind = "gitlab"
+"#,
+    );
+
+    seal_snapshot!(context.filters(), &mut auth_status(&context), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: `seal auth status` only supports GitHub, but Git remote `origin` is hosted on GitLab
+    ");
+}
+
+#[test]
+fn auth_status_detects_github_from_github_api_url() {
+    let context = TestContext::new();
+    context.seal_toml(
+        r#"
+[release]
+current-version = "1.0.0"
+"#,
+    );
+    context.init_git();
+    context.git(&[
+        "remote",
+        "add",
+        "origin",
+        "https://git.example.com/owner/repo.git",
+    ]);
+
+    seal_snapshot!(context.filters(), auth_status(&context).env_remove("GITHUB_API_URL"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Could not detect the forge for host `git.example.com`; set `forge.kind` in `seal.toml`
+    ");
+
+    seal_snapshot!(
+        context.filters(),
+        auth_status(&context).env("GITHUB_API_URL", "https://git.example.com/api/v3"),
+        @r"
+        success: false
+        exit_code: 1
+        ----- stdout -----
+        Not logged in to git.example.com
+        Set GH_ENTERPRISE_TOKEN or GITHUB_ENTERPRISE_TOKEN, log in with `gh auth login`, or set `github.token-command`
+
+        ----- stderr -----
+        "
+    );
+}
@@ -44,6 +44,8 @@ search = "APP_VERSION = '{version}'"
         ))
         .unwrap();
 
+    context.git_commit_all();
+
     seal_snapshot!(context.filters(), context.command().arg("bump").arg("minor"), @r#"
     success: true
     exit_code: 0
@@ -130,6 +132,8 @@ search = "Version: {version}"
         .write_str("Version: 1.2\n")
         .unwrap();
 
+    context.git_commit_all();
+
     seal_snapshot!(context.filters(), context.command().arg("bump").arg("minor"), @r"
     success: false
     exit_code: 2
@@ -172,6 +176,8 @@ search = "#define VERSION \"{version}\""
         ))
         .unwrap();
 
+    context.git_commit_all();
+
     seal_snapshot!(context.filters(), context.command().arg("bump").arg("patch"), @r#"
     success: false
     exit_code: 2
@@ -215,6 +221,8 @@ search = "version={version}"
         .write_str("version=2.0.0-beta.1\n")
         .unwrap();
 
+    context.git_commit_all();
+
     seal_snapshot!(context.filters(), context.command().arg("bump").arg("beta"), @r#"
     success: true
     exit_code: 0
@@ -300,6 +308,8 @@ branch-name = "release/{version}"
         .write_str("Full: 1.5.2\n")
         .unwrap();
 
+    context.git_commit_all();
+
     seal_snapshot!(context.filters(), context.command().arg("bump").arg("minor"), @r"
     success: false
     exit_code: 2
@@ -346,6 +356,8 @@ search = "VERSION={version}"
         .write_str("version=1.0.0\n")
         .unwrap();
 
+    context.git_commit_all();
+
     seal_snapshot!(context.filters(), context.command().arg("bump").arg("major"), @r"
     success: false
     exit_code: 2
@@ -382,6 +394,8 @@ search = "ver={version}"
         .write_str("ver=1.0.0\n")
         .unwrap();
 
+    context.git_commit_all();
+
     seal_snapshot!(context.filters(), context.command().arg("bump").arg("minor"), @r#"
     success: true
     exit_code: 0
@@ -448,6 +462,8 @@ search = "version={version}"
         .write_str("version=2.0.0-rc.3\n")
         .unwrap();
 
+    context.git_commit_all();
+
     seal_snapshot!(context.filters(), context.command().arg("bump").arg("2.0.0"), @r#"
     success: true
     exit_code: 0
@@ -514,6 +530,8 @@ search = "APP_VERSION={version}"
         .write_str("APP_VERSION=1.0.0-alpha.1\n")
         .unwrap();
 
+    context.git_commit_all();
+
     seal_snapshot!(context.filters(), context.command().arg("bump").arg("alpha"), @r#"
     success: true
     exit_code: 0
@@ -584,6 +602,8 @@ search = "version `{version}`"
         ))
         .unwrap();
 
+    context.git_commit_all();
+
     seal_snapshot!(context.filters(), context.command().arg("bump").arg("minor"), @r#"
     success: true
     exit_code: 0
@@ -1429,6 +1449,8 @@ format = "text"
         .write_str("# version")
         .unwrap();
 
+    context.git_commit_all();
+
     seal_snapshot!(context.filters(), context.command().arg("bump").arg("major"), @r"
     success: false
     exit_code: 2
@@ -582,6 +582,607 @@ changelog-path = "CHANGE_LOG.md"
     "#);
 }
 
+#[test]
+fn bump_changelog_compare_links() {
+    let context = TestContext::new();
+    context.seal_toml(
+        r#"
+[release]
+current-version = "1.0.0"
+
+[changelog]
+ignore-labels = ["internal", "ci", "documentation", "enhancement"]
+include-contributors = false
+changelog-heading = "[{version}] (since {previous_version}, tagged {tag})"
+compare-links = true
+
+[changelog.section-labels]
+"Bug Fixes" = ["bug"]
+"#,
+    );
+
+    context
+        .root
+        .child("CHANGELOG.md")
+        .write_str("# Changelog\n\n## [1.0.0]\n\n- Initial release\n\n[1.0.0]: https://github.com/owner/repo/releases/tag/v1.0.0\n")
+        .unwrap();
+
+    context.init_git();
+
+    seal_snapshot!(context.filters(), context.command().arg("bump").arg("patch").write_stdin("y\n"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Bumping version from 1.0.0 to 1.0.1
+
+    Preview of changes:
+    ────────────────────────────────────────────────────────────────────────────────
+    Source: seal.toml
+    ────────────┬───────────────────────────────────────────────────────────────────
+        1     1 │ [release]
+        2       │-current-version = "1.0.0"
+              2 │+current-version = "1.0.1"
+        3     3 │ 
+        4     4 │ [changelog]
+        5     5 │ ignore-labels = ["internal", "ci", "documentation", "enhancement"]
+        6     6 │ include-contributors = false
+    ────────────┴───────────────────────────────────────────────────────────────────
+    Source: CHANGELOG.md
+    ────────────┬───────────────────────────────────────────────────────────────────
+        1     1 │ # Changelog
+        2     2 │ 
+              3 │+## [1.0.1] (since 1.0.0, tagged v1.0.1)
+              4 │+
+              5 │+### Bug Fixes
+              6 │+
+              7 │+- Fix critical bug in module Y ([#5](https://github.com/owner/repo/pull/5))
+              8 │+
+        3     9 │ ## [1.0.0]
+        4    10 │ 
+        5    11 │ - Initial release
+        6    12 │ 
+             13 │+[1.0.1]: https://github.com/owner/repo/compare/v1.0.0...v1.0.1
+        7    14 │ [1.0.0]: https://github.com/owner/repo/releases/tag/v1.0.0
+    ────────────┴───────────────────────────────────────────────────────────────────
+
+    Changes to be made:
+      - Update `seal.toml`
+      - Update `CHANGELOG.md`
+
+    Proceed with these changes? (y/n):
+    Updating files...
+    Successfully bumped to 1.0.1
+
+    ----- stderr -----
+    "#);
+}
+
+#[test]
+fn bump_changelog_unreleased() {
+    let context = TestContext::new();
+    context.seal_toml(
+        r#"
+[release]
+current-version = "1.0.0"
+
+[changelog]
+ignore-labels = ["internal", "ci", "documentation"]
+include-contributors = false
+changelog-heading = "[{version}]"
+compare-links = true
+
+[changelog.section-labels]
+"Fixed" = ["bug"]
+"Added" = ["enhancement"]
+
+[changelog.unreleased]
+merge-generated = true
+"#,
+    );
+
+    context
+        .root
+        .child("CHANGELOG.md")
+        .write_str(
+            "# Changelog\n\n## [Unreleased]\n\n### Added\n\n- A curated note about feature X\n\n## [1.0.0]\n\n- Initial release\n\n[Unreleased]: https://github.com/owner/repo/compare/v1.0.0...HEAD\n[1.0.0]: https://github.com/owner/repo/releases/tag/v1.0.0\n",
+        )
+        .unwrap();
+
+    context.init_git();
+
+    seal_snapshot!(context.filters(), context.command().arg("bump").arg("patch").write_stdin("y\n"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Bumping version from 1.0.0 to 1.0.1
+
+    Preview of changes:
+    ────────────────────────────────────────────────────────────────────────────────
+    Source: seal.toml
+    ────────────┬───────────────────────────────────────────────────────────────────
+        1     1 │ [release]
+        2       │-current-version = "1.0.0"
+              2 │+current-version = "1.0.1"
+        3     3 │ 
+        4     4 │ [changelog]
+        5     5 │ ignore-labels = ["internal", "ci", "documentation"]
+        6     6 │ include-contributors = false
+    ────────────┴───────────────────────────────────────────────────────────────────
+    Source: CHANGELOG.md
+    ────────────┬───────────────────────────────────────────────────────────────────
+        1     1 │ # Changelog
+        2     2 │ 
+        3     3 │ ## [Unreleased]
+        4     4 │ 
+              5 │+## [1.0.1]
+              6 │+
+        5     7 │ ### Added
+        6     8 │ 
+        7     9 │ - A curated note about feature X
+             10 │+- Add new feature X ([#7](https://github.com/owner/repo/pull/7))
+             11 │+- Add new feature X ([#6](https://github.com/owner/repo/pull/6))
+             12 │+
+             13 │+### Fixed
+             14 │+
+             15 │+- Fix critical bug in module Y ([#5](https://github.com/owner/repo/pull/5))
+        8    16 │ 
+        9    17 │ ## [1.0.0]
+       10    18 │ 
+       11    19 │ - Initial release
+       12    20 │ 
+       13       │-[Unreleased]: https://github.com/owner/repo/compare/v1.0.0...HEAD
+             21 │+[Unreleased]: https://github.com/owner/repo/compare/v1.0.1...HEAD
+             22 │+[1.0.1]: https://github.com/owner/repo/compare/v1.0.0...v1.0.1
+       14    23 │ [1.0.0]: https://github.com/owner/repo/releases/tag/v1.0.0
+    ────────────┴───────────────────────────────────────────────────────────────────
+
+    Changes to be made:
+      - Update `seal.toml`
+      - Update `CHANGELOG.md`
+
+    Proceed with these changes? (y/n):
+    Updating files...
+    Successfully bumped to 1.0.1
+
+    ----- stderr -----
+    "#);
+}
+
+#[test]
+fn bump_changelog_unreleased_requires_notes() {
+    let context = TestContext::new();
+    context.seal_toml(
+        r#"
+[release]
+current-version = "1.0.0"
+
+[changelog]
+
+[changelog.unreleased]
+require-notes = true
+"#,
+    );
+
+    context
+        .root
+        .child("CHANGELOG.md")
+        .write_str("# Changelog\n\n## [Unreleased]\n\n## [1.0.0]\n\n- Initial release\n")
+        .unwrap();
+
+    context.init_git();
+
+    seal_snapshot!(context.filters(), context.command().arg("bump").arg("patch"), @r#"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+    Bumping version from 1.0.0 to 1.0.1
+
+
+    ----- stderr -----
+    error: Failed to prepare changelog
+      Caused by: Failed to release the Unreleased section of `[TEMP]/CHANGELOG.md`
+      Caused by: The `## [Unreleased]` section has no release notes
+    "#);
+}
+
+#[test]
+fn bump_changelog_release_notes() {
+    let context = TestContext::new();
+    context.seal_toml(
+        r#"
+[release]
+current-version = "1.0.0"
+
+[changelog]
+ignore-labels = ["internal", "ci"]
+ignore-contributors = ["ignored"]
+include-contributors = false
+
+[changelog.section-labels]
+"Bug Fixes" = ["bug"]
+"New Features" = ["enhancement", "feature"]
+
+[changelog.release-notes]
+breaking-preamble = true
+"#,
+    );
+
+    context.init_git();
+
+    seal_snapshot!(context.filters(), context.command().arg("bump").arg("patch").arg("--dry-run"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Bumping version from 1.0.0 to 1.0.1
+
+    Preview of changes:
+    ────────────────────────────────────────────────────────────────────────────────
+    Source: seal.toml
+    ────────────┬───────────────────────────────────────────────────────────────────
+        1     1 │ [release]
+        2       │-current-version = "1.0.0"
+              2 │+current-version = "1.0.1"
+        3     3 │ 
+        4     4 │ [changelog]
+        5     5 │ ignore-labels = ["internal", "ci"]
+        6     6 │ ignore-contributors = ["ignored"]
+    ────────────┴───────────────────────────────────────────────────────────────────
+    Source: CHANGELOG.md
+    ────────────┬───────────────────────────────────────────────────────────────────
+              1 │+# Changelog
+              2 │+
+              3 │+## 1.0.1
+              4 │+
+              5 │+### Breaking changes
+              6 │+
+              7 │+- Add new feature X ([#6](https://github.com/owner/repo/pull/6))
+              8 │+
+              9 │+  `--x` is now `--feature-x`.
+             10 │+
+             11 │+### Bug Fixes
+             12 │+
+             13 │+- Fix critical bug in module Y ([#5](https://github.com/owner/repo/pull/5))
+             14 │+
+             15 │+  Module Y no longer panics on empty input.
+             16 │+
+             17 │+### New Features
+             18 │+
+             19 │+- Add new feature X ([#6](https://github.com/owner/repo/pull/6))
+             20 │+
+    ────────────┴───────────────────────────────────────────────────────────────────
+
+    Changes to be made:
+      - Update `seal.toml`
+      - Update `CHANGELOG.md`
+
+    Dry run complete. No changes made.
+
+    ----- stderr -----
+    "#);
+}
+
+#[test]
+fn bump_changelog_closed_issues() {
+    let context = TestContext::new();
+    context.seal_toml(
+        r#"
+[release]
+current-version = "1.0.0"
+
+[changelog]
+ignore-labels = ["internal", "ci"]
+ignore-contributors = ["ignored"]
+include-contributors = false
+link-closed-issues = true
+
+[changelog.section-labels]
+"Bug Fixes" = ["bug"]
+"New Features" = ["enhancement", "feature"]
+"#,
+    );
+
+    context.init_git();
+
+    seal_snapshot!(context.filters(), context.command().arg("bump").arg("patch").arg("--dry-run"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Bumping version from 1.0.0 to 1.0.1
+
+    Preview of changes:
+    ────────────────────────────────────────────────────────────────────────────────
+    Source: seal.toml
+    ────────────┬───────────────────────────────────────────────────────────────────
+        1     1 │ [release]
+        2       │-current-version = "1.0.0"
+              2 │+current-version = "1.0.1"
+        3     3 │ 
+        4     4 │ [changelog]
+        5     5 │ ignore-labels = ["internal", "ci"]
+        6     6 │ ignore-contributors = ["ignored"]
+    ────────────┴───────────────────────────────────────────────────────────────────
+    Source: CHANGELOG.md
+    ────────────┬───────────────────────────────────────────────────────────────────
+              1 │+# Changelog
+              2 │+
+              3 │+## 1.0.1
+              4 │+
+              5 │+### Bug Fixes
+              6 │+
+              7 │+- Fix critical bug in module Y ([#5](https://github.com/owner/repo/pull/5), closes [#2](https://github.com/owner/repo/issues/2), [#3](https://github.com/owner/repo/issues/3))
+              8 │+
+              9 │+### New Features
+             10 │+
+             11 │+- Add new feature X ([#6](https://github.com/owner/repo/pull/6))
+             12 │+
+    ────────────┴───────────────────────────────────────────────────────────────────
+
+    Changes to be made:
+      - Update `seal.toml`
+      - Update `CHANGELOG.md`
+
+    Dry run complete. No changes made.
+
+    ----- stderr -----
+    "#);
+}
+
+#[test]
+fn bump_changelog_areas() {
+    let context = TestContext::new();
+    context.seal_toml(
+        r#"
+[release]
+current-version = "1.0.0"
+
+[changelog]
+ignore-labels = ["internal", "ci"]
+ignore-contributors = ["ignored"]
+include-contributors = false
+section-order = ["New Features", "Bug Fixes"]
+
+[changelog.section-labels]
+"Bug Fixes" = ["bug"]
+"New Features" = ["enhancement", "feature"]
+
+[[changelog.areas]]
+name = "Core"
+paths = ["src/**"]
+"#,
+    );
+
+    context.init_git();
+
+    seal_snapshot!(context.filters(), context.command().arg("bump").arg("patch").arg("--dry-run"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Bumping version from 1.0.0 to 1.0.1
+
+    Preview of changes:
+    ────────────────────────────────────────────────────────────────────────────────
+    Source: seal.toml
+    ────────────┬───────────────────────────────────────────────────────────────────
+        1     1 │ [release]
+        2       │-current-version = "1.0.0"
+              2 │+current-version = "1.0.1"
+        3     3 │ 
+        4     4 │ [changelog]
+        5     5 │ ignore-labels = ["internal", "ci"]
+        6     6 │ ignore-contributors = ["ignored"]
+    ────────────┴───────────────────────────────────────────────────────────────────
+    Source: CHANGELOG.md
+    ────────────┬───────────────────────────────────────────────────────────────────
+              1 │+# Changelog
+              2 │+
+              3 │+## 1.0.1
+              4 │+
+              5 │+### Core
+              6 │+
+              7 │+#### Bug Fixes
+              8 │+
+              9 │+- Fix critical bug in module Y ([#5](https://github.com/owner/repo/pull/5))
+             10 │+
+             11 │+### Other
+             12 │+
+             13 │+#### New Features
+             14 │+
+             15 │+- Add new feature X ([#6](https://github.com/owner/repo/pull/6))
+             16 │+
+    ────────────┴───────────────────────────────────────────────────────────────────
+
+    Changes to be made:
+      - Update `seal.toml`
+      - Update `CHANGELOG.md`
+
+    Dry run complete. No changes made.
+
+    ----- stderr -----
+    "#);
+}
+
+#[test]
+fn bump_changelog_fragment_notes() {
+    let context = TestContext::new();
+    context.seal_toml(
+        r#"
+[release]
+current-version = "1.0.0"
+
+[changelog]
+include-contributors = false
+fragments-dir = "changes"
+
+[changelog.section-labels]
+"Features" = ["feature"]
+
+[changelog.release-notes]
+"#,
+    );
+
+    context
+        .root
+        .child("CHANGELOG.md")
+        .write_str("# Changelog\n\n## 1.0.0\n\n- Initial release\n")
+        .unwrap();
+    context
+        .root
+        .child("changes/12.feature.md")
+        .write_str("Add a feature\n\nEnable it with `--feature`.\n")
+        .unwrap();
+
+    context.init_git();
+
+    seal_snapshot!(context.filters(), context.command().arg("bump").arg("patch").arg("--dry-run"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Bumping version from 1.0.0 to 1.0.1
+
+    Preview of changes:
+    ────────────────────────────────────────────────────────────────────────────────
+    Source: seal.toml
+    ────────────┬───────────────────────────────────────────────────────────────────
+        1     1 │ [release]
+        2       │-current-version = "1.0.0"
+              2 │+current-version = "1.0.1"
+        3     3 │ 
+        4     4 │ [changelog]
+        5     5 │ include-contributors = false
+        6     6 │ fragments-dir = "changes"
+    ────────────┴───────────────────────────────────────────────────────────────────
+    Source: CHANGELOG.md
+    ────────────┬───────────────────────────────────────────────────────────────────
+        1     1 │ # Changelog
+        2     2 │ 
+              3 │+## 1.0.1
+              4 │+
+              5 │+### Features
+              6 │+
+              7 │+- Add a feature ([#12](https://github.com/owner/repo/pull/12))
+              8 │+
+              9 │+  Enable it with `--feature`.
+             10 │+
+        3    11 │ ## 1.0.0
+        4    12 │ 
+        5    13 │ - Initial release
+    ────────────┴───────────────────────────────────────────────────────────────────
+    Source: changes/12.feature.md (deleted)
+    ────────────┬───────────────────────────────────────────────────────────────────
+        1       │-Add a feature
+        2       │-
+        3       │-Enable it with `--feature`.
+    ────────────┴───────────────────────────────────────────────────────────────────
+
+    Changes to be made:
+      - Update `seal.toml`
+      - Update `CHANGELOG.md`
+      - Delete `changes/12.feature.md`
+
+    Dry run complete. No changes made.
+
+    ----- stderr -----
+    "#);
+}
+
+#[test]
+fn bump_changelog_fragments() {
+    let context = TestContext::new();
+    context.seal_toml(
+        r#"
+[release]
+current-version = "1.0.0"
+
+[changelog]
+include-contributors = false
+fragments-dir = "changes"
+
+[changelog.section-labels]
+"Bug fixes" = ["bugfix"]
+"Features" = ["feature"]
+"#,
+    );
+
+    context
+        .root
+        .child("CHANGELOG.md")
+        .write_str("# Changelog\n\n## 1.0.0\n\n- Initial release\n")
+        .unwrap();
+    context
+        .root
+        .child("changes/12.feature.md")
+        .write_str("Add a feature\n")
+        .unwrap();
+    context
+        .root
+        .child("changes/15.bugfix.md")
+        .write_str("Fix a bug\n")
+        .unwrap();
+
+    context.init_git();
+
+    seal_snapshot!(context.filters(), context.command().arg("bump").arg("patch").write_stdin("y\n"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Bumping version from 1.0.0 to 1.0.1
+
+    Preview of changes:
+    ────────────────────────────────────────────────────────────────────────────────
+    Source: seal.toml
+    ────────────┬───────────────────────────────────────────────────────────────────
+        1     1 │ [release]
+        2       │-current-version = "1.0.0"
+              2 │+current-version = "1.0.1"
+        3     3 │ 
+        4     4 │ [changelog]
+        5     5 │ include-contributors = false
+        6     6 │ fragments-dir = "changes"
+    ────────────┴───────────────────────────────────────────────────────────────────
+    Source: CHANGELOG.md
+    ────────────┬───────────────────────────────────────────────────────────────────
+        1     1 │ # Changelog
+        2     2 │ 
+              3 │+## 1.0.1
+              4 │+
+              5 │+### Bug fixes
+              6 │+
+              7 │+- Fix a bug ([#15](https://github.com/owner/repo/pull/15))
+              8 │+
+              9 │+### Features
+             10 │+
+             11 │+- Add a feature ([#12](https://github.com/owner/repo/pull/12))
+             12 │+
+        3    13 │ ## 1.0.0
+        4    14 │ 
+        5    15 │ - Initial release
+    ────────────┴───────────────────────────────────────────────────────────────────
+    Source: changes/15.bugfix.md (deleted)
+    ────────────┬───────────────────────────────────────────────────────────────────
+        1       │-Fix a bug
+    ────────────┴───────────────────────────────────────────────────────────────────
+    Source: changes/12.feature.md (deleted)
+    ────────────┬───────────────────────────────────────────────────────────────────
+        1       │-Add a feature
+    ────────────┴───────────────────────────────────────────────────────────────────
+
+    Changes to be made:
+      - Update `seal.toml`
+      - Update `CHANGELOG.md`
+      - Delete `changes/15.bugfix.md`
+      - Delete `changes/12.feature.md`
+
+    Proceed with these changes? (y/n):
+    Updating files...
+    Successfully bumped to 1.0.1
+
+    ----- stderr -----
+    "#);
+
+    assert!(!context.root.child("changes/12.feature.md").exists());
+    assert!(!context.root.child("changes/15.bugfix.md").exists());
+}
+
 #[test]
 fn bump_patch_valid_commit() {
     let context = TestContext::new();
@@ -603,7 +1204,1210 @@ commit-message = "Release v{version}"
         .write_str("# My Package (1.2.3)")
         .unwrap();
 
-    seal_snapshot!(context.filters(), context.command().arg("bump").arg("patch").write_stdin("y\n"), @r#"
+    context.git_commit_all();
+
+    seal_snapshot!(context.filters(), context.command().arg("bump").arg("patch").write_stdin("y\n"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Bumping version from 1.2.3 to 1.2.4
+
+    Preview of changes:
+    ────────────────────────────────────────────────────────────────────────────────
+    Source: README.md
+    ────────────┬───────────────────────────────────────────────────────────────────
+        1       │-# My Package (1.2.3)
+              1 │+# My Package (1.2.4)
+    ────────────┴───────────────────────────────────────────────────────────────────
+    Source: seal.toml
+    ────────────┬───────────────────────────────────────────────────────────────────
+        1     1 │ [release]
+        2       │-current-version = "1.2.3"
+              2 │+current-version = "1.2.4"
+        3     3 │ version-files = ["README.md"]
+        4     4 │ commit-message = "Release v{version}"
+    ────────────┴───────────────────────────────────────────────────────────────────
+
+    Changes to be made:
+      - Update `README.md`
+      - Update `seal.toml`
+
+    Commands to be executed:
+      `git add -A`
+      `git commit -m Release v1.2.4`
+
+    Proceed with these changes? (y/n):
+    Updating files...
+    Executing command: `git add -A`
+    Executing command: `git commit -m Release v1.2.4`
+    Successfully bumped to 1.2.4
+
+    ----- stderr -----
+    "#);
+
+    insta::assert_snapshot!(context.read_file("README.md"), @"# My Package (1.2.4)");
+    insta::assert_snapshot!(context.read_file("seal.toml"), @r#"
+    [release]
+    current-version = "1.2.4"
+    version-files = ["README.md"]
+    commit-message = "Release v{version}"
+    "#);
+
+    insta::assert_snapshot!(context.git_current_branch(), @"main");
+    insta::assert_snapshot!(context.git_last_commit_message(), @"Release v1.2.4");
+}
+
+#[test]
+fn bump_git_commit_failure_includes_hook_output() {
+    let context = TestContext::new();
+
+    context.init_git();
+
+    context.seal_toml(
+        r#"
+[release]
+current-version = "1.2.3"
+commit-message = "Release v{version}"
+confirm = false
+"#,
+    );
+
+    context.git_commit_all();
+
+    let hook = context.root.child(".git/hooks/pre-commit");
+    hook.write_str("#!/bin/sh\necho 'pre-commit hook failed'\nexit 1\n")
+        .unwrap();
+
+    #[cfg(unix)]
+    {
+        let mut permissions = std::fs::metadata(hook.path()).unwrap().permissions();
+        permissions.set_mode(0o755);
+        std::fs::set_permissions(hook.path(), permissions).unwrap();
+    }
+
+    // Git hooks only run when the `git` executable makes the commit.
+    seal_snapshot!(context.filters(), context.command().arg("bump").arg("patch").env("SEAL_GIT_BACKEND", "cli"), @r#"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+    Bumping version from 1.2.3 to 1.2.4
+
+    Preview of changes:
+    ────────────────────────────────────────────────────────────────────────────────
+    Source: seal.toml
+    ────────────┬───────────────────────────────────────────────────────────────────
+        1     1 │ [release]
+        2       │-current-version = "1.2.3"
+              2 │+current-version = "1.2.4"
+        3     3 │ commit-message = "Release v{version}"
+        4     4 │ confirm = false
+    ────────────┴───────────────────────────────────────────────────────────────────
+
+    Changes to be made:
+      - Update `seal.toml`
+
+    Commands to be executed:
+      `git add -A`
+      `git commit -m Release v1.2.4`
+
+    Updating files...
+    Executing command: `git add -A`
+    Executing command: `git commit -m Release v1.2.4`
+
+    ----- stderr -----
+    error: Command `git commit -m Release v1.2.4` failed (exit code 1)
+    pre-commit hook failed
+    "#);
+
+    insta::assert_snapshot!(context.git_last_commit_message(), @"Set up test");
+}
+
+#[test]
+fn bump_patch_valid_commit_branch() {
+    let context = TestContext::new();
+
+    context.init_git();
+
+    context.seal_toml(
+        r#"
+[release]
+current-version = "1.2.3"
+version-files = ["README.md"]
+commit-message = "Release v{version}"
+branch-name = "release/v{version}"
+"#,
+    );
+
+    context
+        .root
+        .child("README.md")
+        .write_str("# My Package (1.2.3)")
+        .unwrap();
+
+    context.git_commit_all();
+
+    seal_snapshot!(context.filters(), context.command().arg("bump").arg("patch").write_stdin("y\n"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Bumping version from 1.2.3 to 1.2.4
+
+    Preview of changes:
+    ────────────────────────────────────────────────────────────────────────────────
+    Source: README.md
+    ────────────┬───────────────────────────────────────────────────────────────────
+        1       │-# My Package (1.2.3)
+              1 │+# My Package (1.2.4)
+    ────────────┴───────────────────────────────────────────────────────────────────
+    Source: seal.toml
+    ────────────┬───────────────────────────────────────────────────────────────────
+        1     1 │ [release]
+        2       │-current-version = "1.2.3"
+              2 │+current-version = "1.2.4"
+        3     3 │ version-files = ["README.md"]
+        4     4 │ commit-message = "Release v{version}"
+        5     5 │ branch-name = "release/v{version}"
+    ────────────┴───────────────────────────────────────────────────────────────────
+
+    Changes to be made:
+      - Update `README.md`
+      - Update `seal.toml`
+
+    Commands to be executed:
+      `git checkout -b release/v1.2.4`
+      `git add -A`
+      `git commit -m Release v1.2.4`
+
+    Proceed with these changes? (y/n):
+    Updating files...
+    Executing command: `git checkout -b release/v1.2.4`
+    Executing command: `git add -A`
+    Executing command: `git commit -m Release v1.2.4`
+    Successfully bumped to 1.2.4
+
+    ----- stderr -----
+    "#);
+
+    insta::assert_snapshot!(context.read_file("README.md"), @"# My Package (1.2.4)");
+    insta::assert_snapshot!(context.read_file("seal.toml"), @r#"
+    [release]
+    current-version = "1.2.4"
+    version-files = ["README.md"]
+    commit-message = "Release v{version}"
+    branch-name = "release/v{version}"
+    "#);
+
+    insta::assert_snapshot!(context.git_current_branch(), @"release/v1.2.4");
+    insta::assert_snapshot!(context.git_last_commit_message(), @"Release v1.2.4");
+}
+
+#[test]
+fn bump_patch_valid_branch() {
+    let context = TestContext::new();
+
+    context.init_git();
+
+    context.seal_toml(
+        r#"
+[release]
+current-version = "1.2.3"
+version-files = ["README.md"]
+branch-name = "release/v{version}"
+"#,
+    );
+
+    context
+        .root
+        .child("README.md")
+        .write_str("# My Package (1.2.3)")
+        .unwrap();
+
+    context.git_commit_all();
+
+    seal_snapshot!(context.filters(), context.command().arg("bump").arg("patch").write_stdin("y\n"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Bumping version from 1.2.3 to 1.2.4
+
+    Preview of changes:
+    ────────────────────────────────────────────────────────────────────────────────
+    Source: README.md
+    ────────────┬───────────────────────────────────────────────────────────────────
+        1       │-# My Package (1.2.3)
+              1 │+# My Package (1.2.4)
+    ────────────┴───────────────────────────────────────────────────────────────────
+    Source: seal.toml
+    ────────────┬───────────────────────────────────────────────────────────────────
+        1     1 │ [release]
+        2       │-current-version = "1.2.3"
+              2 │+current-version = "1.2.4"
+        3     3 │ version-files = ["README.md"]
+        4     4 │ branch-name = "release/v{version}"
+    ────────────┴───────────────────────────────────────────────────────────────────
+
+    Changes to be made:
+      - Update `README.md`
+      - Update `seal.toml`
+
+    Commands to be executed:
+      `git checkout -b release/v1.2.4`
+
+    Proceed with these changes? (y/n):
+    Updating files...
+    Executing command: `git checkout -b release/v1.2.4`
+    Successfully bumped to 1.2.4
+
+    ----- stderr -----
+    "#);
+
+    insta::assert_snapshot!(context.read_file("README.md"), @"# My Package (1.2.4)");
+    insta::assert_snapshot!(context.read_file("seal.toml"), @r#"
+    [release]
+    current-version = "1.2.4"
+    version-files = ["README.md"]
+    branch-name = "release/v{version}"
+    "#);
+
+    insta::assert_snapshot!(context.git_current_branch(), @"release/v1.2.4");
+    insta::assert_snapshot!(context.git_last_commit_message(), @"Set up test");
+}
+
+#[test]
+fn bump_patch_valid_commit_branch_push() {
+    let context = TestContext::new();
+
+    context.init_git().init_git_remote();
+
+    context.seal_toml(
+        r#"
+[release]
+current-version = "1.2.3"
+version-files = ["README.md"]
+commit-message = "Release v{version}"
+branch-name = "release/v{version}"
+push = true
+"#,
+    );
+
+    context
+        .root
+        .child("README.md")
+        .write_str("# My Package (1.2.3)")
+        .unwrap();
+
+    context.git_commit_all();
+
+    seal_snapshot!(context.filters(), context.command().arg("bump").arg("patch").write_stdin("y\n"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Bumping version from 1.2.3 to 1.2.4
+
+    Preview of changes:
+    ────────────────────────────────────────────────────────────────────────────────
+    Source: README.md
+    ────────────┬───────────────────────────────────────────────────────────────────
+        1       │-# My Package (1.2.3)
+              1 │+# My Package (1.2.4)
+    ────────────┴───────────────────────────────────────────────────────────────────
+    Source: seal.toml
+    ────────────┬───────────────────────────────────────────────────────────────────
+        1     1 │ [release]
+        2       │-current-version = "1.2.3"
+              2 │+current-version = "1.2.4"
+        3     3 │ version-files = ["README.md"]
+        4     4 │ commit-message = "Release v{version}"
+        5     5 │ branch-name = "release/v{version}"
+        6     6 │ push = true
+    ────────────┴───────────────────────────────────────────────────────────────────
+
+    Changes to be made:
+      - Update `README.md`
+      - Update `seal.toml`
+
+    Commands to be executed:
+      `git checkout -b release/v1.2.4`
+      `git add -A`
+      `git commit -m Release v1.2.4`
+      `git push origin release/v1.2.4`
+
+    Proceed with these changes? (y/n):
+    Updating files...
+    Executing command: `git checkout -b release/v1.2.4`
+    Executing command: `git add -A`
+    Executing command: `git commit -m Release v1.2.4`
+    Executing command: `git push origin release/v1.2.4`
+    Successfully bumped to 1.2.4
+
+    ----- stderr -----
+    "#);
+
+    insta::assert_snapshot!(context.read_file("README.md"), @"# My Package (1.2.4)");
+    insta::assert_snapshot!(context.read_file("seal.toml"), @r#"
+    [release]
+    current-version = "1.2.4"
+    version-files = ["README.md"]
+    commit-message = "Release v{version}"
+    branch-name = "release/v{version}"
+    push = true
+    "#);
+
+    insta::assert_snapshot!(context.git_current_branch(), @"release/v1.2.4");
+    insta::assert_snapshot!(context.git_last_commit_message(), @"Release v1.2.4");
+}
+
+#[test]
+fn bump_patch_valid_commit_branch_push_pr() {
+    let context = TestContext::new();
+
+    context.init_git().init_git_remote();
+
+    context.seal_toml(
+        r#"
+[release]
+current-version = "1.2.3"
+version-files = ["README.md"]
+commit-message = "Release v{version}"
+branch-name = "release/v{version}"
+push = true
+"#,
+    );
+
+    context
+        .root
+        .child("README.md")
+        .write_str("# My Package (1.2.3)")
+        .unwrap();
+
+    context.git_commit_all();
+
+    seal_snapshot!(context.filters(), context.command().arg("bump").arg("patch").write_stdin("y\n"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Bumping version from 1.2.3 to 1.2.4
+
+    Preview of changes:
+    ────────────────────────────────────────────────────────────────────────────────
+    Source: README.md
+    ────────────┬───────────────────────────────────────────────────────────────────
+        1       │-# My Package (1.2.3)
+              1 │+# My Package (1.2.4)
+    ────────────┴───────────────────────────────────────────────────────────────────
+    Source: seal.toml
+    ────────────┬───────────────────────────────────────────────────────────────────
+        1     1 │ [release]
+        2       │-current-version = "1.2.3"
+              2 │+current-version = "1.2.4"
+        3     3 │ version-files = ["README.md"]
+        4     4 │ commit-message = "Release v{version}"
+        5     5 │ branch-name = "release/v{version}"
+        6     6 │ push = true
+    ────────────┴───────────────────────────────────────────────────────────────────
+
+    Changes to be made:
+      - Update `README.md`
+      - Update `seal.toml`
+
+    Commands to be executed:
+      `git checkout -b release/v1.2.4`
+      `git add -A`
+      `git commit -m Release v1.2.4`
+      `git push origin release/v1.2.4`
+
+    Proceed with these changes? (y/n):
+    Updating files...
+    Executing command: `git checkout -b release/v1.2.4`
+    Executing command: `git add -A`
+    Executing command: `git commit -m Release v1.2.4`
+    Executing command: `git push origin release/v1.2.4`
+    Successfully bumped to 1.2.4
+
+    ----- stderr -----
+    "#);
+
+    insta::assert_snapshot!(context.read_file("README.md"), @"# My Package (1.2.4)");
+    insta::assert_snapshot!(context.read_file("seal.toml"), @r#"
+    [release]
+    current-version = "1.2.4"
+    version-files = ["README.md"]
+    commit-message = "Release v{version}"
+    branch-name = "release/v{version}"
+    push = true
+    "#);
+
+    insta::assert_snapshot!(context.git_current_branch(), @"release/v1.2.4");
+    insta::assert_snapshot!(context.git_last_commit_message(), @"Release v1.2.4");
+}
+
+#[test]
+fn bump_patch_valid_commit_branch_push_pr_no_confirm() {
+    let context = TestContext::new();
+
+    context.init_git().init_git_remote();
+
+    context.seal_toml(
+        r#"
+[release]
+current-version = "1.2.3"
+version-files = ["README.md"]
+commit-message = "Release v{version}"
+branch-name = "release/v{version}"
+push = true
+confirm = false
+"#,
+    );
+
+    context
+        .root
+        .child("README.md")
+        .write_str("# My Package (1.2.3)")
+        .unwrap();
+
+    context.git_commit_all();
+
+    seal_snapshot!(context.filters(), context.command().arg("bump").arg("patch"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Bumping version from 1.2.3 to 1.2.4
+
+    Preview of changes:
+    ────────────────────────────────────────────────────────────────────────────────
+    Source: README.md
+    ────────────┬───────────────────────────────────────────────────────────────────
+        1       │-# My Package (1.2.3)
+              1 │+# My Package (1.2.4)
+    ────────────┴───────────────────────────────────────────────────────────────────
+    Source: seal.toml
+    ────────────┬───────────────────────────────────────────────────────────────────
+        1     1 │ [release]
+        2       │-current-version = "1.2.3"
+              2 │+current-version = "1.2.4"
+        3     3 │ version-files = ["README.md"]
+        4     4 │ commit-message = "Release v{version}"
+        5     5 │ branch-name = "release/v{version}"
+        6     6 │ push = true
+    ────────────┴───────────────────────────────────────────────────────────────────
+
+    Changes to be made:
+      - Update `README.md`
+      - Update `seal.toml`
+
+    Commands to be executed:
+      `git checkout -b release/v1.2.4`
+      `git add -A`
+      `git commit -m Release v1.2.4`
+      `git push origin release/v1.2.4`
+
+    Updating files...
+    Executing command: `git checkout -b release/v1.2.4`
+    Executing command: `git add -A`
+    Executing command: `git commit -m Release v1.2.4`
+    Executing command: `git push origin release/v1.2.4`
+    Successfully bumped to 1.2.4
+
+    ----- stderr -----
+    "#);
+
+    insta::assert_snapshot!(context.read_file("README.md"), @"# My Package (1.2.4)");
+    insta::assert_snapshot!(context.read_file("seal.toml"), @r#"
+    [release]
+    current-version = "1.2.4"
+    version-files = ["README.md"]
+    commit-message = "Release v{version}"
+    branch-name = "release/v{version}"
+    push = true
+    confirm = false
+    "#);
+
+    insta::assert_snapshot!(context.git_current_branch(), @"release/v1.2.4");
+    insta::assert_snapshot!(context.git_last_commit_message(), @"Release v1.2.4");
+}
+
+#[test]
+fn bump_pull_request_not_created_when_push_fails() {
+    let context = TestContext::new();
+
+    context.init_git().init_git_remote();
+
+    let hook = context.root.child(".git/remote.git/hooks/pre-receive");
+    hook.write_str("#!/bin/sh\necho 'push rejected'\nexit 1\n")
+        .unwrap();
+
+    #[cfg(unix)]
+    {
+        let mut permissions = std::fs::metadata(hook.path()).unwrap().permissions();
+        permissions.set_mode(0o755);
+        std::fs::set_permissions(hook.path(), permissions).unwrap();
+    }
+
+    context.seal_toml(
+        r#"
+[release]
+current-version = "1.2.3"
+version-files = ["README.md"]
+commit-message = "Release v{version}"
+branch-name = "release/v{version}"
+push = true
+
+[release.pull-request]
+"#,
+    );
+
+    context
+        .root
+        .child("README.md")
+        .write_str("# My Package (1.2.3)")
+        .unwrap();
+
+    context.git_commit_all();
+
+    seal_snapshot!(context.filters(), context.command().arg("bump").arg("patch").write_stdin("y\n"), @r#"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+    Bumping version from 1.2.3 to 1.2.4
+
+    Preview of changes:
+    ────────────────────────────────────────────────────────────────────────────────
+    Source: README.md
+    ────────────┬───────────────────────────────────────────────────────────────────
+        1       │-# My Package (1.2.3)
+              1 │+# My Package (1.2.4)
+    ────────────┴───────────────────────────────────────────────────────────────────
+    Source: seal.toml
+    ────────────┬───────────────────────────────────────────────────────────────────
+        1     1 │ [release]
+        2       │-current-version = "1.2.3"
+              2 │+current-version = "1.2.4"
+        3     3 │ version-files = ["README.md"]
+        4     4 │ commit-message = "Release v{version}"
+        5     5 │ branch-name = "release/v{version}"
+        6     6 │ push = true
+    ────────────┴───────────────────────────────────────────────────────────────────
+
+    Changes to be made:
+      - Update `README.md`
+      - Update `seal.toml`
+
+    Commands to be executed:
+      `git checkout -b release/v1.2.4`
+      `git add -A`
+      `git commit -m Release v1.2.4`
+      `git push origin release/v1.2.4`
+
+    Pull request:
+      Title: Release v1.2.4
+      Head: release/v1.2.4
+      Base: main
+      Draft: false
+      Body: (empty)
+
+    Proceed with these changes? (y/n):
+    Updating files...
+    Executing command: `git checkout -b release/v1.2.4`
+    Executing command: `git add -A`
+    Executing command: `git commit -m Release v1.2.4`
+    Executing command: `git push origin release/v1.2.4`
+
+    ----- stderr -----
+    error: Command `git push origin release/v1.2.4` failed (exit code 1)
+    remote: push rejected        
+    To [TEMP]/.git/remote.git
+     ! [remote rejected] release/v1.2.4 -> release/v1.2.4 (pre-receive hook declined)
+    error: failed to push some refs to '[TEMP]/.git/remote.git'
+    "#);
+
+    insta::assert_snapshot!(context.read_file("README.md"), @"# My Package (1.2.4)");
+    insta::assert_snapshot!(context.read_file("seal.toml"), @r#"
+    [release]
+    current-version = "1.2.4"
+    version-files = ["README.md"]
+    commit-message = "Release v{version}"
+    branch-name = "release/v{version}"
+    push = true
+
+    [release.pull-request]
+    "#);
+
+    insta::assert_snapshot!(context.git_current_branch(), @"release/v1.2.4");
+    insta::assert_snapshot!(context.git_last_commit_message(), @"Release v1.2.4");
+}
+
+#[test]
+fn bump_pull_request_authentication_failure_makes_no_changes() {
+    let context = TestContext::new();
+
+    context.init_git().init_git_remote();
+
+    context.seal_toml(
+        r#"
+[release]
+current-version = "1.2.3"
+version-files = ["README.md"]
+commit-message = "Release v{version}"
+branch-name = "release/v{version}"
+push = true
+confirm = false
+
+[release.pull-request]
+"#,
+    );
+
+    context
+        .root
+        .child("README.md")
+        .write_str("# My Package (1.2.3)")
+        .unwrap();
+
+    context.git_commit_all();
+
+    seal_snapshot!(context.filters(), context.command().env("SEAL_TEST_GITHUB_AUTHENTICATION_REQUIRED", "1").arg("bump").arg("patch"), @r#"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+    Bumping version from 1.2.3 to 1.2.4
+
+    Preview of changes:
+    ────────────────────────────────────────────────────────────────────────────────
+    Source: README.md
+    ────────────┬───────────────────────────────────────────────────────────────────
+        1       │-# My Package (1.2.3)
+              1 │+# My Package (1.2.4)
+    ────────────┴───────────────────────────────────────────────────────────────────
+    Source: seal.toml
+    ────────────┬───────────────────────────────────────────────────────────────────
+        1     1 │ [release]
+        2       │-current-version = "1.2.3"
+              2 │+current-version = "1.2.4"
+        3     3 │ version-files = ["README.md"]
+        4     4 │ commit-message = "Release v{version}"
+        5     5 │ branch-name = "release/v{version}"
+        6     6 │ push = true
+    ────────────┴───────────────────────────────────────────────────────────────────
+
+    Changes to be made:
+      - Update `README.md`
+      - Update `seal.toml`
+
+    Commands to be executed:
+      `git checkout -b release/v1.2.4`
+      `git add -A`
+      `git commit -m Release v1.2.4`
+      `git push origin release/v1.2.4`
+
+    Pull request:
+      Title: Release v1.2.4
+      Head: release/v1.2.4
+      Base: main
+      Draft: false
+      Body: (empty)
+
+
+    ----- stderr -----
+    error: GitHub authentication is required; set GITHUB_TOKEN or GH_TOKEN (GH_ENTERPRISE_TOKEN on GitHub Enterprise Server), log in with `gh auth login`, or set `github.token-command`
+    "#);
+
+    insta::assert_snapshot!(context.read_file("README.md"), @"# My Package (1.2.3)");
+    insta::assert_snapshot!(context.read_file("seal.toml"), @r#"
+    [release]
+    current-version = "1.2.3"
+    version-files = ["README.md"]
+    commit-message = "Release v{version}"
+    branch-name = "release/v{version}"
+    push = true
+    confirm = false
+
+    [release.pull-request]
+    "#);
+
+    insta::assert_snapshot!(context.git_current_branch(), @"main");
+    insta::assert_snapshot!(context.git_last_commit_message(), @"Set up test");
+}
+
+#[test]
+fn bump_pull_request_dry_run_prints_defaults() {
+    let context = TestContext::new();
+
+    context.init_git().init_git_remote();
+
+    context.seal_toml(
+        r#"
+[release]
+current-version = "1.2.3"
+version-files = ["README.md"]
+commit-message = "Release v{version}"
+branch-name = "release/v{version}"
+push = true
+
+[release.pull-request]
+"#,
+    );
+
+    context
+        .root
+        .child("README.md")
+        .write_str("# My Package (1.2.3)")
+        .unwrap();
+
+    context.git_commit_all();
+
+    seal_snapshot!(context.filters(), context.command().arg("bump").arg("patch").arg("--dry-run"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Bumping version from 1.2.3 to 1.2.4
+
+    Preview of changes:
+    ────────────────────────────────────────────────────────────────────────────────
+    Source: README.md
+    ────────────┬───────────────────────────────────────────────────────────────────
+        1       │-# My Package (1.2.3)
+              1 │+# My Package (1.2.4)
+    ────────────┴───────────────────────────────────────────────────────────────────
+    Source: seal.toml
+    ────────────┬───────────────────────────────────────────────────────────────────
+        1     1 │ [release]
+        2       │-current-version = "1.2.3"
+              2 │+current-version = "1.2.4"
+        3     3 │ version-files = ["README.md"]
+        4     4 │ commit-message = "Release v{version}"
+        5     5 │ branch-name = "release/v{version}"
+        6     6 │ push = true
+    ────────────┴───────────────────────────────────────────────────────────────────
+
+    Changes to be made:
+      - Update `README.md`
+      - Update `seal.toml`
+
+    Commands to be executed:
+      `git checkout -b release/v1.2.4`
+      `git add -A`
+      `git commit -m Release v1.2.4`
+      `git push origin release/v1.2.4`
+
+    Pull request:
+      Title: Release v1.2.4
+      Head: release/v1.2.4
+      Base: main
+      Draft: false
+      Body: (empty)
+
+    Dry run complete. No changes made.
+
+    ----- stderr -----
+    "#);
+
+    insta::assert_snapshot!(context.read_file("README.md"), @"# My Package (1.2.3)");
+    insta::assert_snapshot!(context.read_file("seal.toml"), @r#"
+    [release]
+    current-version = "1.2.3"
+    version-files = ["README.md"]
+    commit-message = "Release v{version}"
+    branch-name = "release/v{version}"
+    push = true
+
+    [release.pull-request]
+    "#);
+
+    insta::assert_snapshot!(context.git_current_branch(), @"main");
+    insta::assert_snapshot!(context.git_last_commit_message(), @"Set up test");
+}
+
+#[test]
+fn bump_pull_request_dry_run_prints_custom_options() {
+    let context = TestContext::new();
+
+    context.init_git().init_git_remote();
+
+    context.seal_toml(
+        r#"
+[release]
+current-version = "1.2.3"
+commit-message = "Release v{version}"
+branch-name = "release/v{version}"
+push = true
+[release.pull-request]
+title = "Ship {version}"
+body = "Prepare release {version}.\n\nReview before merging."
+base = "stable"
+draft = true
+"#,
+    );
+
+    context.git_commit_all();
+
+    seal_snapshot!(context.filters(), context.command().arg("bump").arg("patch").arg("--dry-run"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Bumping version from 1.2.3 to 1.2.4
+
+    Preview of changes:
+    ────────────────────────────────────────────────────────────────────────────────
+    Source: seal.toml
+    ────────────┬───────────────────────────────────────────────────────────────────
+        1     1 │ [release]
+        2       │-current-version = "1.2.3"
+              2 │+current-version = "1.2.4"
+        3     3 │ commit-message = "Release v{version}"
+        4     4 │ branch-name = "release/v{version}"
+        5     5 │ push = true
+        6     6 │ [release.pull-request]
+    ────────────┴───────────────────────────────────────────────────────────────────
+
+    Changes to be made:
+      - Update `seal.toml`
+
+    Commands to be executed:
+      `git checkout -b release/v1.2.4`
+      `git add -A`
+      `git commit -m Release v1.2.4`
+      `git push origin release/v1.2.4`
+
+    Pull request:
+      Title: Ship 1.2.4
+      Head: release/v1.2.4
+      Base: stable
+      Draft: true
+      Body:
+        Prepare release 1.2.4.
+
+        Review before merging.
+
+    Dry run complete. No changes made.
+
+    ----- stderr -----
+    "#);
+}
+
+#[test]
+fn bump_pull_request_runs_after_successful_push() {
+    let context = TestContext::new();
+
+    context.init_git().init_git_remote();
+
+    context.seal_toml(
+        r#"
+[release]
+current-version = "1.2.3"
+version-files = ["README.md"]
+commit-message = "Release v{version}"
+branch-name = "release/v{version}"
+push = true
+confirm = false
+
+[release.pull-request]
+"#,
+    );
+
+    context
+        .root
+        .child("README.md")
+        .write_str("# My Package (1.2.3)")
+        .unwrap();
+
+    context.git_commit_all();
+
+    seal_snapshot!(context.filters(), context.command().arg("bump").arg("patch"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Bumping version from 1.2.3 to 1.2.4
+
+    Preview of changes:
+    ────────────────────────────────────────────────────────────────────────────────
+    Source: README.md
+    ────────────┬───────────────────────────────────────────────────────────────────
+        1       │-# My Package (1.2.3)
+              1 │+# My Package (1.2.4)
+    ────────────┴───────────────────────────────────────────────────────────────────
+    Source: seal.toml
+    ────────────┬───────────────────────────────────────────────────────────────────
+        1     1 │ [release]
+        2       │-current-version = "1.2.3"
+              2 │+current-version = "1.2.4"
+        3     3 │ version-files = ["README.md"]
+        4     4 │ commit-message = "Release v{version}"
+        5     5 │ branch-name = "release/v{version}"
+        6     6 │ push = true
+    ────────────┴───────────────────────────────────────────────────────────────────
+
+    Changes to be made:
+      - Update `README.md`
+      - Update `seal.toml`
+
+    Commands to be executed:
+      `git checkout -b release/v1.2.4`
+      `git add -A`
+      `git commit -m Release v1.2.4`
+      `git push origin release/v1.2.4`
+
+    Pull request:
+      Title: Release v1.2.4
+      Head: release/v1.2.4
+      Base: main
+      Draft: false
+      Body: (empty)
+
+    Updating files...
+    Executing command: `git checkout -b release/v1.2.4`
+    Executing command: `git add -A`
+    Executing command: `git commit -m Release v1.2.4`
+    Executing command: `git push origin release/v1.2.4`
+    Pull request: https://github.com/owner/repo/pull/8
+    Successfully bumped to 1.2.4
+
+    ----- stderr -----
+    "#);
+
+    insta::assert_snapshot!(context.read_file("README.md"), @"# My Package (1.2.4)");
+    insta::assert_snapshot!(context.read_file("seal.toml"), @r#"
+    [release]
+    current-version = "1.2.4"
+    version-files = ["README.md"]
+    commit-message = "Release v{version}"
+    branch-name = "release/v{version}"
+    push = true
+    confirm = false
+
+    [release.pull-request]
+    "#);
+
+    insta::assert_snapshot!(context.git_current_branch(), @"release/v1.2.4");
+    insta::assert_snapshot!(context.git_last_commit_message(), @"Release v1.2.4");
+}
+
+#[test]
+fn bump_alpha_valid_dry_run_single_version_file() {
+    let context = TestContext::new();
+
+    context.init_git();
+
+    context.seal_toml(
+        r#"
+[release]
+current-version = "1.2.3"
+version-files = ["README.md"]
+"#,
+    );
+
+    context
+        .root
+        .child("README.md")
+        .write_str("# My Package (1.2.3)")
+        .unwrap();
+
+    seal_snapshot!(context.filters(), context.command().arg("bump").arg("alpha").arg("--dry-run"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Bumping version from 1.2.3 to 1.2.3-alpha.0
+
+    Preview of changes:
+    ────────────────────────────────────────────────────────────────────────────────
+    Source: README.md
+    ────────────┬───────────────────────────────────────────────────────────────────
+        1       │-# My Package (1.2.3)
+              1 │+# My Package (1.2.3-alpha.0)
+    ────────────┴───────────────────────────────────────────────────────────────────
+    Source: seal.toml
+    ────────────┬───────────────────────────────────────────────────────────────────
+        1     1 │ [release]
+        2       │-current-version = "1.2.3"
+              2 │+current-version = "1.2.3-alpha.0"
+        3     3 │ version-files = ["README.md"]
+    ────────────┴───────────────────────────────────────────────────────────────────
+
+    Changes to be made:
+      - Update `README.md`
+      - Update `seal.toml`
+
+    Dry run complete. No changes made.
+
+    ----- stderr -----
+    "#);
+
+    insta::assert_snapshot!(context.read_file("README.md"), @"# My Package (1.2.3)");
+
+    insta::assert_snapshot!(context.git_current_branch(), @"HEAD");
+    insta::assert_snapshot!(context.git_last_commit_message(), @"");
+}
+
+#[test]
+fn bump_alpha_already_alpha_valid_dry_run_single_version_file() {
+    let context = TestContext::new();
+
+    context.init_git();
+
+    context.seal_toml(
+        r#"
+[release]
+current-version = "1.2.3-alpha.0"
+version-files = ["README.md"]
+"#,
+    );
+
+    context
+        .root
+        .child("README.md")
+        .write_str("# My Package (1.2.3-alpha.0)")
+        .unwrap();
+
+    seal_snapshot!(context.filters(), context.command().arg("bump").arg("alpha").arg("--dry-run"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Bumping version from 1.2.3-alpha.0 to 1.2.3-alpha.1
+
+    Preview of changes:
+    ────────────────────────────────────────────────────────────────────────────────
+    Source: README.md
+    ────────────┬───────────────────────────────────────────────────────────────────
+        1       │-# My Package (1.2.3-alpha.0)
+              1 │+# My Package (1.2.3-alpha.1)
+    ────────────┴───────────────────────────────────────────────────────────────────
+    Source: seal.toml
+    ────────────┬───────────────────────────────────────────────────────────────────
+        1     1 │ [release]
+        2       │-current-version = "1.2.3-alpha.0"
+              2 │+current-version = "1.2.3-alpha.1"
+        3     3 │ version-files = ["README.md"]
+    ────────────┴───────────────────────────────────────────────────────────────────
+
+    Changes to be made:
+      - Update `README.md`
+      - Update `seal.toml`
+
+    Dry run complete. No changes made.
+
+    ----- stderr -----
+    "#);
+
+    insta::assert_snapshot!(context.read_file("README.md"), @"# My Package (1.2.3-alpha.0)");
+
+    insta::assert_snapshot!(context.git_current_branch(), @"HEAD");
+    insta::assert_snapshot!(context.git_last_commit_message(), @"");
+}
+
+#[test]
+fn bump_alpha_base_alpha_valid_dry_run_single_version_file() {
+    let context = TestContext::new();
+
+    context.init_git();
+
+    context.seal_toml(
+        r#"
+[release]
+current-version = "1.2.3-alpha"
+version-files = ["README.md"]
+"#,
+    );
+
+    context
+        .root
+        .child("README.md")
+        .write_str("# My Package (1.2.3-alpha)")
+        .unwrap();
+
+    seal_snapshot!(context.filters(), context.command().arg("bump").arg("alpha").arg("--dry-run"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Bumping version from 1.2.3-alpha to 1.2.3-alpha.1
+
+    Preview of changes:
+    ────────────────────────────────────────────────────────────────────────────────
+    Source: README.md
+    ────────────┬───────────────────────────────────────────────────────────────────
+        1       │-# My Package (1.2.3-alpha)
+              1 │+# My Package (1.2.3-alpha.1)
+    ────────────┴───────────────────────────────────────────────────────────────────
+    Source: seal.toml
+    ────────────┬───────────────────────────────────────────────────────────────────
+        1     1 │ [release]
+        2       │-current-version = "1.2.3-alpha"
+              2 │+current-version = "1.2.3-alpha.1"
+        3     3 │ version-files = ["README.md"]
+    ────────────┴───────────────────────────────────────────────────────────────────
+
+    Changes to be made:
+      - Update `README.md`
+      - Update `seal.toml`
+
+    Dry run complete. No changes made.
+
+    ----- stderr -----
+    "#);
+
+    insta::assert_snapshot!(context.read_file("README.md"), @"# My Package (1.2.3-alpha)");
+
+    insta::assert_snapshot!(context.git_current_branch(), @"HEAD");
+    insta::assert_snapshot!(context.git_last_commit_message(), @"");
+}
+
+#[test]
+fn bump_alpha_invalid_alpha_valid_dry_run_single_version_file() {
+    let context = TestContext::new();
+
+    context.init_git();
+
+    context.seal_toml(
+        r#"
+[release]
+current-version = "1.2.3-alpha.-1"
+version-files = ["README.md"]
+"#,
+    );
+
+    context
+        .root
+        .child("README.md")
+        .write_str("# My Package (1.2.3-alpha.-1)")
+        .unwrap();
+
+    seal_snapshot!(context.filters(), context.command().arg("bump").arg("alpha").arg("--dry-run"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: malformed version: 'Invalid prerelease number in: alpha.-1'. Expected format 'X.Y.Z' where X, Y, and Z are non-negative integers
+    ");
+
+    insta::assert_snapshot!(context.read_file("README.md"), @"# My Package (1.2.3-alpha.-1)");
+
+    insta::assert_snapshot!(context.git_current_branch(), @"HEAD");
+    insta::assert_snapshot!(context.git_last_commit_message(), @"");
+}
+
+#[test]
+fn bump_with_pre_commit_commands_dry_run() {
+    let context = TestContext::new();
+
+    context.init_git();
+
+    context.seal_toml(
+        r#"
+[release]
+current-version = "1.2.3"
+version-files = ["README.md"]
+commit-message = "Release v{version}"
+pre-commit-commands = ["touch pre-commit-ran.txt", "echo done"]
+"#,
+    );
+
+    context
+        .root
+        .child("README.md")
+        .write_str("# My Package (1.2.3)")
+        .unwrap();
+
+    context.git_commit_all();
+
+    seal_snapshot!(context.filters(), context.command().arg("bump").arg("patch").arg("--dry-run"), @r#"
     success: true
     exit_code: 0
     ----- stdout -----
@@ -623,6 +2427,7 @@ commit-message = "Release v{version}"
               2 │+current-version = "1.2.4"
         3     3 │ version-files = ["README.md"]
         4     4 │ commit-message = "Release v{version}"
+        5     5 │ pre-commit-commands = ["touch pre-commit-ran.txt", "echo done"]
     ────────────┴───────────────────────────────────────────────────────────────────
 
     Changes to be made:
@@ -630,32 +2435,23 @@ commit-message = "Release v{version}"
       - Update `seal.toml`
 
     Commands to be executed:
+      `git add -A`
+      `touch pre-commit-ran.txt`
+      `echo done`
       `git add -A`
       `git commit -m Release v1.2.4`
 
-    Proceed with these changes? (y/n):
-    Updating files...
-    Executing command: `git add -A`
-    Executing command: `git commit -m Release v1.2.4`
-    Successfully bumped to 1.2.4
+    Dry run complete. No changes made.
 
     ----- stderr -----
     "#);
 
-    insta::assert_snapshot!(context.read_file("README.md"), @"# My Package (1.2.4)");
-    insta::assert_snapshot!(context.read_file("seal.toml"), @r#"
-    [release]
-    current-version = "1.2.4"
-    version-files = ["README.md"]
-    commit-message = "Release v{version}"
-    "#);
-
     insta::assert_snapshot!(context.git_current_branch(), @"main");
-    insta::assert_snapshot!(context.git_last_commit_message(), @"Release v1.2.4");
+    insta::assert_snapshot!(context.git_last_commit_message(), @"Set up test");
 }
 
 #[test]
-fn bump_git_commit_failure_includes_hook_output() {
+fn bump_with_pre_commit_commands_execute() {
     let context = TestContext::new();
 
     context.init_git();
@@ -664,60 +2460,72 @@ fn bump_git_commit_failure_includes_hook_output() {
         r#"
 [release]
 current-version = "1.2.3"
+version-files = ["README.md"]
 commit-message = "Release v{version}"
-confirm = false
+pre-commit-commands = ["touch pre-commit-ran.txt"]
 "#,
     );
 
-    let hook = context.root.child(".git/hooks/pre-commit");
-    hook.write_str("#!/bin/sh\necho 'pre-commit hook failed'\nexit 1\n")
+    context
+        .root
+        .child("README.md")
+        .write_str("# My Package (1.2.3)")
         .unwrap();
 
-    #[cfg(unix)]
-    {
-        let mut permissions = std::fs::metadata(hook.path()).unwrap().permissions();
-        permissions.set_mode(0o755);
-        std::fs::set_permissions(hook.path(), permissions).unwrap();
-    }
+    context.git_commit_all();
 
-    seal_snapshot!(context.filters(), context.command().arg("bump").arg("patch"), @r#"
-    success: false
-    exit_code: 2
+    seal_snapshot!(context.filters(), context.command().arg("bump").arg("patch").write_stdin("y\n"), @r#"
+    success: true
+    exit_code: 0
     ----- stdout -----
     Bumping version from 1.2.3 to 1.2.4
 
     Preview of changes:
     ────────────────────────────────────────────────────────────────────────────────
+    Source: README.md
+    ────────────┬───────────────────────────────────────────────────────────────────
+        1       │-# My Package (1.2.3)
+              1 │+# My Package (1.2.4)
+    ────────────┴───────────────────────────────────────────────────────────────────
     Source: seal.toml
     ────────────┬───────────────────────────────────────────────────────────────────
         1     1 │ [release]
         2       │-current-version = "1.2.3"
               2 │+current-version = "1.2.4"
-        3     3 │ commit-message = "Release v{version}"
-        4     4 │ confirm = false
+        3     3 │ version-files = ["README.md"]
+        4     4 │ commit-message = "Release v{version}"
+        5     5 │ pre-commit-commands = ["touch pre-commit-ran.txt"]
     ────────────┴───────────────────────────────────────────────────────────────────
 
     Changes to be made:
+      - Update `README.md`
       - Update `seal.toml`
 
     Commands to be executed:
+      `git add -A`
+      `touch pre-commit-ran.txt`
       `git add -A`
       `git commit -m Release v1.2.4`
 
+    Proceed with these changes? (y/n):
     Updating files...
     Executing command: `git add -A`
+    Executing command: `touch pre-commit-ran.txt`
+    Executing command: `git add -A`
     Executing command: `git commit -m Release v1.2.4`
+    Successfully bumped to 1.2.4
 
     ----- stderr -----
-    error: Command `git commit -m Release v1.2.4` failed (exit code 1)
-    pre-commit hook failed
     "#);
 
-    insta::assert_snapshot!(context.git_last_commit_message(), @"");
+    insta::assert_snapshot!(context.read_file("README.md"), @"# My Package (1.2.4)");
+    assert!(context.root.join("pre-commit-ran.txt").exists());
+    insta::assert_snapshot!(context.git_current_branch(), @"main");
+    insta::assert_snapshot!(context.git_last_commit_message(), @"Release v1.2.4");
 }
 
 #[test]
-fn bump_patch_valid_commit_branch() {
+fn bump_with_pre_commit_commands_no_confirm() {
     let context = TestContext::new();
 
     context.init_git();
@@ -728,7 +2536,8 @@ fn bump_patch_valid_commit_branch() {
 current-version = "1.2.3"
 version-files = ["README.md"]
 commit-message = "Release v{version}"
-branch-name = "release/v{version}"
+pre-commit-commands = ["touch pre-commit-ran.txt"]
+confirm = false
 "#,
     );
 
@@ -738,7 +2547,9 @@ branch-name = "release/v{version}"
         .write_str("# My Package (1.2.3)")
         .unwrap();
 
-    seal_snapshot!(context.filters(), context.command().arg("bump").arg("patch").write_stdin("y\n"), @r#"
+    context.git_commit_all();
+
+    seal_snapshot!(context.filters(), context.command().arg("bump").arg("patch"), @r#"
     success: true
     exit_code: 0
     ----- stdout -----
@@ -758,7 +2569,8 @@ branch-name = "release/v{version}"
               2 │+current-version = "1.2.4"
         3     3 │ version-files = ["README.md"]
         4     4 │ commit-message = "Release v{version}"
-        5     5 │ branch-name = "release/v{version}"
+        5     5 │ pre-commit-commands = ["touch pre-commit-ran.txt"]
+        6     6 │ confirm = false
     ────────────┴───────────────────────────────────────────────────────────────────
 
     Changes to be made:
@@ -766,13 +2578,14 @@ branch-name = "release/v{version}"
       - Update `seal.toml`
 
     Commands to be executed:
-      `git checkout -b release/v1.2.4`
+      `git add -A`
+      `touch pre-commit-ran.txt`
       `git add -A`
       `git commit -m Release v1.2.4`
 
-    Proceed with these changes? (y/n):
     Updating files...
-    Executing command: `git checkout -b release/v1.2.4`
+    Executing command: `git add -A`
+    Executing command: `touch pre-commit-ran.txt`
     Executing command: `git add -A`
     Executing command: `git commit -m Release v1.2.4`
     Successfully bumped to 1.2.4
@@ -781,20 +2594,13 @@ branch-name = "release/v{version}"
     "#);
 
     insta::assert_snapshot!(context.read_file("README.md"), @"# My Package (1.2.4)");
-    insta::assert_snapshot!(context.read_file("seal.toml"), @r#"
-    [release]
-    current-version = "1.2.4"
-    version-files = ["README.md"]
-    commit-message = "Release v{version}"
-    branch-name = "release/v{version}"
-    "#);
-
-    insta::assert_snapshot!(context.git_current_branch(), @"release/v1.2.4");
+    assert!(context.root.join("pre-commit-ran.txt").exists());
+    insta::assert_snapshot!(context.git_current_branch(), @"main");
     insta::assert_snapshot!(context.git_last_commit_message(), @"Release v1.2.4");
 }
 
 #[test]
-fn bump_patch_valid_branch() {
+fn bump_pre_commit_command_fails_abort_mode() {
     let context = TestContext::new();
 
     context.init_git();
@@ -804,7 +2610,9 @@ fn bump_patch_valid_branch() {
 [release]
 current-version = "1.2.3"
 version-files = ["README.md"]
-branch-name = "release/v{version}"
+commit-message = "Release v{version}"
+pre-commit-commands = ["false"]
+confirm = false
 "#,
     );
 
@@ -814,9 +2622,11 @@ branch-name = "release/v{version}"
         .write_str("# My Package (1.2.3)")
         .unwrap();
 
-    seal_snapshot!(context.filters(), context.command().arg("bump").arg("patch").write_stdin("y\n"), @r#"
-    success: true
-    exit_code: 0
+    context.git_commit_all();
+
+    seal_snapshot!(context.filters(), context.command().arg("bump").arg("patch"), @r#"
+    success: false
+    exit_code: 2
     ----- stdout -----
     Bumping version from 1.2.3 to 1.2.4
 
@@ -833,7 +2643,9 @@ branch-name = "release/v{version}"
         2       │-current-version = "1.2.3"
               2 │+current-version = "1.2.4"
         3     3 │ version-files = ["README.md"]
-        4     4 │ branch-name = "release/v{version}"
+        4     4 │ commit-message = "Release v{version}"
+        5     5 │ pre-commit-commands = ["false"]
+        6     6 │ confirm = false
     ────────────┴───────────────────────────────────────────────────────────────────
 
     Changes to be made:
@@ -841,30 +2653,24 @@ branch-name = "release/v{version}"
       - Update `seal.toml`
 
     Commands to be executed:
-      `git checkout -b release/v1.2.4`
+      `git add -A`
+      `false`
+      `git add -A`
+      `git commit -m Release v1.2.4`
 
-    Proceed with these changes? (y/n):
     Updating files...
-    Executing command: `git checkout -b release/v1.2.4`
-    Successfully bumped to 1.2.4
+    Executing command: `git add -A`
+    Executing command: `false`
 
     ----- stderr -----
+    error: Command `false` failed (exit code 1)
     "#);
 
-    insta::assert_snapshot!(context.read_file("README.md"), @"# My Package (1.2.4)");
-    insta::assert_snapshot!(context.read_file("seal.toml"), @r#"
-    [release]
-    current-version = "1.2.4"
-    version-files = ["README.md"]
-    branch-name = "release/v{version}"
-    "#);
-
-    insta::assert_snapshot!(context.git_current_branch(), @"HEAD");
-    insta::assert_snapshot!(context.git_last_commit_message(), @"");
+    insta::assert_snapshot!(context.git_last_commit_message(), @"Set up test");
 }
 
 #[test]
-fn bump_patch_valid_commit_branch_push() {
+fn bump_pre_commit_command_fails_continue_mode() {
     let context = TestContext::new();
 
     context.init_git();
@@ -875,8 +2681,9 @@ fn bump_patch_valid_commit_branch_push() {
 current-version = "1.2.3"
 version-files = ["README.md"]
 commit-message = "Release v{version}"
-branch-name = "release/v{version}"
-push = true
+pre-commit-commands = ["false", "touch success.txt"]
+on-pre-commit-failure = "continue"
+confirm = false
 "#,
     );
 
@@ -886,9 +2693,11 @@ push = true
         .write_str("# My Package (1.2.3)")
         .unwrap();
 
-    seal_snapshot!(context.filters(), context.command().arg("bump").arg("patch").write_stdin("y\n"), @r#"
-    success: false
-    exit_code: 2
+    context.git_commit_all();
+
+    seal_snapshot!(context.filters(), context.command().arg("bump").arg("patch"), @r#"
+    success: true
+    exit_code: 0
     ----- stdout -----
     Bumping version from 1.2.3 to 1.2.4
 
@@ -906,8 +2715,8 @@ push = true
               2 │+current-version = "1.2.4"
         3     3 │ version-files = ["README.md"]
         4     4 │ commit-message = "Release v{version}"
-        5     5 │ branch-name = "release/v{version}"
-        6     6 │ push = true
+        5     5 │ pre-commit-commands = ["false", "touch success.txt"]
+        6     6 │ on-pre-commit-failure = "continue"
     ────────────┴───────────────────────────────────────────────────────────────────
 
     Changes to be made:
@@ -915,43 +2724,91 @@ push = true
       - Update `seal.toml`
 
     Commands to be executed:
-      `git checkout -b release/v1.2.4`
+      `git add -A`
+      `false`
+      `touch success.txt`
       `git add -A`
       `git commit -m Release v1.2.4`
-      `git push origin release/v1.2.4`
 
-    Proceed with these changes? (y/n):
     Updating files...
-    Executing command: `git checkout -b release/v1.2.4`
+    Executing command: `git add -A`
+    Executing command: `false`
+    Warning: Command `false` failed (exit code 1), continuing...
+    Executing command: `touch success.txt`
     Executing command: `git add -A`
     Executing command: `git commit -m Release v1.2.4`
-    Executing command: `git push origin release/v1.2.4`
+    Successfully bumped to 1.2.4
+
+    ----- stderr -----
+    "#);
+
+    insta::assert_snapshot!(context.read_file("README.md"), @"# My Package (1.2.4)");
+    assert!(context.root.join("success.txt").exists());
+    insta::assert_snapshot!(context.git_current_branch(), @"main");
+    insta::assert_snapshot!(context.git_last_commit_message(), @"Release v1.2.4");
+}
+
+#[test]
+fn bump_pre_commit_command_with_quoted_arguments() {
+    let context = TestContext::new();
+
+    context.init_git();
+
+    context.seal_toml(
+        r#"
+[release]
+current-version = "1.2.3"
+commit-message = "Release v{version}"
+pre-commit-commands = ["touch 'file with spaces.txt'"]
+confirm = false
+"#,
+    );
+
+    context.git_commit_all();
+
+    seal_snapshot!(context.filters(), context.command().arg("bump").arg("patch"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Bumping version from 1.2.3 to 1.2.4
+
+    Preview of changes:
+    ────────────────────────────────────────────────────────────────────────────────
+    Source: seal.toml
+    ────────────┬───────────────────────────────────────────────────────────────────
+        1     1 │ [release]
+        2       │-current-version = "1.2.3"
+              2 │+current-version = "1.2.4"
+        3     3 │ commit-message = "Release v{version}"
+        4     4 │ pre-commit-commands = ["touch 'file with spaces.txt'"]
+        5     5 │ confirm = false
+    ────────────┴───────────────────────────────────────────────────────────────────
+
+    Changes to be made:
+      - Update `seal.toml`
+
+    Commands to be executed:
+      `git add -A`
+      `touch 'file with spaces.txt'`
+      `git add -A`
+      `git commit -m Release v1.2.4`
+
+    Updating files...
+    Executing command: `git add -A`
+    Executing command: `touch 'file with spaces.txt'`
+    Executing command: `git add -A`
+    Executing command: `git commit -m Release v1.2.4`
+    Successfully bumped to 1.2.4
 
     ----- stderr -----
-    error: Command `git push origin release/v1.2.4` failed (exit code 128)
-    fatal: 'origin' does not appear to be a git repository
-    fatal: Could not read from remote repository.
-
-    Please make sure you have the correct access rights
-    and the repository exists.
-    "#);
-
-    insta::assert_snapshot!(context.read_file("README.md"), @"# My Package (1.2.4)");
-    insta::assert_snapshot!(context.read_file("seal.toml"), @r#"
-    [release]
-    current-version = "1.2.4"
-    version-files = ["README.md"]
-    commit-message = "Release v{version}"
-    branch-name = "release/v{version}"
-    push = true
     "#);
 
-    insta::assert_snapshot!(context.git_current_branch(), @"release/v1.2.4");
+    assert!(context.root.join("file with spaces.txt").exists());
     insta::assert_snapshot!(context.git_last_commit_message(), @"Release v1.2.4");
 }
 
 #[test]
-fn bump_patch_valid_commit_branch_push_pr() {
+fn bump_pre_commit_shell_command_streams_output() {
     let context = TestContext::new();
 
     context.init_git();
@@ -960,85 +2817,62 @@ fn bump_patch_valid_commit_branch_push_pr() {
         r#"
 [release]
 current-version = "1.2.3"
-version-files = ["README.md"]
 commit-message = "Release v{version}"
-branch-name = "release/v{version}"
-push = true
+pre-commit-commands = [
+    { command = "echo building && echo 'warning: slow build' >&2 && echo done > built.txt", shell = true },
+]
+confirm = false
 "#,
     );
 
-    context
-        .root
-        .child("README.md")
-        .write_str("# My Package (1.2.3)")
-        .unwrap();
+    context.git_commit_all();
 
-    seal_snapshot!(context.filters(), context.command().arg("bump").arg("patch").write_stdin("y\n"), @r#"
-    success: false
-    exit_code: 2
+    seal_snapshot!(context.filters(), context.command().arg("bump").arg("patch"), @r#"
+    success: true
+    exit_code: 0
     ----- stdout -----
     Bumping version from 1.2.3 to 1.2.4
 
     Preview of changes:
     ────────────────────────────────────────────────────────────────────────────────
-    Source: README.md
-    ────────────┬───────────────────────────────────────────────────────────────────
-        1       │-# My Package (1.2.3)
-              1 │+# My Package (1.2.4)
-    ────────────┴───────────────────────────────────────────────────────────────────
     Source: seal.toml
     ────────────┬───────────────────────────────────────────────────────────────────
         1     1 │ [release]
         2       │-current-version = "1.2.3"
               2 │+current-version = "1.2.4"
-        3     3 │ version-files = ["README.md"]
-        4     4 │ commit-message = "Release v{version}"
-        5     5 │ branch-name = "release/v{version}"
-        6     6 │ push = true
+        3     3 │ commit-message = "Release v{version}"
+        4     4 │ pre-commit-commands = [
+        5     5 │     { command = "echo building && echo 'warning: slow build' >&2 && echo done > built.txt", shell = true },
+        6     6 │ ]
     ────────────┴───────────────────────────────────────────────────────────────────
 
     Changes to be made:
-      - Update `README.md`
       - Update `seal.toml`
 
     Commands to be executed:
-      `git checkout -b release/v1.2.4`
+      `git add -A`
+      `echo building && echo 'warning: slow build' >&2 && echo done > built.txt`
       `git add -A`
       `git commit -m Release v1.2.4`
-      `git push origin release/v1.2.4`
 
-    Proceed with these changes? (y/n):
     Updating files...
-    Executing command: `git checkout -b release/v1.2.4`
+    Executing command: `git add -A`
+    Executing command: `echo building && echo 'warning: slow build' >&2 && echo done > built.txt`
+    building
     Executing command: `git add -A`
     Executing command: `git commit -m Release v1.2.4`
-    Executing command: `git push origin release/v1.2.4`
+    Successfully bumped to 1.2.4
 
     ----- stderr -----
-    error: Command `git push origin release/v1.2.4` failed (exit code 128)
-    fatal: 'origin' does not appear to be a git repository
-    fatal: Could not read from remote repository.
-
-    Please make sure you have the correct access rights
-    and the repository exists.
-    "#);
-
-    insta::assert_snapshot!(context.read_file("README.md"), @"# My Package (1.2.4)");
-    insta::assert_snapshot!(context.read_file("seal.toml"), @r#"
-    [release]
-    current-version = "1.2.4"
-    version-files = ["README.md"]
-    commit-message = "Release v{version}"
-    branch-name = "release/v{version}"
-    push = true
+    warning: slow build
     "#);
 
-    insta::assert_snapshot!(context.git_current_branch(), @"release/v1.2.4");
+    insta::assert_snapshot!(context.read_file("built.txt"), @"done");
     insta::assert_snapshot!(context.git_last_commit_message(), @"Release v1.2.4");
 }
 
 #[test]
-fn bump_patch_valid_commit_branch_push_pr_no_confirm() {
+fn bump_pre_commit_command_table_form() {
     let context = TestContext::new();
 
     context.init_git();
@@ -1047,86 +2881,62 @@ fn bump_patch_valid_commit_branch_push_pr_no_confirm() {
         r#"
 [release]
 current-version = "1.2.3"
-version-files = ["README.md"]
 commit-message = "Release v{version}"
-branch-name = "release/v{version}"
-push = true
+pre-commit-commands = [
+    { command = "sh", args = ["-c", "printf '%s' \"$RELEASE_NAME\" > name.txt"], cwd = "web", env = { RELEASE_NAME = "seal release" } },
+]
 confirm = false
 "#,
     );
 
-    context
-        .root
-        .child("README.md")
-        .write_str("# My Package (1.2.3)")
-        .unwrap();
+    context.root.child("web/.gitkeep").touch().unwrap();
+
+    context.git_commit_all();
 
     seal_snapshot!(context.filters(), context.command().arg("bump").arg("patch"), @r#"
-    success: false
-    exit_code: 2
+    success: true
+    exit_code: 0
     ----- stdout -----
     Bumping version from 1.2.3 to 1.2.4
 
     Preview of changes:
     ────────────────────────────────────────────────────────────────────────────────
-    Source: README.md
-    ────────────┬───────────────────────────────────────────────────────────────────
-        1       │-# My Package (1.2.3)
-              1 │+# My Package (1.2.4)
-    ────────────┴───────────────────────────────────────────────────────────────────
     Source: seal.toml
     ────────────┬───────────────────────────────────────────────────────────────────
         1     1 │ [release]
         2       │-current-version = "1.2.3"
               2 │+current-version = "1.2.4"
-        3     3 │ version-files = ["README.md"]
-        4     4 │ commit-message = "Release v{version}"
-        5     5 │ branch-name = "release/v{version}"
-        6     6 │ push = true
+        3     3 │ commit-message = "Release v{version}"
+        4     4 │ pre-commit-commands = [
+        5     5 │     { command = "sh", args = ["-c", "printf '%s' /"$RELEASE_NAME/" > name.txt"], cwd = "web", env = { RELEASE_NAME = "seal release" } },
+        6     6 │ ]
     ────────────┴───────────────────────────────────────────────────────────────────
 
     Changes to be made:
-      - Update `README.md`
       - Update `seal.toml`
 
     Commands to be executed:
-      `git checkout -b release/v1.2.4`
+      `git add -A`
+      `sh -c "printf '%s' /""'$RELEASE_NAME" > name.txt'`
       `git add -A`
       `git commit -m Release v1.2.4`
-      `git push origin release/v1.2.4`
 
     Updating files...
-    Executing command: `git checkout -b release/v1.2.4`
+    Executing command: `git add -A`
+    Executing command: `sh -c "printf '%s' /""'$RELEASE_NAME" > name.txt'`
     Executing command: `git add -A`
     Executing command: `git commit -m Release v1.2.4`
-    Executing command: `git push origin release/v1.2.4`
+    Successfully bumped to 1.2.4
 
     ----- stderr -----
-    error: Command `git push origin release/v1.2.4` failed (exit code 128)
-    fatal: 'origin' does not appear to be a git repository
-    fatal: Could not read from remote repository.
-
-    Please make sure you have the correct access rights
-    and the repository exists.
-    "#);
-
-    insta::assert_snapshot!(context.read_file("README.md"), @"# My Package (1.2.4)");
-    insta::assert_snapshot!(context.read_file("seal.toml"), @r#"
-    [release]
-    current-version = "1.2.4"
-    version-files = ["README.md"]
-    commit-message = "Release v{version}"
-    branch-name = "release/v{version}"
-    push = true
-    confirm = false
     "#);
 
-    insta::assert_snapshot!(context.git_current_branch(), @"release/v1.2.4");
+    insta::assert_snapshot!(context.read_file("web/name.txt"), @"seal release");
     insta::assert_snapshot!(context.git_last_commit_message(), @"Release v1.2.4");
 }
 
 #[test]
-fn bump_pull_request_not_created_when_push_fails() {
+fn bump_pre_commit_command_timeout() {
     let context = TestContext::new();
 
     context.init_git();
@@ -1135,22 +2945,15 @@ fn bump_pull_request_not_created_when_push_fails() {
         r#"
 [release]
 current-version = "1.2.3"
-version-files = ["README.md"]
 commit-message = "Release v{version}"
-branch-name = "release/v{version}"
-push = true
-
-[release.pull-request]
+pre-commit-commands = [{ command = "sleep 10", timeout = 1 }]
+confirm = false
 "#,
     );
 
-    context
-        .root
-        .child("README.md")
-        .write_str("# My Package (1.2.3)")
-        .unwrap();
+    context.git_commit_all();
 
-    seal_snapshot!(context.filters(), context.command().arg("bump").arg("patch").write_stdin("y\n"), @r#"
+    seal_snapshot!(context.filters(), context.command().arg("bump").arg("patch"), @r#"
     success: false
     exit_code: 2
     ----- stdout -----
@@ -1158,130 +2961,99 @@ push = true
 
     Preview of changes:
     ────────────────────────────────────────────────────────────────────────────────
-    Source: README.md
-    ────────────┬───────────────────────────────────────────────────────────────────
-        1       │-# My Package (1.2.3)
-              1 │+# My Package (1.2.4)
-    ────────────┴───────────────────────────────────────────────────────────────────
     Source: seal.toml
     ────────────┬───────────────────────────────────────────────────────────────────
         1     1 │ [release]
         2       │-current-version = "1.2.3"
               2 │+current-version = "1.2.4"
-        3     3 │ version-files = ["README.md"]
-        4     4 │ commit-message = "Release v{version}"
-        5     5 │ branch-name = "release/v{version}"
-        6     6 │ push = true
+        3     3 │ commit-message = "Release v{version}"
+        4     4 │ pre-commit-commands = [{ command = "sleep 10", timeout = 1 }]
+        5     5 │ confirm = false
     ────────────┴───────────────────────────────────────────────────────────────────
 
     Changes to be made:
-      - Update `README.md`
       - Update `seal.toml`
 
     Commands to be executed:
-      `git checkout -b release/v1.2.4`
+      `git add -A`
+      `sleep 10`
       `git add -A`
       `git commit -m Release v1.2.4`
-      `git push origin release/v1.2.4`
-
-    Pull request:
-      Title: Release v1.2.4
-      Head: release/v1.2.4
-      Base: main
-      Draft: false
-      Body: (empty)
 
-    Proceed with these changes? (y/n):
     Updating files...
-    Executing command: `git checkout -b release/v1.2.4`
     Executing command: `git add -A`
-    Executing command: `git commit -m Release v1.2.4`
-    Executing command: `git push origin release/v1.2.4`
+    Executing command: `sleep 10`
 
     ----- stderr -----
-    error: Command `git push origin release/v1.2.4` failed (exit code 128)
-    fatal: 'origin' does not appear to be a git repository
-    fatal: Could not read from remote repository.
-
-    Please make sure you have the correct access rights
-    and the repository exists.
-    "#);
-
-    insta::assert_snapshot!(context.read_file("README.md"), @"# My Package (1.2.4)");
-    insta::assert_snapshot!(context.read_file("seal.toml"), @r#"
-    [release]
-    current-version = "1.2.4"
-    version-files = ["README.md"]
-    commit-message = "Release v{version}"
-    branch-name = "release/v{version}"
-    push = true
-
-    [release.pull-request]
+    error: Command `sleep 10` failed (timed out after 1s)
     "#);
 
-    insta::assert_snapshot!(context.git_current_branch(), @"release/v1.2.4");
-    insta::assert_snapshot!(context.git_last_commit_message(), @"Release v1.2.4");
+    insta::assert_snapshot!(context.git_last_commit_message(), @"Set up test");
 }
 
 #[test]
-fn bump_pull_request_authentication_failure_makes_no_changes() {
+fn bump_release_hooks_run_in_lifecycle_order() {
     let context = TestContext::new();
 
-    context.init_git();
+    context.init_git().init_git_remote();
 
     context.seal_toml(
         r#"
 [release]
 current-version = "1.2.3"
-version-files = ["README.md"]
 commit-message = "Release v{version}"
 branch-name = "release/v{version}"
 push = true
 confirm = false
 
 [release.pull-request]
+
+[release.hooks]
+pre-bump = [{ command = "echo \"pre-bump $SEAL_PREVIOUS_VERSION\" >> .git/hooks.log", shell = true }]
+post-files = [{ command = "echo \"post-files $SEAL_VERSION\" >> .git/hooks.log", shell = true }]
+pre-commit = [{ command = "echo \"pre-commit $SEAL_BRANCH\" >> .git/hooks.log", shell = true }]
+post-commit = [{ command = "echo post-commit >> .git/hooks.log", shell = true }]
+post-push = [{ command = "echo post-push >> .git/hooks.log", shell = true }]
+post-pull-request = [{ command = "echo \"post-pull-request $SEAL_PR_URL\" >> .git/hooks.log", shell = true }]
 "#,
     );
 
-    context
-        .root
-        .child("README.md")
-        .write_str("# My Package (1.2.3)")
-        .unwrap();
+    context.git_commit_all();
 
-    seal_snapshot!(context.filters(), context.command().env("SEAL_TEST_GITHUB_AUTHENTICATION_REQUIRED", "1").arg("bump").arg("patch"), @r#"
-    success: false
-    exit_code: 2
+    seal_snapshot!(context.filters(), context.command().arg("bump").arg("patch"), @r#"
+    success: true
+    exit_code: 0
     ----- stdout -----
+    Executing command: `echo "pre-bump $SEAL_PREVIOUS_VERSION" >> .git/hooks.log`
     Bumping version from 1.2.3 to 1.2.4
 
     Preview of changes:
     ────────────────────────────────────────────────────────────────────────────────
-    Source: README.md
-    ────────────┬───────────────────────────────────────────────────────────────────
-        1       │-# My Package (1.2.3)
-              1 │+# My Package (1.2.4)
-    ────────────┴───────────────────────────────────────────────────────────────────
     Source: seal.toml
     ────────────┬───────────────────────────────────────────────────────────────────
         1     1 │ [release]
         2       │-current-version = "1.2.3"
               2 │+current-version = "1.2.4"
-        3     3 │ version-files = ["README.md"]
-        4     4 │ commit-message = "Release v{version}"
-        5     5 │ branch-name = "release/v{version}"
-        6     6 │ push = true
+        3     3 │ commit-message = "Release v{version}"
+        4     4 │ branch-name = "release/v{version}"
+        5     5 │ push = true
+        6     6 │ confirm = false
     ────────────┴───────────────────────────────────────────────────────────────────
 
     Changes to be made:
-      - Update `README.md`
       - Update `seal.toml`
 
     Commands to be executed:
+      `echo "post-files $SEAL_VERSION" >> .git/hooks.log`
       `git checkout -b release/v1.2.4`
       `git add -A`
+      `echo "pre-commit $SEAL_BRANCH" >> .git/hooks.log`
+      `git add -A`
       `git commit -m Release v1.2.4`
+      `echo post-commit >> .git/hooks.log`
       `git push origin release/v1.2.4`
+      `echo post-push >> .git/hooks.log`
+      `echo "post-pull-request $SEAL_PR_URL" >> .git/hooks.log`
 
     Pull request:
       Title: Release v1.2.4
@@ -1290,30 +3062,36 @@ confirm = false
       Draft: false
       Body: (empty)
 
+    Updating files...
+    Executing command: `echo "post-files $SEAL_VERSION" >> .git/hooks.log`
+    Executing command: `git checkout -b release/v1.2.4`
+    Executing command: `git add -A`
+    Executing command: `echo "pre-commit $SEAL_BRANCH" >> .git/hooks.log`
+    Executing command: `git add -A`
+    Executing command: `git commit -m Release v1.2.4`
+    Executing command: `echo post-commit >> .git/hooks.log`
+    Executing command: `git push origin release/v1.2.4`
+    Executing command: `echo post-push >> .git/hooks.log`
+    Pull request: https://github.com/owner/repo/pull/8
+    Executing command: `echo "post-pull-request $SEAL_PR_URL" >> .git/hooks.log`
+    Successfully bumped to 1.2.4
 
     ----- stderr -----
-    error: GitHub authentication is required; set GITHUB_TOKEN or GH_TOKEN
-    "#);
-
-    insta::assert_snapshot!(context.read_file("README.md"), @"# My Package (1.2.3)");
-    insta::assert_snapshot!(context.read_file("seal.toml"), @r#"
-    [release]
-    current-version = "1.2.3"
-    version-files = ["README.md"]
-    commit-message = "Release v{version}"
-    branch-name = "release/v{version}"
-    push = true
-    confirm = false
-
-    [release.pull-request]
     "#);
 
-    insta::assert_snapshot!(context.git_current_branch(), @"HEAD");
-    insta::assert_snapshot!(context.git_last_commit_message(), @"");
+    insta::assert_snapshot!(context.read_file(".git/hooks.log"), @r"
+    pre-bump 1.2.3
+    post-files 1.2.4
+    pre-commit release/v1.2.4
+    post-commit
+    post-push
+    post-pull-request https://github.com/owner/repo/pull/8
+    ");
+    insta::assert_snapshot!(context.git_last_commit_message(), @"Release v1.2.4");
 }
 
 #[test]
-fn bump_pull_request_dry_run_prints_defaults() {
+fn bump_pre_bump_hook_failure_aborts_before_version_calculation() {
     let context = TestContext::new();
 
     context.init_git();
@@ -1322,79 +3100,39 @@ fn bump_pull_request_dry_run_prints_defaults() {
         r#"
 [release]
 current-version = "1.2.3"
-version-files = ["README.md"]
 commit-message = "Release v{version}"
-branch-name = "release/v{version}"
-push = true
+confirm = false
 
-[release.pull-request]
+[release.hooks]
+pre-bump = ["false"]
 "#,
-    );
-
-    context
-        .root
-        .child("README.md")
-        .write_str("# My Package (1.2.3)")
-        .unwrap();
-
-    seal_snapshot!(context.filters(), context.command().arg("bump").arg("patch").arg("--dry-run"), @r#"
-    success: true
-    exit_code: 0
-    ----- stdout -----
-    Bumping version from 1.2.3 to 1.2.4
-
-    Preview of changes:
-    ────────────────────────────────────────────────────────────────────────────────
-    Source: README.md
-    ────────────┬───────────────────────────────────────────────────────────────────
-        1       │-# My Package (1.2.3)
-              1 │+# My Package (1.2.4)
-    ────────────┴───────────────────────────────────────────────────────────────────
-    Source: seal.toml
-    ────────────┬───────────────────────────────────────────────────────────────────
-        1     1 │ [release]
-        2       │-current-version = "1.2.3"
-              2 │+current-version = "1.2.4"
-        3     3 │ version-files = ["README.md"]
-        4     4 │ commit-message = "Release v{version}"
-        5     5 │ branch-name = "release/v{version}"
-        6     6 │ push = true
-    ────────────┴───────────────────────────────────────────────────────────────────
-
-    Changes to be made:
-      - Update `README.md`
-      - Update `seal.toml`
+    );
 
-    Pull request:
-      Title: Release v1.2.4
-      Head: release/v1.2.4
-      Base: main
-      Draft: false
-      Body: (empty)
+    context.git_commit_all();
 
-    Dry run complete. No changes made.
+    seal_snapshot!(context.filters(), context.command().arg("bump").arg("patch"), @r#"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+    Executing command: `false`
 
     ----- stderr -----
+    error: Command `false` failed (exit code 1)
     "#);
 
-    insta::assert_snapshot!(context.read_file("README.md"), @"# My Package (1.2.3)");
     insta::assert_snapshot!(context.read_file("seal.toml"), @r#"
     [release]
     current-version = "1.2.3"
-    version-files = ["README.md"]
     commit-message = "Release v{version}"
-    branch-name = "release/v{version}"
-    push = true
+    confirm = false
 
-    [release.pull-request]
+    [release.hooks]
+    pre-bump = ["false"]
     "#);
-
-    insta::assert_snapshot!(context.git_current_branch(), @"HEAD");
-    insta::assert_snapshot!(context.git_last_commit_message(), @"");
 }
 
 #[test]
-fn bump_pull_request_dry_run_prints_custom_options() {
+fn bump_dry_run_lists_pre_bump_hooks_without_running_them() {
     let context = TestContext::new();
 
     context.init_git();
@@ -1404,16 +3142,15 @@ fn bump_pull_request_dry_run_prints_custom_options() {
 [release]
 current-version = "1.2.3"
 commit-message = "Release v{version}"
-branch-name = "release/v{version}"
-push = true
-[release.pull-request]
-title = "Ship {version}"
-body = "Prepare release {version}.\n\nReview before merging."
-base = "stable"
-draft = true
+confirm = false
+
+[release.hooks]
+pre-bump = ["false"]
 "#,
     );
 
+    context.git_commit_all();
+
     seal_snapshot!(context.filters(), context.command().arg("bump").arg("patch").arg("--dry-run"), @r#"
     success: true
     exit_code: 0
@@ -1428,23 +3165,18 @@ draft = true
         2       │-current-version = "1.2.3"
               2 │+current-version = "1.2.4"
         3     3 │ commit-message = "Release v{version}"
-        4     4 │ branch-name = "release/v{version}"
-        5     5 │ push = true
-        6     6 │ [release.pull-request]
+        4     4 │ confirm = false
+        5     5 │ 
+        6     6 │ [release.hooks]
     ────────────┴───────────────────────────────────────────────────────────────────
 
     Changes to be made:
       - Update `seal.toml`
 
-    Pull request:
-      Title: Ship 1.2.4
-      Head: release/v1.2.4
-      Base: stable
-      Draft: true
-      Body:
-        Prepare release 1.2.4.
-
-        Review before merging.
+    Commands to be executed:
+      `false`
+      `git add -A`
+      `git commit -m Release v1.2.4`
 
     Dry run complete. No changes made.
 
@@ -1453,30 +3185,25 @@ draft = true
 }
 
 #[test]
-fn bump_pull_request_runs_after_successful_push() {
+fn bump_release_hook_failure_continue() {
     let context = TestContext::new();
 
-    context.init_git().init_git_remote();
+    context.init_git();
 
     context.seal_toml(
         r#"
 [release]
 current-version = "1.2.3"
-version-files = ["README.md"]
 commit-message = "Release v{version}"
-branch-name = "release/v{version}"
-push = true
 confirm = false
 
-[release.pull-request]
+[release.hooks]
+post-commit = ["false"]
+on-failure = { post-commit = "continue" }
 "#,
     );
 
-    context
-        .root
-        .child("README.md")
-        .write_str("# My Package (1.2.3)")
-        .unwrap();
+    context.git_commit_all();
 
     seal_snapshot!(context.filters(), context.command().arg("bump").arg("patch"), @r#"
     success: true
@@ -1486,351 +3213,301 @@ confirm = false
 
     Preview of changes:
     ────────────────────────────────────────────────────────────────────────────────
-    Source: README.md
-    ────────────┬───────────────────────────────────────────────────────────────────
-        1       │-# My Package (1.2.3)
-              1 │+# My Package (1.2.4)
-    ────────────┴───────────────────────────────────────────────────────────────────
     Source: seal.toml
     ────────────┬───────────────────────────────────────────────────────────────────
         1     1 │ [release]
         2       │-current-version = "1.2.3"
               2 │+current-version = "1.2.4"
-        3     3 │ version-files = ["README.md"]
-        4     4 │ commit-message = "Release v{version}"
-        5     5 │ branch-name = "release/v{version}"
-        6     6 │ push = true
+        3     3 │ commit-message = "Release v{version}"
+        4     4 │ confirm = false
+        5     5 │ 
+        6     6 │ [release.hooks]
     ────────────┴───────────────────────────────────────────────────────────────────
 
     Changes to be made:
-      - Update `README.md`
       - Update `seal.toml`
 
     Commands to be executed:
-      `git checkout -b release/v1.2.4`
       `git add -A`
       `git commit -m Release v1.2.4`
-      `git push origin release/v1.2.4`
-
-    Pull request:
-      Title: Release v1.2.4
-      Head: release/v1.2.4
-      Base: main
-      Draft: false
-      Body: (empty)
+      `false`
 
     Updating files...
-    Executing command: `git checkout -b release/v1.2.4`
     Executing command: `git add -A`
     Executing command: `git commit -m Release v1.2.4`
-    Executing command: `git push origin release/v1.2.4`
-    Pull request: https://github.com/owner/repo/pull/8
+    Executing command: `false`
+    Warning: Command `false` failed (exit code 1), continuing...
     Successfully bumped to 1.2.4
 
     ----- stderr -----
     "#);
 
-    insta::assert_snapshot!(context.read_file("README.md"), @"# My Package (1.2.4)");
-    insta::assert_snapshot!(context.read_file("seal.toml"), @r#"
-    [release]
-    current-version = "1.2.4"
-    version-files = ["README.md"]
-    commit-message = "Release v{version}"
-    branch-name = "release/v{version}"
-    push = true
-    confirm = false
-
-    [release.pull-request]
-    "#);
-
-    insta::assert_snapshot!(context.git_current_branch(), @"release/v1.2.4");
     insta::assert_snapshot!(context.git_last_commit_message(), @"Release v1.2.4");
 }
 
 #[test]
-fn bump_alpha_valid_dry_run_single_version_file() {
+fn bump_preflight_reports_all_failed_checks() {
     let context = TestContext::new();
 
-    context.init_git();
-
     context.seal_toml(
         r#"
 [release]
 current-version = "1.2.3"
-version-files = ["README.md"]
+commit-message = "Release v{version}"
+branch-name = "release/v{version}"
+allow-branches = ["stable"]
+confirm = false
 "#,
     );
 
+    context.init_git();
+    context.git(&["branch", "release/v1.2.4"]);
+    context.git(&["tag", "v1.2.4"]);
     context
         .root
-        .child("README.md")
-        .write_str("# My Package (1.2.3)")
+        .child("notes.txt")
+        .write_str("work in progress")
         .unwrap();
 
-    seal_snapshot!(context.filters(), context.command().arg("bump").arg("alpha").arg("--dry-run"), @r#"
-    success: true
-    exit_code: 0
+    seal_snapshot!(context.filters(), context.command().arg("bump").arg("patch"), @r"
+    success: false
+    exit_code: 2
     ----- stdout -----
-    Bumping version from 1.2.3 to 1.2.3-alpha.0
+    Bumping version from 1.2.3 to 1.2.4
 
-    Preview of changes:
-    ────────────────────────────────────────────────────────────────────────────────
-    Source: README.md
-    ────────────┬───────────────────────────────────────────────────────────────────
-        1       │-# My Package (1.2.3)
-              1 │+# My Package (1.2.3-alpha.0)
-    ────────────┴───────────────────────────────────────────────────────────────────
-    Source: seal.toml
-    ────────────┬───────────────────────────────────────────────────────────────────
-        1     1 │ [release]
-        2       │-current-version = "1.2.3"
-              2 │+current-version = "1.2.3-alpha.0"
-        3     3 │ version-files = ["README.md"]
-    ────────────┴───────────────────────────────────────────────────────────────────
+    ----- stderr -----
+    error: Pre-flight checks failed:
+      - The working tree has uncommitted changes (set `release.allow-dirty = true` to release anyway)
+      - Current branch `main` does not match `release.allow-branches`
+      - Release branch `release/v1.2.4` already exists locally (set `release.allow-existing-branch = true` to release anyway)
+      - Tag `v1.2.4` already exists (set `release.allow-existing-tag = true` to release anyway)
+    ");
 
-    Changes to be made:
-      - Update `README.md`
-      - Update `seal.toml`
+    insta::assert_snapshot!(context.read_file("seal.toml"), @r#"
+    [release]
+    current-version = "1.2.3"
+    commit-message = "Release v{version}"
+    branch-name = "release/v{version}"
+    allow-branches = ["stable"]
+    confirm = false
+    "#);
+}
 
-    Dry run complete. No changes made.
+#[test]
+fn bump_preflight_checks_the_configured_tag_format() {
+    let context = TestContext::new();
 
-    ----- stderr -----
-    "#);
+    context.seal_toml(
+        r#"
+[release]
+current-version = "1.2.3"
+commit-message = "Release v{version}"
+confirm = false
 
-    insta::assert_snapshot!(context.read_file("README.md"), @"# My Package (1.2.3)");
+[changelog]
+tag-format = "release-{version}"
+"#,
+    );
 
-    insta::assert_snapshot!(context.git_current_branch(), @"HEAD");
-    insta::assert_snapshot!(context.git_last_commit_message(), @"");
+    context.init_git();
+    context.git(&["tag", "v1.2.4"]);
+    context.git(&["tag", "release-1.2.4"]);
+
+    seal_snapshot!(context.filters(), context.command().arg("bump").arg("patch"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+    Bumping version from 1.2.3 to 1.2.4
+
+    ----- stderr -----
+    error: Pre-flight checks failed:
+      - Tag `release-1.2.4` already exists (set `release.allow-existing-tag = true` to release anyway)
+    ");
 }
 
 #[test]
-fn bump_alpha_already_alpha_valid_dry_run_single_version_file() {
+fn bump_preflight_allow_overrides() {
     let context = TestContext::new();
 
-    context.init_git();
-
     context.seal_toml(
         r#"
 [release]
-current-version = "1.2.3-alpha.0"
-version-files = ["README.md"]
+current-version = "1.2.3"
+commit-message = "Release v{version}"
+allow-dirty = true
+allow-branches = ["ma*"]
+allow-existing-tag = true
+confirm = false
 "#,
     );
 
+    context.init_git();
+    context.git(&["tag", "1.2.4"]);
     context
         .root
-        .child("README.md")
-        .write_str("# My Package (1.2.3-alpha.0)")
+        .child("notes.txt")
+        .write_str("work in progress")
         .unwrap();
 
-    seal_snapshot!(context.filters(), context.command().arg("bump").arg("alpha").arg("--dry-run"), @r#"
+    seal_snapshot!(context.filters(), context.command().arg("bump").arg("patch"), @r#"
     success: true
     exit_code: 0
     ----- stdout -----
-    Bumping version from 1.2.3-alpha.0 to 1.2.3-alpha.1
+    Bumping version from 1.2.3 to 1.2.4
 
     Preview of changes:
     ────────────────────────────────────────────────────────────────────────────────
-    Source: README.md
-    ────────────┬───────────────────────────────────────────────────────────────────
-        1       │-# My Package (1.2.3-alpha.0)
-              1 │+# My Package (1.2.3-alpha.1)
-    ────────────┴───────────────────────────────────────────────────────────────────
     Source: seal.toml
     ────────────┬───────────────────────────────────────────────────────────────────
         1     1 │ [release]
-        2       │-current-version = "1.2.3-alpha.0"
-              2 │+current-version = "1.2.3-alpha.1"
-        3     3 │ version-files = ["README.md"]
+        2       │-current-version = "1.2.3"
+              2 │+current-version = "1.2.4"
+        3     3 │ commit-message = "Release v{version}"
+        4     4 │ allow-dirty = true
+        5     5 │ allow-branches = ["ma*"]
+        6     6 │ allow-existing-tag = true
     ────────────┴───────────────────────────────────────────────────────────────────
 
     Changes to be made:
-      - Update `README.md`
       - Update `seal.toml`
 
-    Dry run complete. No changes made.
+    Commands to be executed:
+      `git add -A`
+      `git commit -m Release v1.2.4`
+
+    Updating files...
+    Executing command: `git add -A`
+    Executing command: `git commit -m Release v1.2.4`
+    Successfully bumped to 1.2.4
 
     ----- stderr -----
     "#);
 
-    insta::assert_snapshot!(context.read_file("README.md"), @"# My Package (1.2.3-alpha.0)");
-
-    insta::assert_snapshot!(context.git_current_branch(), @"HEAD");
-    insta::assert_snapshot!(context.git_last_commit_message(), @"");
+    insta::assert_snapshot!(context.git_last_commit_message(), @"Release v1.2.4");
 }
 
 #[test]
-fn bump_alpha_base_alpha_valid_dry_run_single_version_file() {
+fn bump_preflight_branch_behind_upstream() {
     let context = TestContext::new();
 
-    context.init_git();
-
     context.seal_toml(
         r#"
 [release]
-current-version = "1.2.3-alpha"
-version-files = ["README.md"]
-"#,
-    );
-
-    context
-        .root
-        .child("README.md")
-        .write_str("# My Package (1.2.3-alpha)")
-        .unwrap();
-
-    seal_snapshot!(context.filters(), context.command().arg("bump").arg("alpha").arg("--dry-run"), @r#"
-    success: true
-    exit_code: 0
-    ----- stdout -----
-    Bumping version from 1.2.3-alpha to 1.2.3-alpha.1
-
-    Preview of changes:
-    ────────────────────────────────────────────────────────────────────────────────
-    Source: README.md
-    ────────────┬───────────────────────────────────────────────────────────────────
-        1       │-# My Package (1.2.3-alpha)
-              1 │+# My Package (1.2.3-alpha.1)
-    ────────────┴───────────────────────────────────────────────────────────────────
-    Source: seal.toml
-    ────────────┬───────────────────────────────────────────────────────────────────
-        1     1 │ [release]
-        2       │-current-version = "1.2.3-alpha"
-              2 │+current-version = "1.2.3-alpha.1"
-        3     3 │ version-files = ["README.md"]
-    ────────────┴───────────────────────────────────────────────────────────────────
+current-version = "1.2.3"
+commit-message = "Release v{version}"
+branch-name = "release/v{version}"
+push = true
+confirm = false
+"#,
+    );
 
-    Changes to be made:
-      - Update `README.md`
-      - Update `seal.toml`
+    context.init_git().init_git_remote();
+    context.git(&["push", "--set-upstream", "origin", "main"]);
+    context.git(&["commit", "--allow-empty", "-m", "Upstream change"]);
+    context.git(&["push", "origin", "main"]);
+    context.git(&["reset", "--hard", "HEAD~1"]);
+    context.git(&["push", "origin", "main:refs/heads/release/v1.2.4"]);
 
-    Dry run complete. No changes made.
+    seal_snapshot!(context.filters(), context.command().arg("bump").arg("patch"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+    Bumping version from 1.2.3 to 1.2.4
 
     ----- stderr -----
-    "#);
-
-    insta::assert_snapshot!(context.read_file("README.md"), @"# My Package (1.2.3-alpha)");
-
-    insta::assert_snapshot!(context.git_current_branch(), @"HEAD");
-    insta::assert_snapshot!(context.git_last_commit_message(), @"");
+    error: Pre-flight checks failed:
+      - Release branch `release/v1.2.4` already exists on `origin` (set `release.allow-existing-branch = true` to release anyway)
+      - The current branch is 1 commit(s) behind `origin/main` as of the last fetch (set `release.allow-behind = true` to release anyway)
+    ");
 }
 
 #[test]
-fn bump_alpha_invalid_alpha_valid_dry_run_single_version_file() {
+fn bump_preflight_compares_with_the_configured_upstream() {
     let context = TestContext::new();
 
-    context.init_git();
-
     context.seal_toml(
         r#"
 [release]
-current-version = "1.2.3-alpha.-1"
-version-files = ["README.md"]
+current-version = "1.2.3"
+commit-message = "Release v{version}"
+confirm = false
 "#,
     );
 
-    context
-        .root
-        .child("README.md")
-        .write_str("# My Package (1.2.3-alpha.-1)")
-        .unwrap();
+    context.init_git().init_git_remote();
+    context.git(&["push", "origin", "main", "main:trunk"]);
+    context.git(&["branch", "--set-upstream-to", "origin/trunk"]);
+    context.git(&["commit", "--allow-empty", "-m", "Upstream change"]);
+    context.git(&["push", "origin", "main:trunk"]);
+    context.git(&["reset", "--hard", "HEAD~1"]);
 
-    seal_snapshot!(context.filters(), context.command().arg("bump").arg("alpha").arg("--dry-run"), @r"
+    seal_snapshot!(context.filters(), context.command().arg("bump").arg("patch"), @r"
     success: false
     exit_code: 2
     ----- stdout -----
+    Bumping version from 1.2.3 to 1.2.4
 
     ----- stderr -----
-    error: malformed version: 'Invalid prerelease number in: alpha.-1'. Expected format 'X.Y.Z' where X, Y, and Z are non-negative integers
+    error: Pre-flight checks failed:
+      - The current branch is 1 commit(s) behind `origin/trunk` as of the last fetch (set `release.allow-behind = true` to release anyway)
     ");
-
-    insta::assert_snapshot!(context.read_file("README.md"), @"# My Package (1.2.3-alpha.-1)");
-
-    insta::assert_snapshot!(context.git_current_branch(), @"HEAD");
-    insta::assert_snapshot!(context.git_last_commit_message(), @"");
 }
 
 #[test]
-fn bump_with_pre_commit_commands_dry_run() {
+fn bump_signed_commit_requires_signing_key() {
     let context = TestContext::new();
 
-    context.init_git();
-
     context.seal_toml(
         r#"
 [release]
 current-version = "1.2.3"
-version-files = ["README.md"]
 commit-message = "Release v{version}"
-pre-commit-commands = ["touch pre-commit-ran.txt", "echo done"]
+sign = "ssh"
+confirm = false
 "#,
     );
 
-    context
-        .root
-        .child("README.md")
-        .write_str("# My Package (1.2.3)")
-        .unwrap();
+    context.init_git();
+    context.git(&["config", "user.signingkey", ""]);
 
-    seal_snapshot!(context.filters(), context.command().arg("bump").arg("patch").arg("--dry-run"), @r#"
-    success: true
-    exit_code: 0
+    seal_snapshot!(context.filters(), context.command().arg("bump").arg("patch"), @r#"
+    success: false
+    exit_code: 2
     ----- stdout -----
     Bumping version from 1.2.3 to 1.2.4
 
-    Preview of changes:
-    ────────────────────────────────────────────────────────────────────────────────
-    Source: README.md
-    ────────────┬───────────────────────────────────────────────────────────────────
-        1       │-# My Package (1.2.3)
-              1 │+# My Package (1.2.4)
-    ────────────┴───────────────────────────────────────────────────────────────────
-    Source: seal.toml
-    ────────────┬───────────────────────────────────────────────────────────────────
-        1     1 │ [release]
-        2       │-current-version = "1.2.3"
-              2 │+current-version = "1.2.4"
-        3     3 │ version-files = ["README.md"]
-        4     4 │ commit-message = "Release v{version}"
-        5     5 │ pre-commit-commands = ["touch pre-commit-ran.txt", "echo done"]
-    ────────────┴───────────────────────────────────────────────────────────────────
-
-    Changes to be made:
-      - Update `README.md`
-      - Update `seal.toml`
-
-    Dry run complete. No changes made.
-
     ----- stderr -----
+    error: Pre-flight checks failed:
+      - `release.sign = "ssh"` requires `user.signingkey` to be set to an SSH key
     "#);
-
-    insta::assert_snapshot!(context.git_current_branch(), @"HEAD");
-    insta::assert_snapshot!(context.git_last_commit_message(), @"");
 }
 
 #[test]
-fn bump_with_pre_commit_commands_execute() {
+fn bump_signed_commit_with_ssh_key() {
     let context = TestContext::new();
 
-    context.init_git();
-
     context.seal_toml(
         r#"
 [release]
 current-version = "1.2.3"
-version-files = ["README.md"]
 commit-message = "Release v{version}"
-pre-commit-commands = ["touch pre-commit-ran.txt"]
+sign = "ssh"
+confirm = false
 "#,
     );
 
-    context
-        .root
-        .child("README.md")
-        .write_str("# My Package (1.2.3)")
-        .unwrap();
+    context.init_git();
 
-    seal_snapshot!(context.filters(), context.command().arg("bump").arg("patch").write_stdin("y\n"), @r#"
+    let key = context.root.join(".git/signing_key");
+    let output = std::process::Command::new("ssh-keygen")
+        .args(["-q", "-t", "ed25519", "-N", "", "-C", "seal-test", "-f"])
+        .arg(&key)
+        .output()
+        .expect("Failed to run ssh-keygen");
+    assert!(output.status.success(), "Failed to generate SSH key");
+    context.git(&["config", "user.signingkey", key.to_str().unwrap()]);
+
+    seal_snapshot!(context.filters(), context.command().arg("bump").arg("patch"), @r#"
     success: true
     exit_code: 0
     ----- stdout -----
@@ -1838,70 +3515,61 @@ pre-commit-commands = ["touch pre-commit-ran.txt"]
 
     Preview of changes:
     ────────────────────────────────────────────────────────────────────────────────
-    Source: README.md
-    ────────────┬───────────────────────────────────────────────────────────────────
-        1       │-# My Package (1.2.3)
-              1 │+# My Package (1.2.4)
-    ────────────┴───────────────────────────────────────────────────────────────────
     Source: seal.toml
     ────────────┬───────────────────────────────────────────────────────────────────
         1     1 │ [release]
         2       │-current-version = "1.2.3"
               2 │+current-version = "1.2.4"
-        3     3 │ version-files = ["README.md"]
-        4     4 │ commit-message = "Release v{version}"
-        5     5 │ pre-commit-commands = ["touch pre-commit-ran.txt"]
+        3     3 │ commit-message = "Release v{version}"
+        4     4 │ sign = "ssh"
+        5     5 │ confirm = false
     ────────────┴───────────────────────────────────────────────────────────────────
 
     Changes to be made:
-      - Update `README.md`
       - Update `seal.toml`
 
     Commands to be executed:
       `git add -A`
-      `touch pre-commit-ran.txt`
-      `git add -A`
-      `git commit -m Release v1.2.4`
+      `git -c gpg.format=ssh commit -S -m Release v1.2.4`
 
-    Proceed with these changes? (y/n):
     Updating files...
     Executing command: `git add -A`
-    Executing command: `touch pre-commit-ran.txt`
-    Executing command: `git add -A`
-    Executing command: `git commit -m Release v1.2.4`
+    Executing command: `git -c gpg.format=ssh commit -S -m Release v1.2.4`
     Successfully bumped to 1.2.4
 
     ----- stderr -----
     "#);
 
-    insta::assert_snapshot!(context.read_file("README.md"), @"# My Package (1.2.4)");
-    assert!(context.root.join("pre-commit-ran.txt").exists());
-    insta::assert_snapshot!(context.git_current_branch(), @"main");
-    insta::assert_snapshot!(context.git_last_commit_message(), @"Release v1.2.4");
+    let commit = std::process::Command::new("git")
+        .args(["cat-file", "commit", "HEAD"])
+        .current_dir(context.root.path())
+        .output()
+        .expect("Failed to read commit");
+    assert!(String::from_utf8_lossy(&commit.stdout).contains("-----BEGIN SSH SIGNATURE-----"));
 }
 
 #[test]
-fn bump_with_pre_commit_commands_no_confirm() {
+fn bump_commit_author_and_committer_override() {
     let context = TestContext::new();
 
-    context.init_git();
-
     context.seal_toml(
         r#"
 [release]
 current-version = "1.2.3"
-version-files = ["README.md"]
 commit-message = "Release v{version}"
-pre-commit-commands = ["touch pre-commit-ran.txt"]
 confirm = false
+
+[release.author]
+name = "release-bot"
+email = "release-bot@example.com"
+
+[release.committer]
+name = "CI"
+email = "ci@example.com"
 "#,
     );
 
-    context
-        .root
-        .child("README.md")
-        .write_str("# My Package (1.2.3)")
-        .unwrap();
+    context.init_git();
 
     seal_snapshot!(context.filters(), context.command().arg("bump").arg("patch"), @r#"
     success: true
@@ -1911,189 +3579,133 @@ confirm = false
 
     Preview of changes:
     ────────────────────────────────────────────────────────────────────────────────
-    Source: README.md
-    ────────────┬───────────────────────────────────────────────────────────────────
-        1       │-# My Package (1.2.3)
-              1 │+# My Package (1.2.4)
-    ────────────┴───────────────────────────────────────────────────────────────────
     Source: seal.toml
     ────────────┬───────────────────────────────────────────────────────────────────
         1     1 │ [release]
         2       │-current-version = "1.2.3"
               2 │+current-version = "1.2.4"
-        3     3 │ version-files = ["README.md"]
-        4     4 │ commit-message = "Release v{version}"
-        5     5 │ pre-commit-commands = ["touch pre-commit-ran.txt"]
-        6     6 │ confirm = false
+        3     3 │ commit-message = "Release v{version}"
+        4     4 │ confirm = false
+        5     5 │ 
+        6     6 │ [release.author]
     ────────────┴───────────────────────────────────────────────────────────────────
 
     Changes to be made:
-      - Update `README.md`
       - Update `seal.toml`
 
     Commands to be executed:
-      `git add -A`
-      `touch pre-commit-ran.txt`
       `git add -A`
       `git commit -m Release v1.2.4`
 
     Updating files...
     Executing command: `git add -A`
-    Executing command: `touch pre-commit-ran.txt`
-    Executing command: `git add -A`
     Executing command: `git commit -m Release v1.2.4`
     Successfully bumped to 1.2.4
 
     ----- stderr -----
     "#);
 
-    insta::assert_snapshot!(context.read_file("README.md"), @"# My Package (1.2.4)");
-    assert!(context.root.join("pre-commit-ran.txt").exists());
-    insta::assert_snapshot!(context.git_current_branch(), @"main");
-    insta::assert_snapshot!(context.git_last_commit_message(), @"Release v1.2.4");
+    let identity = std::process::Command::new("git")
+        .args(["log", "-1", "--format=%an <%ae> / %cn <%ce>"])
+        .current_dir(context.root.path())
+        .output()
+        .expect("Failed to read commit identity");
+    insta::assert_snapshot!(String::from_utf8_lossy(&identity.stdout).trim(), @"release-bot <release-bot@example.com> / CI <ci@example.com>");
 }
 
 #[test]
-fn bump_pre_commit_command_fails_abort_mode() {
+fn bump_pushes_to_configured_remote() {
     let context = TestContext::new();
 
-    context.init_git();
-
     context.seal_toml(
         r#"
 [release]
 current-version = "1.2.3"
-version-files = ["README.md"]
 commit-message = "Release v{version}"
-pre-commit-commands = ["false"]
+branch-name = "release/v{version}"
+push = true
+remote = "upstream"
 confirm = false
 "#,
     );
 
     context
-        .root
-        .child("README.md")
-        .write_str("# My Package (1.2.3)")
-        .unwrap();
+        .init_git()
+        .init_git_remote()
+        .add_git_remote("upstream", ".git/upstream.git");
 
     seal_snapshot!(context.filters(), context.command().arg("bump").arg("patch"), @r#"
-    success: false
-    exit_code: 2
+    success: true
+    exit_code: 0
     ----- stdout -----
     Bumping version from 1.2.3 to 1.2.4
 
     Preview of changes:
     ────────────────────────────────────────────────────────────────────────────────
-    Source: README.md
-    ────────────┬───────────────────────────────────────────────────────────────────
-        1       │-# My Package (1.2.3)
-              1 │+# My Package (1.2.4)
-    ────────────┴───────────────────────────────────────────────────────────────────
     Source: seal.toml
     ────────────┬───────────────────────────────────────────────────────────────────
         1     1 │ [release]
         2       │-current-version = "1.2.3"
               2 │+current-version = "1.2.4"
-        3     3 │ version-files = ["README.md"]
-        4     4 │ commit-message = "Release v{version}"
-        5     5 │ pre-commit-commands = ["false"]
-        6     6 │ confirm = false
+        3     3 │ commit-message = "Release v{version}"
+        4     4 │ branch-name = "release/v{version}"
+        5     5 │ push = true
+        6     6 │ remote = "upstream"
     ────────────┴───────────────────────────────────────────────────────────────────
 
     Changes to be made:
-      - Update `README.md`
       - Update `seal.toml`
 
     Commands to be executed:
-      `git add -A`
-      `false`
+      `git checkout -b release/v1.2.4`
       `git add -A`
       `git commit -m Release v1.2.4`
+      `git push upstream release/v1.2.4`
 
     Updating files...
+    Executing command: `git checkout -b release/v1.2.4`
     Executing command: `git add -A`
-    Executing command: `false`
+    Executing command: `git commit -m Release v1.2.4`
+    Executing command: `git push upstream release/v1.2.4`
+    Successfully bumped to 1.2.4
 
     ----- stderr -----
-    error: Command `false` failed (exit code 1)
     "#);
 
-    insta::assert_snapshot!(context.git_last_commit_message(), @"");
+    let upstream = std::process::Command::new("git")
+        .args(["ls-remote", "--heads", "upstream"])
+        .current_dir(context.root.path())
+        .output()
+        .expect("Failed to list upstream branches");
+    assert!(String::from_utf8_lossy(&upstream.stdout).contains("refs/heads/release/v1.2.4"));
 }
 
 #[test]
-fn bump_pre_commit_command_fails_continue_mode() {
+fn bump_remote_flag_overrides_config() {
     let context = TestContext::new();
 
-    context.init_git();
-
     context.seal_toml(
         r#"
 [release]
 current-version = "1.2.3"
-version-files = ["README.md"]
 commit-message = "Release v{version}"
-pre-commit-commands = ["false", "touch success.txt"]
-on-pre-commit-failure = "continue"
+branch-name = "release/v{version}"
+push = true
+remote = "upstream"
 confirm = false
 "#,
     );
 
-    context
-        .root
-        .child("README.md")
-        .write_str("# My Package (1.2.3)")
-        .unwrap();
+    context.init_git().init_git_remote();
 
-    seal_snapshot!(context.filters(), context.command().arg("bump").arg("patch"), @r#"
-    success: true
-    exit_code: 0
+    seal_snapshot!(context.filters(), context.command().arg("bump").arg("patch").arg("--remote").arg("fork").arg("--dry-run"), @r"
+    success: false
+    exit_code: 2
     ----- stdout -----
     Bumping version from 1.2.3 to 1.2.4
 
-    Preview of changes:
-    ────────────────────────────────────────────────────────────────────────────────
-    Source: README.md
-    ────────────┬───────────────────────────────────────────────────────────────────
-        1       │-# My Package (1.2.3)
-              1 │+# My Package (1.2.4)
-    ────────────┴───────────────────────────────────────────────────────────────────
-    Source: seal.toml
-    ────────────┬───────────────────────────────────────────────────────────────────
-        1     1 │ [release]
-        2       │-current-version = "1.2.3"
-              2 │+current-version = "1.2.4"
-        3     3 │ version-files = ["README.md"]
-        4     4 │ commit-message = "Release v{version}"
-        5     5 │ pre-commit-commands = ["false", "touch success.txt"]
-        6     6 │ on-pre-commit-failure = "continue"
-    ────────────┴───────────────────────────────────────────────────────────────────
-
-    Changes to be made:
-      - Update `README.md`
-      - Update `seal.toml`
-
-    Commands to be executed:
-      `git add -A`
-      `false`
-      `touch success.txt`
-      `git add -A`
-      `git commit -m Release v1.2.4`
-
-    Updating files...
-    Executing command: `git add -A`
-    Executing command: `false`
-    Warning: Command `false` failed (exit code 1), continuing...
-    Executing command: `touch success.txt`
-    Executing command: `git add -A`
-    Executing command: `git commit -m Release v1.2.4`
-    Successfully bumped to 1.2.4
-
     ----- stderr -----
-    "#);
-
-    insta::assert_snapshot!(context.read_file("README.md"), @"# My Package (1.2.4)");
-    assert!(context.root.join("success.txt").exists());
-    insta::assert_snapshot!(context.git_current_branch(), @"main");
-    insta::assert_snapshot!(context.git_last_commit_message(), @"Release v1.2.4");
+    error: Pre-flight checks failed:
+      - Git remote `fork` does not exist (add it with `git remote add` or set `release.remote`)
+    ");
 }
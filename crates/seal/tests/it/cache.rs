@@ -0,0 +1,76 @@
+use assert_fs::prelude::*;
+
+use crate::{common::TestContext, seal_snapshot};
+
+#[test]
+fn cache_clean() {
+    let context = TestContext::new();
+    context
+        .cache_dir
+        .child("github-v0/0123")
+        .write_str("{}")
+        .unwrap();
+    context
+        .cache_dir
+        .child("github-v0/4567")
+        .write_binary(&[0; 2048])
+        .unwrap();
+
+    seal_snapshot!(context.filters(), context.command().arg("cache").arg("clean"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Clearing cache at `[CACHE_DIR]/`
+    Removed 2 files (2.0KiB)
+
+    ----- stderr -----
+    ");
+
+    assert!(!context.cache_dir.exists());
+}
+
+#[test]
+fn cache_clean_missing() {
+    let context = TestContext::new();
+    let cache_dir = context.root.child("missing-cache");
+
+    seal_snapshot!(
+        context.filters(),
+        context
+            .command()
+            .arg("cache")
+            .arg("clean")
+            .arg("--cache-dir")
+            .arg(cache_dir.path()),
+        @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    No cache found at `[TEMP]/missing-cache`
+
+    ----- stderr -----
+    "
+    );
+}
+
+#[test]
+fn cache_refresh_conflicts_with_no_cache() {
+    let context = TestContext::new();
+
+    seal_snapshot!(
+        context.filters(),
+        context.command().arg("cache").arg("clean").arg("--no-cache").arg("--refresh"),
+        @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: the argument '--no-cache' cannot be used with '--refresh'
+
+    Usage: seal cache clean --no-cache --cache-dir <CACHE_DIR>
+
+    For more information, try '--help'.
+    "
+    );
+}
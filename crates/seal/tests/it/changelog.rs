@@ -0,0 +1,124 @@
+use assert_fs::prelude::*;
+
+use crate::{common::TestContext, seal_snapshot};
+
+const FRAGMENTS_CONFIG: &str = r#"
+[changelog]
+fragments-dir = "changes"
+
+[changelog.section-labels]
+"Bug fixes" = ["bugfix"]
+"Features" = ["feature"]
+"#;
+
+#[test]
+fn changelog_add() {
+    let context = TestContext::new();
+    context.seal_toml(FRAGMENTS_CONFIG);
+
+    seal_snapshot!(
+        context.filters(),
+        context
+            .command()
+            .arg("changelog")
+            .arg("add")
+            .arg("42")
+            .arg("--type")
+            .arg("feature")
+            .arg("--message")
+            .arg("Add support for fragments"),
+        @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Created `changes/42.feature.md`
+
+    ----- stderr -----
+    "
+    );
+
+    assert_eq!(
+        context.read_file("changes/42.feature.md"),
+        "Add support for fragments\n"
+    );
+}
+
+#[test]
+fn changelog_add_interactive() {
+    let context = TestContext::new();
+    context.seal_toml(FRAGMENTS_CONFIG);
+
+    seal_snapshot!(
+        context.filters(),
+        context
+            .command()
+            .arg("changelog")
+            .arg("add")
+            .write_stdin("7\nbugfix\nFix a crash on startup\n"),
+        @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Pull request number: Type (bugfix, feature): Changelog entry: Created `changes/7.bugfix.md`
+
+    ----- stderr -----
+    "
+    );
+
+    assert_eq!(
+        context.read_file("changes/7.bugfix.md"),
+        "Fix a crash on startup\n"
+    );
+}
+
+#[test]
+fn changelog_add_unknown_type() {
+    let context = TestContext::new();
+    context.seal_toml(FRAGMENTS_CONFIG);
+
+    seal_snapshot!(
+        context.filters(),
+        context
+            .command()
+            .arg("changelog")
+            .arg("add")
+            .arg("42")
+            .arg("--type")
+            .arg("docs")
+            .arg("-m")
+            .arg("Document fragments"),
+        @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Unknown fragment type `docs`, expected one of: bugfix, feature
+    "
+    );
+
+    assert!(!context.root.child("changes").exists());
+}
+
+#[test]
+fn changelog_add_without_fragments_dir() {
+    let context = TestContext::new();
+    context.seal_toml(
+        r"
+[changelog]
+",
+    );
+
+    seal_snapshot!(
+        context.filters(),
+        context.command().arg("changelog").arg("add").arg("42"),
+        @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: `changelog.fragments-dir` must be set to add changelog fragments
+    "
+    );
+}
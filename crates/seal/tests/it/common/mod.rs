@@ -12,8 +12,11 @@ pub struct TestContext {
     pub root: ChildPath,
     /// Standard filters for this test context.
     filters: Vec<(String, String)>,
+    /// The cache directory for this test, outside of the project.
+    pub cache_dir: ChildPath,
     /// The temporary directory for this test.
     pub _root: tempfile::TempDir,
+    pub _cache_dir: tempfile::TempDir,
 }
 
 impl TestContext {
@@ -22,6 +25,9 @@ impl TestContext {
         let root = tempfile::TempDir::with_prefix("seal-test")
             .expect("Failed to create test root directory");
 
+        let cache_dir = tempfile::TempDir::with_prefix("seal-cache")
+            .expect("Failed to create test cache directory");
+
         let mut filters = Vec::new();
 
         filters.extend(
@@ -29,6 +35,11 @@ impl TestContext {
                 .into_iter()
                 .map(|pattern| (pattern, "[TEMP]/".to_string())),
         );
+        filters.extend(
+            Self::path_patterns(cache_dir.path())
+                .into_iter()
+                .map(|pattern| (pattern, "[CACHE_DIR]/".to_string())),
+        );
 
         if cfg!(windows) {
             // Windows temp directory pattern
@@ -43,7 +54,9 @@ impl TestContext {
 
         Self {
             root: ChildPath::new(root.path()),
+            cache_dir: ChildPath::new(cache_dir.path()),
             _root: root,
+            _cache_dir: cache_dir,
             filters,
         }
     }
@@ -140,6 +153,7 @@ current-version = "{version}"
     pub fn command(&self) -> Command {
         let mut command = Self::new_command();
         command.current_dir(self.root.path());
+        command.env("SEAL_CACHE_DIR", self.cache_dir.path());
         command
     }
 
@@ -178,9 +192,37 @@ current-version = "{version}"
         self
     }
 
+    /// Run a git command in the test context, panicking if it fails.
+    pub fn git(&self, args: &[&str]) -> &Self {
+        let output = std::process::Command::new("git")
+            .args(args)
+            .current_dir(self.root.path())
+            .output()
+            .expect("Failed to run git");
+        assert!(
+            output.status.success(),
+            "`git {}` failed: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr)
+        );
+
+        self
+    }
+
+    /// Stage and commit every file in the test context, so the working tree is clean.
+    pub fn git_commit_all(&self) -> &Self {
+        self.git(&["add", "-A"])
+            .git(&["commit", "-m", "Set up test"])
+    }
+
     /// Add a local bare repository as `origin`.
     pub fn init_git_remote(&self) -> &Self {
-        let remote = self.root.join(".git/remote.git");
+        self.add_git_remote("origin", ".git/remote.git")
+    }
+
+    /// Add a local bare repository at `path` as the remote `name`.
+    pub fn add_git_remote(&self, name: &str, path: &str) -> &Self {
+        let remote = self.root.join(path);
 
         let output = std::process::Command::new("git")
             .args(["init", "--bare"])
@@ -194,7 +236,7 @@ current-version = "{version}"
         );
 
         let output = std::process::Command::new("git")
-            .args(["remote", "add", "origin"])
+            .args(["remote", "add", name])
             .arg(&remote)
             .current_dir(self.root.path())
             .output()
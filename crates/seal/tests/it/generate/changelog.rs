@@ -220,7 +220,7 @@ include-contributors = false
 }
 
 #[test]
-fn generate_changelog_max_prs() {
+fn generate_changelog_different_changelog_path() {
     let context = TestContext::new();
     context.init_git();
 
@@ -231,7 +231,7 @@ current-version = "1.0.0"
 
 [changelog]
 ignore-labels = ["internal", "ci"]
-include-contributors = false
+changelog-path = "CHANGE_LOG.md"
 
 [changelog.section-labels]
 "Bug Fixes" = ["bug"]
@@ -240,23 +240,50 @@ include-contributors = false
 "#,
     );
 
-    seal_snapshot!(context.filters(), context.command().arg("generate").arg("changelog").arg("--dry-run").arg("--max-prs").arg("5"), @r"
+    seal_snapshot!(context.filters(), context.command().arg("generate").arg("changelog"), @r"
     success: true
     exit_code: 0
     ----- stdout -----
+    Changelog generated successfully at `CHANGE_LOG.md`.
+
+    ----- stderr -----
+    ");
+
+    insta::assert_snapshot!(context.read_file("CHANGE_LOG.md"), @r"
     ## 1.0.0
 
     ### Documentation
 
     - Update documentation ([#3](https://github.com/owner/repo/pull/3))
 
+    ### Contributors
 
-    ----- stderr -----
+    - [@alice](https://github.com/alice)
+
+    ## 0.2.0
+
+    ### Documentation
+
+    - Update documentation ([#2](https://github.com/owner/repo/pull/2))
+
+    ### Contributors
+
+    - [@alice](https://github.com/alice)
+
+    ## Release 2025-11-05
+
+    ### Documentation
+
+    - Update documentation ([#1](https://github.com/owner/repo/pull/1))
+
+    ### Contributors
+
+    - [@alice](https://github.com/alice)
     ");
 }
 
 #[test]
-fn generate_changelog_different_changelog_path() {
+fn generate_changelog_file_already_exists_with_overwrite() {
     let context = TestContext::new();
     context.init_git();
 
@@ -267,7 +294,6 @@ current-version = "1.0.0"
 
 [changelog]
 ignore-labels = ["internal", "ci"]
-changelog-path = "CHANGE_LOG.md"
 
 [changelog.section-labels]
 "Bug Fixes" = ["bug"]
@@ -276,16 +302,22 @@ changelog-path = "CHANGE_LOG.md"
 "#,
     );
 
-    seal_snapshot!(context.filters(), context.command().arg("generate").arg("changelog"), @r"
+    context
+        .root
+        .child("CHANGELOG.md")
+        .write_str("# Existing changelog")
+        .unwrap();
+
+    seal_snapshot!(context.filters(), context.command().arg("generate").arg("changelog").arg("--overwrite"), @r"
     success: true
     exit_code: 0
     ----- stdout -----
-    Changelog generated successfully at `CHANGE_LOG.md`.
+    Changelog generated successfully at `CHANGELOG.md`.
 
     ----- stderr -----
     ");
 
-    insta::assert_snapshot!(context.read_file("CHANGE_LOG.md"), @r"
+    insta::assert_snapshot!(context.read_file("CHANGELOG.md"), @r"
     ## 1.0.0
 
     ### Documentation
@@ -319,46 +351,110 @@ changelog-path = "CHANGE_LOG.md"
 }
 
 #[test]
-fn generate_changelog_file_already_exists_with_overwrite() {
+fn generate_changelog_replays_fixtures() {
     let context = TestContext::new();
     context.init_git();
 
     context.seal_toml(
         r#"
 [release]
-current-version = "1.0.0"
+current-version = "2.0.0"
 
 [changelog]
-ignore-labels = ["internal", "ci"]
 
 [changelog.section-labels]
 "Bug Fixes" = ["bug"]
-"New Features" = ["enhancement", "feature"]
-"Documentation" = ["documentation"]
 "#,
     );
-
     context
         .root
-        .child("CHANGELOG.md")
-        .write_str("# Existing changelog")
+        .child("fixtures.toml")
+        .write_str(
+            r#"
+[[releases]]
+name = "v1.0.0"
+created-at = "2026-01-01T00:00:00Z"
+
+[[releases]]
+name = "v2.0.0"
+created-at = "2026-02-01T00:00:00Z"
+
+[[pull-requests]]
+number = 42
+title = "Fix the parser"
+url = "https://github.com/acme/widgets/pull/42"
+labels = ["bug"]
+author = "carol"
+author-url = "https://github.com/carol"
+merged-at = "2026-01-15T00:00:00Z"
+
+[[pull-requests]]
+number = 41
+title = "Merged after the release"
+url = "https://github.com/acme/widgets/pull/41"
+labels = ["bug"]
+merged-at = "2026-02-15T00:00:00Z"
+"#,
+        )
         .unwrap();
 
-    seal_snapshot!(context.filters(), context.command().arg("generate").arg("changelog").arg("--overwrite"), @r"
+    seal_snapshot!(
+        context.filters(),
+        context
+            .command()
+            .arg("generate")
+            .arg("changelog")
+            .arg("--dry-run")
+            .env("SEAL_GITHUB_FIXTURES", "fixtures.toml"),
+        @r"
     success: true
     exit_code: 0
     ----- stdout -----
-    Changelog generated successfully at `CHANGELOG.md`.
+    ## 2.0.0
+
+    ### Bug Fixes
+
+    - Fix the parser ([#42](https://github.com/acme/widgets/pull/42))
+
+    ### Contributors
+
+    - [@carol](https://github.com/carol)
+
 
     ----- stderr -----
-    ");
+    "
+    );
+}
 
-    insta::assert_snapshot!(context.read_file("CHANGELOG.md"), @r"
-    ## 1.0.0
+#[test]
+fn generate_changelog_records_fixtures() {
+    let context = TestContext::new();
+    context.init_git();
 
-    ### Documentation
+    context.seal_toml(
+        r#"
+[release]
+current-version = "1.0.0"
 
-    - Update documentation ([#3](https://github.com/owner/repo/pull/3))
+[changelog]
+"#,
+    );
+
+    seal_snapshot!(
+        context.filters(),
+        context
+            .command()
+            .arg("generate")
+            .arg("changelog")
+            .arg("--dry-run")
+            .arg("--forge-fixtures")
+            .arg("fixtures.json")
+            .arg("--record-fixtures"),
+        @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    ## 1.0.0
 
     ### Contributors
 
@@ -366,22 +462,71 @@ ignore-labels = ["internal", "ci"]
 
     ## 0.2.0
 
-    ### Documentation
-
-    - Update documentation ([#2](https://github.com/owner/repo/pull/2))
-
     ### Contributors
 
     - [@alice](https://github.com/alice)
 
     ## Release 2025-11-05
 
-    ### Documentation
-
-    - Update documentation ([#1](https://github.com/owner/repo/pull/1))
-
     ### Contributors
 
     - [@alice](https://github.com/alice)
-    ");
+
+
+    ----- stderr -----
+    "
+    );
+
+    insta::assert_snapshot!(context.read_file("fixtures.json"), @r#"
+    {
+      "releases": [
+        {
+          "created-at": "2025-11-05T00:00:00Z"
+        },
+        {
+          "created-at": "2025-11-15T00:00:00Z",
+          "name": "0.2.0"
+        },
+        {
+          "created-at": "2025-12-01T00:00:00Z",
+          "name": "v1.0.0"
+        }
+      ],
+      "pull-requests": [
+        {
+          "title": "Update documentation",
+          "number": 3,
+          "url": "https://github.com/owner/repo/pull/3",
+          "labels": [
+            "documentation"
+          ],
+          "author": "alice",
+          "author-url": "https://github.com/alice",
+          "merged-at": "2025-11-25T00:00:00Z"
+        },
+        {
+          "title": "Update documentation",
+          "number": 2,
+          "url": "https://github.com/owner/repo/pull/2",
+          "labels": [
+            "documentation"
+          ],
+          "author": "alice",
+          "author-url": "https://github.com/alice",
+          "merged-at": "2025-11-10T00:00:00Z"
+        },
+        {
+          "title": "Update documentation",
+          "number": 1,
+          "url": "https://github.com/owner/repo/pull/1",
+          "labels": [
+            "documentation"
+          ],
+          "author": "alice",
+          "author-url": "https://github.com/alice",
+          "merged-at": "2025-11-03T00:00:00Z"
+        }
+      ]
+    }
+    "#);
 }
@@ -320,3 +320,162 @@ current-version = "1.0.0"
     let formatted = serde_json::to_string_pretty(&json).unwrap();
     insta::assert_snapshot!(formatted);
 }
+
+const MULTI_VERSION_CHANGELOG: &str = "# Changelog
+
+## [Unreleased]
+
+## [1.1.0] - 2025-12-10
+
+### Features
+
+- Added new feature ([#3](https://github.com/owner/repo/pull/3))
+
+## [1.0.1-rc.1] - 2025-11-20
+
+- Release candidate fix ([#2](https://github.com/owner/repo/pull/2))
+
+## [1.0.0] - 2025-11-01
+
+- Initial release ([#1](https://github.com/owner/repo/pull/1))
+";
+
+#[test]
+fn generate_release_for_version() {
+    let context = TestContext::new();
+    context.init_git();
+    context.minimal_seal_toml("1.1.0");
+
+    context
+        .root
+        .child("CHANGELOG.md")
+        .write_str(MULTI_VERSION_CHANGELOG)
+        .unwrap();
+
+    let output = context
+        .command()
+        .arg("generate")
+        .arg("release")
+        .arg("--version")
+        .arg("1.0.1-rc.1")
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let formatted = serde_json::to_string_pretty(&json).unwrap();
+    insta::assert_snapshot!(formatted);
+}
+
+#[test]
+fn generate_release_for_missing_version() {
+    let context = TestContext::new();
+    context.init_git();
+    context.minimal_seal_toml("1.1.0");
+
+    context
+        .root
+        .child("CHANGELOG.md")
+        .write_str(MULTI_VERSION_CHANGELOG)
+        .unwrap();
+
+    seal_snapshot!(context.filters(), context.command().arg("generate").arg("release").arg("--version").arg("0.9.0"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: No section found for version 0.9.0 in changelog, available versions: 1.1.0, 1.0.1-rc.1, 1.0.0
+    ");
+}
+
+#[test]
+fn generate_release_all() {
+    let context = TestContext::new();
+    context.init_git();
+    context.minimal_seal_toml("1.1.0");
+
+    context
+        .root
+        .child("CHANGELOG.md")
+        .write_str(MULTI_VERSION_CHANGELOG)
+        .unwrap();
+
+    let output = context
+        .command()
+        .arg("generate")
+        .arg("release")
+        .arg("--all")
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let formatted = serde_json::to_string_pretty(&json).unwrap();
+    insta::assert_snapshot!(formatted);
+}
+
+#[test]
+fn generate_release_markdown() {
+    let context = TestContext::new();
+    context.init_git();
+    context.minimal_seal_toml("1.1.0");
+
+    context
+        .root
+        .child("CHANGELOG.md")
+        .write_str(MULTI_VERSION_CHANGELOG)
+        .unwrap();
+
+    seal_snapshot!(context.filters(), context.command().arg("generate").arg("release").arg("--output-format").arg("markdown"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    ### Features
+
+    - Added new feature ([#3](https://github.com/owner/repo/pull/3))
+
+    ----- stderr -----
+    ");
+
+    seal_snapshot!(context.filters(), context.command().arg("generate").arg("release").arg("--all").arg("--output-format").arg("markdown"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    ## 1.1.0
+
+    ### Features
+
+    - Added new feature ([#3](https://github.com/owner/repo/pull/3))
+
+    ## 1.0.1-rc.1
+
+    - Release candidate fix ([#2](https://github.com/owner/repo/pull/2))
+
+    ## 1.0.0
+
+    - Initial release ([#1](https://github.com/owner/repo/pull/1))
+
+    ----- stderr -----
+    ");
+}
+
+#[test]
+fn generate_release_version_conflicts_with_all() {
+    let context = TestContext::new();
+    context.init_git();
+    context.minimal_seal_toml("1.1.0");
+
+    seal_snapshot!(context.filters(), context.command().arg("generate").arg("release").arg("--all").arg("--version").arg("1.0.0"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: the argument '--all' cannot be used with '--version <VERSION>'
+
+    Usage: seal generate release --all --cache-dir <CACHE_DIR>
+
+    For more information, try '--help'.
+    ");
+}
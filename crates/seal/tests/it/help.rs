@@ -14,11 +14,14 @@ fn help_command() {
     Usage: seal [OPTIONS] <COMMAND>
 
     Commands:
-      self      Manage the seal executable
-      validate  Validate project configuration and structure
-      bump      Bump version and create release branch
-      generate  Generate project files
-      help      Display documentation for a command
+      self       Manage the seal executable
+      validate   Validate project configuration and structure
+      bump       Bump version and create release branch
+      generate   Generate project files
+      changelog  Manage changelog entries
+      auth       Manage forge authentication
+      cache      Manage seal's cache
+      help       Display documentation for a command
 
     Global options:
       -q, --quiet...              Use quiet output
@@ -29,6 +32,11 @@ fn help_command() {
       -h, --help                  Display the concise help for this command
       -V, --version               Display the seal version
 
+    Cache options:
+          --no-cache               Avoid reading from or writing to the cache
+          --refresh                Ignore cached forge API responses, replacing them with fresh ones
+          --cache-dir <CACHE_DIR>  Path to the cache directory [env: SEAL_CACHE_DIR]
+
     Use `seal help <command>` for more information on a specific command.
 
 
@@ -49,11 +57,14 @@ fn help_flag() {
     Usage: seal [OPTIONS] <COMMAND>
 
     Commands:
-      self      Manage the seal executable
-      validate  Validate project configuration and structure
-      bump      Bump version and create release branch
-      generate  Generate project files
-      help      Display documentation for a command
+      self       Manage the seal executable
+      validate   Validate project configuration and structure
+      bump       Bump version and create release branch
+      generate   Generate project files
+      changelog  Manage changelog entries
+      auth       Manage forge authentication
+      cache      Manage seal's cache
+      help       Display documentation for a command
 
     Global options:
       -q, --quiet...              Use quiet output
@@ -64,6 +75,11 @@ fn help_flag() {
       -h, --help                  Display the concise help for this command
       -V, --version               Display the seal version
 
+    Cache options:
+          --no-cache               Avoid reading from or writing to the cache
+          --refresh                Ignore cached forge API responses, replacing them with fresh ones
+          --cache-dir <CACHE_DIR>  Path to the cache directory [env: SEAL_CACHE_DIR]
+
     Use `seal help` for more details.
 
     ----- stderr -----
@@ -83,11 +99,14 @@ fn help_short_flag() {
     Usage: seal [OPTIONS] <COMMAND>
 
     Commands:
-      self      Manage the seal executable
-      validate  Validate project configuration and structure
-      bump      Bump version and create release branch
-      generate  Generate project files
-      help      Display documentation for a command
+      self       Manage the seal executable
+      validate   Validate project configuration and structure
+      bump       Bump version and create release branch
+      generate   Generate project files
+      changelog  Manage changelog entries
+      auth       Manage forge authentication
+      cache      Manage seal's cache
+      help       Display documentation for a command
 
     Global options:
       -q, --quiet...              Use quiet output
@@ -98,6 +117,11 @@ fn help_short_flag() {
       -h, --help                  Display the concise help for this command
       -V, --version               Display the seal version
 
+    Cache options:
+          --no-cache               Avoid reading from or writing to the cache
+          --refresh                Ignore cached forge API responses, replacing them with fresh ones
+          --cache-dir <CACHE_DIR>  Path to the cache directory [env: SEAL_CACHE_DIR]
+
     Use `seal help` for more details.
 
     ----- stderr -----
@@ -148,6 +172,23 @@ fn help_self_command() {
       -h, --help
               Display the concise help for this command
 
+    Cache options:
+          --no-cache
+              Avoid reading from or writing to the cache.
+              
+              Every forge API request is sent in full, without a conditional request.
+
+          --refresh
+              Ignore cached forge API responses, replacing them with fresh ones
+
+          --cache-dir <CACHE_DIR>
+              Path to the cache directory.
+              
+              Defaults to `$XDG_CACHE_HOME/seal` or `$HOME/.cache/seal` on Linux,
+              `$HOME/Library/Caches/seal` on macOS, and `%LOCALAPPDATA%/seal/cache` on Windows.
+              
+              [env: SEAL_CACHE_DIR]
+
     Use `seal help self <command>` for more information on a specific command.
 
 
@@ -170,6 +211,9 @@ fn help_invalid_command() {
         validate
         bump
         generate
+        changelog
+        auth
+        cache
     ");
 }
 
@@ -240,6 +284,23 @@ fn help_self_version_command() {
       -h, --help
               Display the concise help for this command
 
+    Cache options:
+          --no-cache
+              Avoid reading from or writing to the cache.
+              
+              Every forge API request is sent in full, without a conditional request.
+
+          --refresh
+              Ignore cached forge API responses, replacing them with fresh ones
+
+          --cache-dir <CACHE_DIR>
+              Path to the cache directory.
+              
+              Defaults to `$XDG_CACHE_HOME/seal` or `$HOME/.cache/seal` on Linux,
+              `$HOME/Library/Caches/seal` on macOS, and `%LOCALAPPDATA%/seal/cache` on Windows.
+              
+              [env: SEAL_CACHE_DIR]
+
 
     ----- stderr -----
     ");
@@ -258,11 +319,14 @@ fn help_command_no_pager() {
     Usage: seal [OPTIONS] <COMMAND>
 
     Commands:
-      self      Manage the seal executable
-      validate  Validate project configuration and structure
-      bump      Bump version and create release branch
-      generate  Generate project files
-      help      Display documentation for a command
+      self       Manage the seal executable
+      validate   Validate project configuration and structure
+      bump       Bump version and create release branch
+      generate   Generate project files
+      changelog  Manage changelog entries
+      auth       Manage forge authentication
+      cache      Manage seal's cache
+      help       Display documentation for a command
 
     Global options:
       -q, --quiet...              Use quiet output
@@ -273,6 +337,11 @@ fn help_command_no_pager() {
       -h, --help                  Display the concise help for this command
       -V, --version               Display the seal version
 
+    Cache options:
+          --no-cache               Avoid reading from or writing to the cache
+          --refresh                Ignore cached forge API responses, replacing them with fresh ones
+          --cache-dir <CACHE_DIR>  Path to the cache directory [env: SEAL_CACHE_DIR]
+
     Use `seal help <command>` for more information on a specific command.
 
 
@@ -293,11 +362,14 @@ fn help_command_no_color() {
     Usage: seal [OPTIONS] <COMMAND>
 
     Commands:
-      self      Manage the seal executable
-      validate  Validate project configuration and structure
-      bump      Bump version and create release branch
-      generate  Generate project files
-      help      Display documentation for a command
+      self       Manage the seal executable
+      validate   Validate project configuration and structure
+      bump       Bump version and create release branch
+      generate   Generate project files
+      changelog  Manage changelog entries
+      auth       Manage forge authentication
+      cache      Manage seal's cache
+      help       Display documentation for a command
 
     Global options:
       -q, --quiet...              Use quiet output
@@ -308,6 +380,11 @@ fn help_command_no_color() {
       -h, --help                  Display the concise help for this command
       -V, --version               Display the seal version
 
+    Cache options:
+          --no-cache               Avoid reading from or writing to the cache
+          --refresh                Ignore cached forge API responses, replacing them with fresh ones
+          --cache-dir <CACHE_DIR>  Path to the cache directory [env: SEAL_CACHE_DIR]
+
     Use `seal help <command>` for more information on a specific command.
 
 
@@ -3,6 +3,10 @@
 
 pub(crate) mod common;
 
+mod auth;
+mod cache;
+mod changelog;
+
 #[cfg(feature = "integration-test")]
 mod bump;
 
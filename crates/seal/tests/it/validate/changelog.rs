@@ -0,0 +1,136 @@
+use assert_fs::prelude::*;
+
+use crate::{common::TestContext, seal_snapshot};
+
+#[test]
+fn validate_changelog_valid() {
+    let context = TestContext::new();
+    context.seal_toml(
+        r#"
+[release]
+current-version = "1.1.0"
+
+[changelog]
+changelog-heading = "[{version}] - {date}"
+"#,
+    );
+    context
+        .root
+        .child("CHANGELOG.md")
+        .write_str(concat!(
+            "# Changelog\n\n",
+            "## [Unreleased]\n\n",
+            "## [1.1.0] - 2025-12-10\n\n",
+            "### Added\n\n",
+            "- New feature\n\n",
+            "## [1.0.0] - 2025-11-01\n\n",
+            "- Initial release\n"
+        ))
+        .unwrap();
+
+    seal_snapshot!(context.filters(), context.command().arg("validate").arg("changelog"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Changelog `CHANGELOG.md` is valid
+
+    ----- stderr -----
+    ");
+}
+
+#[test]
+fn validate_changelog_invalid() {
+    let context = TestContext::new();
+    context.seal_toml(
+        r#"
+[release]
+current-version = "1.2.0"
+
+[changelog]
+changelog-heading = "[{version}] - {date}"
+"#,
+    );
+    context
+        .root
+        .child("CHANGELOG.md")
+        .write_str(concat!(
+            "# Changelog\n\n",
+            "## [1.0.0] - 2025-11-01\n\n",
+            "- Initial release\n\n",
+            "## 1.1.0\n\n",
+            "#### Added\n\n",
+            "- New feature\n\n",
+            "## [1.0.0] - 2025-11-01\n\n",
+        ))
+        .unwrap();
+
+    seal_snapshot!(context.filters(), context.command().arg("validate").arg("changelog"), @r#"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Changelog `CHANGELOG.md` is invalid:
+
+    Changelog error at line 7
+      |
+    7 | ## 1.1.0
+      | ^^^^^^^^
+    Version 1.1.0 is listed below older version 1.0.0, but versions must be in descending order
+
+    Changelog error at line 7
+      |
+    7 | ## 1.1.0
+      | ^^^^^^^^
+    Heading does not match `changelog-heading` "[{version}] - {date}"
+
+    Changelog error at line 9
+      |
+    9 | #### Added
+      | ^^^^^^^^^^
+    A `####` heading cannot follow a `##` heading
+
+    Changelog error at line 13
+       |
+    13 | ## [1.0.0] - 2025-11-01
+       | ^^^^^^^^^^^^^^^^^^^^^^^
+    Duplicate section for version 1.0.0, first at line 3
+
+    Changelog error: No section found for `release.current-version` 1.2.0
+    "#);
+}
+
+#[test]
+fn validate_changelog_with_explicit_path() {
+    let context = TestContext::new();
+    context.minimal_seal_toml("1.0.0");
+    context
+        .root
+        .child("docs/CHANGES.md")
+        .write_str("# Changes\n\n## 1.0.0\n\n- Initial release\n")
+        .unwrap();
+
+    seal_snapshot!(context.filters(), context.command().arg("validate").arg("changelog").arg("--changelog-file").arg("docs/CHANGES.md"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Changelog `docs/CHANGES.md` is valid
+
+    ----- stderr -----
+    ");
+}
+
+#[test]
+fn validate_changelog_missing() {
+    let context = TestContext::new();
+    context.minimal_seal_toml("1.0.0");
+
+    seal_snapshot!(context.filters(), context.command().arg("validate").arg("changelog"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Changelog not found at `CHANGELOG.md`
+    ");
+}
@@ -0,0 +1,259 @@
+//! The on-disk cache of forge API responses.
+//!
+//! Entries are stored in per-forge buckets under the user cache directory, keyed by a hash of the
+//! request they answer.
+
+use std::fmt::Write as _;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use sha2::{Digest, Sha256};
+use thiserror::Error;
+
+/// The environment variable that overrides the cache directory.
+pub const CACHE_DIR_ENV_VAR: &str = "SEAL_CACHE_DIR";
+
+#[derive(Debug, Error)]
+pub enum CacheError {
+    #[error(
+        "Could not determine the user cache directory; set `{CACHE_DIR_ENV_VAR}` or pass `--cache-dir`"
+    )]
+    NoCacheDir,
+}
+
+/// A group of cache entries with a shared format.
+///
+/// The version suffix is bumped whenever the format of the entries changes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CacheBucket {
+    /// Responses from the GitHub REST and GraphQL APIs.
+    GitHub,
+}
+
+impl CacheBucket {
+    const ALL: [Self; 1] = [Self::GitHub];
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::GitHub => "github-v0",
+        }
+    }
+}
+
+/// The files and bytes removed when clearing the cache.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Removal {
+    pub num_files: u64,
+    pub total_bytes: u64,
+}
+
+#[derive(Debug, Clone)]
+pub struct Cache {
+    root: PathBuf,
+    /// Ignore existing entries, while still writing new ones.
+    refresh: bool,
+}
+
+impl Cache {
+    /// A cache rooted at `root`.
+    pub fn from_path(root: impl Into<PathBuf>) -> Self {
+        Self {
+            root: root.into(),
+            refresh: false,
+        }
+    }
+
+    /// A cache rooted at `cache_dir`, or at the user cache directory by default.
+    pub fn from_settings(cache_dir: Option<PathBuf>) -> Result<Self, CacheError> {
+        cache_dir
+            .or_else(default_cache_dir)
+            .map(Self::from_path)
+            .ok_or(CacheError::NoCacheDir)
+    }
+
+    /// Ignore existing entries when `refresh` is set, replacing them as responses arrive.
+    #[must_use]
+    pub fn with_refresh(mut self, refresh: bool) -> Self {
+        self.refresh = refresh;
+        self
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Read the entry for `key`, unless refreshing or it does not exist.
+    pub fn read(&self, bucket: CacheBucket, key: &str) -> Option<Vec<u8>> {
+        if self.refresh {
+            return None;
+        }
+
+        let path = self.entry_path(bucket, key);
+        match fs_err::read(&path) {
+            Ok(contents) => Some(contents),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => None,
+            Err(err) => {
+                tracing::debug!("Ignoring unreadable cache entry: {err}");
+                None
+            }
+        }
+    }
+
+    /// Write the entry for `key`, replacing any existing entry.
+    pub fn write(&self, bucket: CacheBucket, key: &str, contents: &[u8]) -> io::Result<()> {
+        let path = self.entry_path(bucket, key);
+        let dir = path.parent().unwrap_or(&self.root);
+        fs_err::create_dir_all(dir)?;
+
+        // Write to a temporary file first, so that readers never see a partial entry.
+        let temp_path = path.with_extension(format!("tmp{}", std::process::id()));
+        fs_err::write(&temp_path, contents)?;
+        fs_err::rename(&temp_path, &path)
+    }
+
+    /// Remove every entry in the cache.
+    ///
+    /// Only the bucket directories are removed, since the root may be a directory that holds other
+    /// files, e.g. when set with `--cache-dir`. The root itself is removed once it is empty.
+    pub fn clear(&self) -> io::Result<Removal> {
+        let mut removal = Removal::default();
+        for bucket in CacheBucket::ALL {
+            let dir = self.root.join(bucket.as_str());
+            if !dir.exists() {
+                continue;
+            }
+
+            count_files(&dir, &mut removal)?;
+            fs_err::remove_dir_all(&dir)?;
+        }
+
+        if fs_err::read_dir(&self.root).is_ok_and(|mut entries| entries.next().is_none()) {
+            fs_err::remove_dir(&self.root)?;
+        }
+        Ok(removal)
+    }
+
+    fn entry_path(&self, bucket: CacheBucket, key: &str) -> PathBuf {
+        let digest = Sha256::digest(key.as_bytes());
+        let mut name = String::with_capacity(digest.len() * 2);
+        for byte in digest {
+            write!(name, "{byte:02x}").expect("writing to a string cannot fail");
+        }
+        self.root.join(bucket.as_str()).join(name)
+    }
+}
+
+fn count_files(dir: &Path, removal: &mut Removal) -> io::Result<()> {
+    for entry in fs_err::read_dir(dir)? {
+        let entry = entry?;
+        let metadata = entry.metadata()?;
+        if metadata.is_dir() {
+            count_files(&entry.path(), removal)?;
+        } else {
+            removal.num_files += 1;
+            removal.total_bytes += metadata.len();
+        }
+    }
+    Ok(())
+}
+
+/// The user cache directory, following the platform conventions.
+fn default_cache_dir() -> Option<PathBuf> {
+    let env_dir = |name: &str| {
+        std::env::var_os(name)
+            .filter(|value| !value.is_empty())
+            .map(PathBuf::from)
+    };
+
+    if cfg!(windows) {
+        env_dir("LOCALAPPDATA").map(|dir| dir.join("seal").join("cache"))
+    } else if cfg!(target_os = "macos") {
+        env_dir("HOME").map(|dir| dir.join("Library").join("Caches").join("seal"))
+    } else {
+        env_dir("XDG_CACHE_HOME")
+            .or_else(|| env_dir("HOME").map(|dir| dir.join(".cache")))
+            .map(|dir| dir.join("seal"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Cache, CacheBucket, Removal};
+
+    #[test]
+    fn reads_written_entries() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = Cache::from_path(dir.path().join("cache"));
+
+        assert_eq!(cache.read(CacheBucket::GitHub, "key"), None);
+        cache.write(CacheBucket::GitHub, "key", b"first").unwrap();
+        cache.write(CacheBucket::GitHub, "key", b"second").unwrap();
+        cache.write(CacheBucket::GitHub, "other", b"other").unwrap();
+
+        assert_eq!(
+            cache.read(CacheBucket::GitHub, "key").as_deref(),
+            Some(&b"second"[..])
+        );
+        assert_eq!(
+            cache.read(CacheBucket::GitHub, "other").as_deref(),
+            Some(&b"other"[..])
+        );
+    }
+
+    #[test]
+    fn refresh_ignores_existing_entries() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = Cache::from_path(dir.path());
+        cache.write(CacheBucket::GitHub, "key", b"stale").unwrap();
+
+        let cache = cache.with_refresh(true);
+        assert_eq!(cache.read(CacheBucket::GitHub, "key"), None);
+        cache.write(CacheBucket::GitHub, "key", b"fresh").unwrap();
+
+        let cache = cache.with_refresh(false);
+        assert_eq!(
+            cache.read(CacheBucket::GitHub, "key").as_deref(),
+            Some(&b"fresh"[..])
+        );
+    }
+
+    #[test]
+    fn clear_removes_every_entry() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = Cache::from_path(dir.path().join("cache"));
+        assert_eq!(cache.clear().unwrap(), Removal::default());
+
+        cache.write(CacheBucket::GitHub, "a", b"abc").unwrap();
+        cache.write(CacheBucket::GitHub, "b", b"de").unwrap();
+
+        assert_eq!(
+            cache.clear().unwrap(),
+            Removal {
+                num_files: 2,
+                total_bytes: 5,
+            }
+        );
+        assert!(!cache.root().exists());
+        assert_eq!(cache.read(CacheBucket::GitHub, "a"), None);
+    }
+
+    #[test]
+    fn clear_keeps_files_outside_buckets() {
+        let dir = tempfile::tempdir().unwrap();
+        fs_err::write(dir.path().join("notes.txt"), "keep").unwrap();
+        fs_err::create_dir(dir.path().join("other")).unwrap();
+        let cache = Cache::from_path(dir.path());
+        cache.write(CacheBucket::GitHub, "a", b"abc").unwrap();
+
+        assert_eq!(
+            cache.clear().unwrap(),
+            Removal {
+                num_files: 1,
+                total_bytes: 3,
+            }
+        );
+        assert!(dir.path().join("notes.txt").exists());
+        assert!(dir.path().join("other").exists());
+        assert!(!dir.path().join("github-v0").exists());
+    }
+}
@@ -0,0 +1,476 @@
+//! A structured view of a changelog, parsed from its markdown.
+
+use std::ops::Range;
+
+use anyhow::{Result, anyhow};
+use chrono::NaiveDate;
+use markdown::mdast::{Heading, Node};
+use markdown::{ParseOptions, to_mdast};
+use semver::Version;
+
+/// A parsed changelog: its `## ` release sections and the link reference definitions at its end.
+#[derive(Debug)]
+pub struct ChangelogDocument<'a> {
+    source: &'a str,
+    /// Whether the changelog starts with a `# ` title.
+    has_title: bool,
+    sections: Vec<ReleaseSection<'a>>,
+    headings: Vec<DocumentHeading>,
+    /// Where the link reference definitions after the last section start.
+    definitions_start: Option<usize>,
+}
+
+/// A heading at the top level of a changelog, outside of lists, quotes, and code blocks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DocumentHeading {
+    pub depth: u8,
+    /// Where the heading starts in the changelog.
+    pub offset: usize,
+}
+
+/// A `## ` section of a changelog, such as `## [1.2.0] - 2025-12-10` or `## [Unreleased]`.
+#[derive(Debug)]
+pub struct ReleaseSection<'a> {
+    /// The heading as written, e.g. `[1.2.0] - 2025-12-10`.
+    pub heading: &'a str,
+    /// The heading's text without link markup, e.g. `1.2.0 - 2025-12-10`.
+    pub title: String,
+    /// The first version in the heading.
+    pub version: Option<Version>,
+    /// The first `YYYY-MM-DD` date in the heading.
+    pub date: Option<NaiveDate>,
+    /// The entries of the section, grouped by their `###` heading.
+    pub groups: Vec<EntryGroup>,
+    /// Everything after the heading, without surrounding blank lines.
+    pub body: &'a str,
+    /// The body's byte range in the changelog.
+    pub body_range: Range<usize>,
+    /// The section's byte range in the changelog, from its heading to the next section.
+    pub range: Range<usize>,
+}
+
+/// The list items under one `###` heading of a section, or before the first one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EntryGroup {
+    pub title: Option<String>,
+    /// Each list item as written, without its bullet.
+    pub entries: Vec<String>,
+    /// The group's byte range in the changelog, from its heading, or its first list when it has
+    /// no title, to the next heading or the end of the section.
+    pub range: Range<usize>,
+}
+
+impl<'a> ChangelogDocument<'a> {
+    pub fn parse(source: &'a str) -> Result<Self> {
+        let root = to_mdast(source, &ParseOptions::default())
+            .map_err(|message| anyhow!("Failed to parse changelog: {message}"))?;
+        let children = root.children().map(Vec::as_slice).unwrap_or_default();
+
+        let has_title = children
+            .first()
+            .is_some_and(|node| matches!(node, Node::Heading(Heading { depth: 1, .. })));
+
+        let trailing_definitions = children
+            .iter()
+            .rev()
+            .take_while(|node| matches!(node, Node::Definition(_)))
+            .count();
+        let (content, definitions) = children.split_at(children.len() - trailing_definitions);
+        let definitions_start = definitions.first().map(start);
+        let content_end = definitions_start.unwrap_or(source.len());
+
+        let headings = content
+            .iter()
+            .filter_map(|node| match node {
+                Node::Heading(heading) => Some(DocumentHeading {
+                    depth: heading.depth,
+                    offset: start(node),
+                }),
+                _ => None,
+            })
+            .collect();
+
+        let mut sections = Vec::new();
+        let mut nodes = content.iter().peekable();
+        while let Some(node) = nodes.next() {
+            let Node::Heading(heading) = node else {
+                continue;
+            };
+            if heading.depth != 2 {
+                continue;
+            }
+
+            let mut section_nodes = Vec::new();
+            while let Some(next) = nodes.peek() {
+                if matches!(next, Node::Heading(Heading { depth: 1 | 2, .. })) {
+                    break;
+                }
+                section_nodes.push(nodes.next().unwrap());
+            }
+            let section_end = nodes.peek().map_or(content_end, |next| start(next));
+
+            sections.push(ReleaseSection::new(
+                source,
+                node,
+                &section_nodes,
+                start(node)..section_end,
+            ));
+        }
+
+        Ok(Self {
+            source,
+            has_title,
+            sections,
+            headings,
+            definitions_start,
+        })
+    }
+
+    pub fn sections(&self) -> &[ReleaseSection<'a>] {
+        &self.sections
+    }
+
+    pub fn headings(&self) -> &[DocumentHeading] {
+        &self.headings
+    }
+
+    /// The 1-based line number of the byte `offset`.
+    pub fn line_number(&self, offset: usize) -> usize {
+        self.source[..offset].matches('\n').count() + 1
+    }
+
+    /// The line containing the byte `offset`, without its line ending.
+    pub fn line(&self, offset: usize) -> &'a str {
+        let start = self.source[..offset]
+            .rfind('\n')
+            .map_or(0, |position| position + 1);
+        self.source[start..].lines().next().unwrap_or_default()
+    }
+
+    /// The sections with a version, newest first.
+    pub fn releases(&self) -> impl Iterator<Item = &ReleaseSection<'a>> {
+        self.sections
+            .iter()
+            .filter(|section| section.version.is_some())
+    }
+
+    /// The first section with a version, skipping an `Unreleased` section.
+    pub fn latest_release(&self) -> Option<&ReleaseSection<'a>> {
+        self.releases().next()
+    }
+
+    /// The section for `version`.
+    pub fn section(&self, version: &Version) -> Option<&ReleaseSection<'a>> {
+        self.releases()
+            .find(|section| section.version.as_ref() == Some(version))
+    }
+
+    /// The `## [Unreleased]` section, if there is one.
+    pub fn unreleased(&self) -> Option<&ReleaseSection<'a>> {
+        self.sections.iter().find(|section| section.is_unreleased())
+    }
+
+    /// Insert `section` above the latest release, or after the other sections when there is none.
+    pub fn insert_section(&self, section: &str) -> String {
+        if !self.has_title {
+            return format!("# Changelog\n\n{section}{}", self.source);
+        }
+
+        let offset = self.latest_release().map_or_else(
+            || self.definitions_start.unwrap_or(self.source.len()),
+            |release| release.range.start,
+        );
+        let (before, after) = self.source.split_at(offset);
+        format!("{}\n\n{section}{after}", before.trim_end())
+    }
+
+    /// Insert the link reference definition `line` above those at the end of the changelog.
+    pub fn insert_definition(&self, line: &str) -> String {
+        if let Some(offset) = self.definitions_start {
+            let (before, after) = self.source.split_at(offset);
+            format!("{before}{line}\n{after}")
+        } else {
+            format!("{}\n\n{line}\n", self.source.trim_end())
+        }
+    }
+}
+
+impl<'a> ReleaseSection<'a> {
+    fn new(source: &'a str, heading: &Node, nodes: &[&Node], range: Range<usize>) -> Self {
+        let heading_source = &source[start(heading)..end(heading)];
+        // Setext headings are underlined on the next line.
+        let heading_line = heading_source.lines().next().unwrap_or_default();
+        let heading_text = heading_line
+            .trim()
+            .trim_start_matches('#')
+            .trim_end_matches('#')
+            .trim();
+
+        let title = heading.to_string();
+        let words: Vec<&str> = title
+            .split_whitespace()
+            .map(|word| word.trim_matches(|c: char| matches!(c, '[' | ']' | '(' | ')' | ',' | ':')))
+            .collect();
+        let version = words
+            .iter()
+            .find_map(|word| Version::parse(word.strip_prefix('v').unwrap_or(word)).ok());
+        let date = words
+            .iter()
+            .find_map(|word| NaiveDate::parse_from_str(word, "%Y-%m-%d").ok());
+
+        let mut groups: Vec<EntryGroup> = Vec::new();
+        for node in nodes {
+            match node {
+                // Deeper headings, since `#` and `##` end the section.
+                Node::Heading(_) => groups.push(EntryGroup {
+                    title: Some(node.to_string()),
+                    entries: Vec::new(),
+                    range: start(node)..range.end,
+                }),
+                Node::List(list) => {
+                    let entries = list.children.iter().filter_map(|item| {
+                        let first = item.children().and_then(|children| children.first())?;
+                        Some(source[start(first)..end(item)].trim_end().to_string())
+                    });
+                    if groups.is_empty() {
+                        groups.push(EntryGroup {
+                            title: None,
+                            entries: Vec::new(),
+                            range: start(node)..range.end,
+                        });
+                    }
+                    groups.last_mut().unwrap().entries.extend(entries);
+                }
+                _ => {}
+            }
+        }
+        for index in 1..groups.len() {
+            groups[index - 1].range.end = groups[index].range.start;
+        }
+
+        let after_heading = &source[end(heading)..range.end];
+        let body = after_heading.trim_matches('\n').trim_end();
+        let body_start = range.end - after_heading.trim_start_matches('\n').len();
+
+        Self {
+            heading: heading_text,
+            title,
+            version,
+            date,
+            groups,
+            body,
+            body_range: body_start..body_start + body.len(),
+            range,
+        }
+    }
+
+    pub fn is_unreleased(&self) -> bool {
+        self.title
+            .trim_matches(|c| c == '[' || c == ']')
+            .eq_ignore_ascii_case("unreleased")
+    }
+}
+
+pub(crate) fn start(node: &Node) -> usize {
+    node.position().map_or(0, |position| position.start.offset)
+}
+
+pub(crate) fn end(node: &Node) -> usize {
+    node.position().map_or(0, |position| position.end.offset)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CHANGELOG: &str = "# Changelog
+
+All notable changes to this project are documented here.
+
+## [Unreleased]
+
+- Upcoming change
+
+## [1.1.0] - 2025-12-10
+
+### Added
+
+- New feature ([#2](https://github.com/owner/repo/pull/2))
+- Another feature
+  spanning two lines
+
+### Fixed
+
+- Bug fix
+
+Release 1.0.0
+-------------
+
+```markdown
+## 0.9.0
+
+- Not a section
+```
+
+[Unreleased]: https://github.com/owner/repo/compare/v1.1.0...HEAD
+[1.1.0]: https://github.com/owner/repo/compare/v1.0.0...v1.1.0
+";
+
+    #[test]
+    fn test_parse_sections() {
+        let document = ChangelogDocument::parse(CHANGELOG).unwrap();
+
+        let summary: Vec<_> = document
+            .sections()
+            .iter()
+            .map(|section| {
+                (
+                    section.heading,
+                    section.title.as_str(),
+                    section.version.as_ref().map(ToString::to_string),
+                    section.date.map(|date| date.to_string()),
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("[Unreleased]", "Unreleased", None, None),
+                (
+                    "[1.1.0] - 2025-12-10",
+                    "1.1.0 - 2025-12-10",
+                    Some("1.1.0".to_string()),
+                    Some("2025-12-10".to_string())
+                ),
+                (
+                    "Release 1.0.0",
+                    "Release 1.0.0",
+                    Some("1.0.0".to_string()),
+                    None
+                ),
+            ]
+        );
+
+        assert!(document.sections()[0].is_unreleased());
+        assert_eq!(
+            document.unreleased().map(|section| section.body),
+            Some("- Upcoming change")
+        );
+        for section in document.sections() {
+            assert_eq!(&CHANGELOG[section.body_range.clone()], section.body);
+        }
+    }
+
+    fn offset(text: &str) -> usize {
+        CHANGELOG.find(text).unwrap()
+    }
+
+    #[test]
+    fn test_parse_entries() {
+        let document = ChangelogDocument::parse(CHANGELOG).unwrap();
+        let release = document.latest_release().unwrap();
+
+        assert_eq!(
+            release.groups,
+            vec![
+                EntryGroup {
+                    title: Some("Added".to_string()),
+                    entries: vec![
+                        "New feature ([#2](https://github.com/owner/repo/pull/2))".to_string(),
+                        "Another feature\n  spanning two lines".to_string(),
+                    ],
+                    range: offset("### Added")..offset("### Fixed"),
+                },
+                EntryGroup {
+                    title: Some("Fixed".to_string()),
+                    entries: vec!["Bug fix".to_string()],
+                    range: offset("### Fixed")..offset("Release 1.0.0"),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_non_ascii() {
+        let document =
+            ChangelogDocument::parse("# Änderungen\n\n## 1.0.0 – café\n\n- Grüße\n").unwrap();
+        let section = document.latest_release().unwrap();
+
+        assert_eq!(section.heading, "1.0.0 – café");
+        assert_eq!(section.body, "- Grüße");
+    }
+
+    #[test]
+    fn test_section_body_ignores_code_blocks_and_definitions() {
+        let document = ChangelogDocument::parse(CHANGELOG).unwrap();
+        let section = document.section(&Version::new(1, 0, 0)).unwrap();
+
+        insta::assert_snapshot!(section.body, @r"
+        ```markdown
+        ## 0.9.0
+
+        - Not a section
+        ```
+        ");
+        assert!(document.section(&Version::new(0, 9, 0)).is_none());
+    }
+
+    #[test]
+    fn test_insert_section() {
+        let document = ChangelogDocument::parse(CHANGELOG).unwrap();
+        let updated = document.insert_section("## [1.2.0]\n\n- Newest\n\n");
+
+        let document = ChangelogDocument::parse(&updated).unwrap();
+        let headings: Vec<_> = document
+            .sections()
+            .iter()
+            .map(|section| section.heading)
+            .collect();
+        assert_eq!(
+            headings,
+            vec![
+                "[Unreleased]",
+                "[1.2.0]",
+                "[1.1.0] - 2025-12-10",
+                "Release 1.0.0"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_insert_section_without_releases() {
+        let document = ChangelogDocument::parse(
+            "# Changelog\n\nIntro.\n\n[Unreleased]: https://example.com\n",
+        )
+        .unwrap();
+
+        insta::assert_snapshot!(document.insert_section("## 1.0.0\n\n- First\n\n"), @r"
+        # Changelog
+
+        Intro.
+
+        ## 1.0.0
+
+        - First
+
+        [Unreleased]: https://example.com
+        ");
+    }
+
+    #[test]
+    fn test_insert_definition() {
+        let document = ChangelogDocument::parse("# Changelog\n\n## 1.0.0\n\n- First\n").unwrap();
+        let updated = document.insert_definition("[1.0.0]: https://example.com/1.0.0");
+        assert_eq!(
+            updated,
+            "# Changelog\n\n## 1.0.0\n\n- First\n\n[1.0.0]: https://example.com/1.0.0\n"
+        );
+
+        let document = ChangelogDocument::parse(&updated).unwrap();
+        let updated = document.insert_definition("[1.1.0]: https://example.com/1.1.0");
+        assert_eq!(
+            updated,
+            "# Changelog\n\n## 1.0.0\n\n- First\n\n[1.1.0]: https://example.com/1.1.0\n[1.0.0]: https://example.com/1.0.0\n"
+        );
+    }
+}
@@ -0,0 +1,296 @@
+//! Changelog fragments: one file per change, named `<pull request number>.<type>.md`.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result, bail};
+use chrono::Utc;
+use seal_file_change::FileChange;
+use seal_forge::{ForgeService, PullRequest};
+use seal_project::ChangelogConfig;
+
+use crate::UNKNOWN_LABEL;
+
+/// A changelog entry written to its own file.
+#[derive(Debug, Clone)]
+pub(crate) struct Fragment {
+    path: PathBuf,
+    number: u64,
+    kind: String,
+    content: String,
+}
+
+impl Fragment {
+    /// An entry for the fragment's pull request, labelled with the fragment's type.
+    ///
+    /// The first paragraph is the entry's title. Any paragraphs after it are the entry's release
+    /// notes, under the heading that release notes are read from.
+    pub(crate) fn to_pull_request(&self, forge: &dyn ForgeService) -> PullRequest {
+        let (title, notes) = split_title(&self.content);
+        PullRequest {
+            title,
+            number: self.number,
+            url: forge.pull_request_url(self.number).unwrap_or_default(),
+            labels: vec![self.kind.clone()],
+            author: None,
+            author_url: None,
+            body: notes.map(|notes| format!("# Release notes\n\n{notes}")),
+            closed_issues: Vec::new(),
+            changed_files: Vec::new(),
+            merged_at: Utc::now(),
+        }
+    }
+
+    /// The change that deletes the fragment once it is in the changelog.
+    pub(crate) fn deletion(&self) -> FileChange {
+        FileChange::delete(self.path.clone(), self.content.clone())
+    }
+}
+
+/// The first paragraph of a fragment's text, joined into one line, and the text after it, if any.
+fn split_title(content: &str) -> (String, Option<String>) {
+    let mut lines = content.trim().lines();
+    let title: Vec<&str> = lines
+        .by_ref()
+        .take_while(|line| !line.trim().is_empty())
+        .map(str::trim)
+        .collect();
+    let rest = lines.collect::<Vec<_>>().join("\n");
+    let rest = rest.trim();
+    (
+        title.join(" "),
+        (!rest.is_empty()).then(|| rest.to_string()),
+    )
+}
+
+/// The fragment types that `config` maps to a section, in section order.
+pub fn fragment_types(config: &ChangelogConfig) -> Vec<&str> {
+    let order = config.section_order();
+    let mut sections: Vec<_> = config.section_labels().iter().collect();
+    // The sort is stable, so unlisted sections stay in alphabetical order.
+    sections.sort_by_key(|(name, _)| {
+        order
+            .iter()
+            .position(|ordered| ordered == *name)
+            .unwrap_or(order.len())
+    });
+
+    let mut types = Vec::new();
+    for label in sections.into_iter().flat_map(|(_, labels)| labels) {
+        if label != UNKNOWN_LABEL && !types.contains(&label.as_str()) {
+            types.push(label.as_str());
+        }
+    }
+    types
+}
+
+/// Read the fragments in `dir`, newest pull request first.
+///
+/// Hidden files and Markdown files without a type, such as a `README.md`, are skipped.
+pub(crate) fn read_fragments(dir: &Path, config: &ChangelogConfig) -> Result<Vec<Fragment>> {
+    if !dir.exists() {
+        return Ok(Vec::new());
+    }
+
+    let types = fragment_types(config);
+    let mut fragments = Vec::new();
+
+    for entry in fs_err::read_dir(dir)? {
+        let path = entry?.path();
+        let Some(name) = path.file_name().and_then(|name| name.to_str()) else {
+            continue;
+        };
+        if name.starts_with('.') || !path.is_file() {
+            continue;
+        }
+        let Some((number, kind)) = name
+            .strip_suffix(".md")
+            .and_then(|stem| stem.split_once('.'))
+        else {
+            continue;
+        };
+
+        let Ok(number) = number.parse() else {
+            bail!("Changelog fragment `{name}` must be named `<pull request number>.<type>.md`");
+        };
+        if !types.contains(&kind) && !config.ignore_labels().iter().any(|label| label == kind) {
+            bail!(
+                "Changelog fragment `{name}` has unknown type `{kind}`, expected one of: {}",
+                types.join(", ")
+            );
+        }
+
+        let content = fs_err::read_to_string(&path)?;
+        if content.trim().is_empty() {
+            bail!("Changelog fragment `{name}` is empty");
+        }
+        if config.release_notes.is_none() && split_title(&content).1.is_some() {
+            bail!(
+                "Changelog fragment `{name}` has more than one paragraph; add a `[changelog.release-notes]` table to show the paragraphs after the first as the entry's notes"
+            );
+        }
+
+        fragments.push(Fragment {
+            number,
+            kind: kind.to_string(),
+            content,
+            path,
+        });
+    }
+
+    fragments.sort_by(|a, b| b.number.cmp(&a.number).then_with(|| a.kind.cmp(&b.kind)));
+
+    Ok(fragments)
+}
+
+/// Write a fragment for pull request `number` to `dir`, returning its path.
+pub fn add_fragment(dir: &Path, number: u64, kind: &str, text: &str) -> Result<PathBuf> {
+    let path = dir.join(format!("{number}.{kind}.md"));
+    if path.exists() {
+        bail!("Changelog fragment `{}` already exists", path.display());
+    }
+
+    fs_err::create_dir_all(dir)?;
+    fs_err::write(&path, format!("{}\n", text.trim()))
+        .with_context(|| format!("Failed to write changelog fragment `{}`", path.display()))?;
+
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::*;
+
+    fn config() -> ChangelogConfig {
+        ChangelogConfig {
+            ignore_labels: Some(vec!["internal".to_string()]),
+            section_labels: Some(BTreeMap::from([
+                (
+                    "Bug fixes".to_string(),
+                    vec!["bugfix".to_string(), UNKNOWN_LABEL.to_string()],
+                ),
+                ("Features".to_string(), vec!["feature".to_string()]),
+            ])),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_fragment_types() {
+        assert_eq!(fragment_types(&config()), vec!["bugfix", "feature"]);
+
+        let config = ChangelogConfig {
+            section_order: Some(vec!["Features".to_string()]),
+            ..config()
+        };
+        assert_eq!(fragment_types(&config), vec!["feature", "bugfix"]);
+    }
+
+    #[test]
+    fn test_split_title() {
+        assert_eq!(
+            split_title("Add a feature\n"),
+            ("Add a feature".to_string(), None)
+        );
+        assert_eq!(
+            split_title("Add a feature\nwith a long title\n\n  \nMore details.\n\n- And a list\n"),
+            (
+                "Add a feature with a long title".to_string(),
+                Some("More details.\n\n- And a list".to_string())
+            )
+        );
+    }
+
+    #[test]
+    fn test_read_fragments() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let dir = temp_dir.path();
+        fs_err::write(dir.join("12.feature.md"), "Add a feature\n").unwrap();
+        fs_err::write(dir.join("15.bugfix.md"), "Fix a bug\n").unwrap();
+        fs_err::write(dir.join("16.internal.md"), "Refactor\n").unwrap();
+        fs_err::write(dir.join("README.md"), "Fragments go here.\n").unwrap();
+        fs_err::write(dir.join(".gitkeep"), "").unwrap();
+
+        let fragments = read_fragments(dir, &config()).unwrap();
+
+        let summary: Vec<_> = fragments
+            .iter()
+            .map(|fragment| {
+                (
+                    fragment.number,
+                    fragment.kind.as_str(),
+                    fragment.content.trim(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (16, "internal", "Refactor"),
+                (15, "bugfix", "Fix a bug"),
+                (12, "feature", "Add a feature"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_read_fragments_missing_dir() {
+        let temp_dir = tempfile::tempdir().unwrap();
+
+        let fragments = read_fragments(&temp_dir.path().join("changes"), &config()).unwrap();
+        assert!(fragments.is_empty());
+    }
+
+    #[test]
+    fn test_read_fragments_errors() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let dir = temp_dir.path();
+
+        fs_err::write(dir.join("12.docs.md"), "Document things\n").unwrap();
+        let err = read_fragments(dir, &config()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Changelog fragment `12.docs.md` has unknown type `docs`, expected one of: bugfix, feature"
+        );
+        fs_err::remove_file(dir.join("12.docs.md")).unwrap();
+
+        fs_err::write(dir.join("fix-typo.bugfix.md"), "Fix a typo\n").unwrap();
+        let err = read_fragments(dir, &config()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Changelog fragment `fix-typo.bugfix.md` must be named `<pull request number>.<type>.md`"
+        );
+        fs_err::remove_file(dir.join("fix-typo.bugfix.md")).unwrap();
+
+        fs_err::write(dir.join("12.bugfix.md"), "\n").unwrap();
+        let err = read_fragments(dir, &config()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Changelog fragment `12.bugfix.md` is empty"
+        );
+
+        fs_err::write(dir.join("12.bugfix.md"), "Fix a bug\n\nDetails\n").unwrap();
+        let err = read_fragments(dir, &config()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Changelog fragment `12.bugfix.md` has more than one paragraph; add a `[changelog.release-notes]` table to show the paragraphs after the first as the entry's notes"
+        );
+    }
+
+    #[test]
+    fn test_add_fragment() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let dir = temp_dir.path().join("changes");
+
+        let path = add_fragment(&dir, 42, "feature", "  Add a feature  ").unwrap();
+        assert_eq!(path, dir.join("42.feature.md"));
+        assert_eq!(fs_err::read_to_string(&path).unwrap(), "Add a feature\n");
+
+        let err = add_fragment(&dir, 42, "feature", "Again").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            format!("Changelog fragment `{}` already exists", path.display())
+        );
+    }
+}
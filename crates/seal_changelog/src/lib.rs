@@ -1,42 +1,33 @@
 use std::collections::{BTreeMap, HashSet};
-use std::fmt::Write;
 use std::path::Path;
 use std::sync::Arc;
 
 use anyhow::{Context, Result};
+use chrono::{NaiveDate, Utc};
 use seal_file_change::{FileChange, FileChanges};
-use seal_github::{GitHubPullRequest, GitHubService, filter_prs_by_date_range};
+use seal_forge::{ForgeService, PullRequest, Release};
 
-use seal_project::ChangelogConfig;
+use seal_project::{ChangelogArea, ChangelogConfig};
 use semver::Version;
 
-const VERSION_PLACEHOLDER: &str = "{version}";
-const UNKNOWN_LABEL: &str = "__unknown__";
-
-fn contributor_url(contributor: &str) -> String {
-    if let Some(app) = contributor.strip_suffix("[bot]") {
-        format!("https://github.com/apps/{app}")
-    } else {
-        format!("https://github.com/{contributor}")
-    }
-}
-
-fn write_contributors(output: &mut String, mut contributors: Vec<String>) -> Result<()> {
-    if contributors.is_empty() {
-        return Ok(());
-    }
+mod document;
+mod fragments;
+mod lint;
+mod notes;
+mod template;
+mod unreleased;
 
-    output.push_str("### Contributors\n\n");
-    contributors.sort();
+pub use document::{ChangelogDocument, DocumentHeading, EntryGroup, ReleaseSection};
+pub use fragments::{add_fragment, fragment_types};
+pub use lint::{ChangelogDiagnostic, lint_changelog};
+pub use template::{ChangelogRenderer, DEFAULT_TEMPLATE};
 
-    for contributor in contributors {
-        let url = contributor_url(&contributor);
-        writeln!(output, "- [@{contributor}]({url})")?;
-    }
+use crate::fragments::Fragment;
+use crate::template::ChangelogContext;
 
-    output.push('\n');
-    Ok(())
-}
+const VERSION_PLACEHOLDER: &str = "{version}";
+const UNKNOWN_LABEL: &str = "__unknown__";
+const OTHER_AREA: &str = "Other";
 
 pub const DEFAULT_CHANGELOG_PATH: &str = "CHANGELOG.md";
 
@@ -50,38 +41,148 @@ fn extract_version_from_release_name(name: Option<&String>) -> Option<String> {
     })
 }
 
+/// The release that a changelog section describes.
+#[derive(Debug, Clone)]
+pub struct ChangelogRelease {
+    pub version: String,
+    /// The version of the release before this one, if there is one.
+    pub previous_version: Option<String>,
+    pub date: NaiveDate,
+    pub tag: String,
+    /// The forge's comparison of the previous release's tag with this one.
+    pub compare_url: Option<String>,
+}
+
+impl ChangelogRelease {
+    pub fn new(
+        version: String,
+        previous_version: Option<String>,
+        date: NaiveDate,
+        config: &ChangelogConfig,
+        forge: &dyn ForgeService,
+    ) -> Self {
+        let tag = config.tag(&version);
+        let compare_url = previous_version
+            .as_ref()
+            .and_then(|previous| forge.compare_url(&config.tag(previous), &tag));
+
+        Self {
+            version,
+            previous_version,
+            date,
+            tag,
+            compare_url,
+        }
+    }
+
+    /// Fill in the placeholders of a `changelog-heading` template.
+    pub fn heading(&self, template: &str) -> String {
+        template
+            .replace(VERSION_PLACEHOLDER, &self.version)
+            .replace(
+                "{previous_version}",
+                self.previous_version.as_deref().unwrap_or_default(),
+            )
+            .replace("{date}", &self.date.format("%Y-%m-%d").to_string())
+            .replace("{tag}", &self.tag)
+            .replace(
+                "{compare_url}",
+                self.compare_url.as_deref().unwrap_or_default(),
+            )
+    }
+
+    /// The `[version]: compare-url` link reference definition for this release, if the forge
+    /// can compare it with a previous release.
+    pub fn link_definition(&self) -> Option<String> {
+        self.compare_url
+            .as_ref()
+            .map(|url| format!("[{}]: {url}", self.version))
+    }
+}
+
 struct ChangelogGenerator<'a> {
-    github_service: &'a Arc<dyn GitHubService>,
+    forge: &'a Arc<dyn ForgeService>,
 }
 
 impl<'a> ChangelogGenerator<'a> {
-    fn new(github_service: &'a Arc<dyn GitHubService>) -> Self {
-        Self { github_service }
+    fn new(forge: &'a Arc<dyn ForgeService>) -> Self {
+        Self { forge }
     }
 
-    async fn generate_changelog(&self, version: &str, config: &ChangelogConfig) -> Result<String> {
-        let release = self.github_service.get_latest_release().await.ok();
+    /// The release of `version` today, and the latest release on the forge before it.
+    async fn release(
+        &self,
+        version: &str,
+        config: &ChangelogConfig,
+    ) -> (ChangelogRelease, Option<Release>) {
+        let latest_release = self.forge.get_latest_release().await.ok();
+
+        let release = ChangelogRelease::new(
+            version.to_string(),
+            latest_release
+                .as_ref()
+                .and_then(|release| extract_version_from_release_name(release.name.as_ref())),
+            Utc::now().date_naive(),
+            config,
+            self.forge.as_ref(),
+        );
 
-        let prs = self
-            .github_service
-            .get_prs_between(release.as_ref().map(|r| &r.created_at), None)
-            .await?;
+        (release, latest_release)
+    }
 
-        format_changelog_content(version, prs, config)
+    /// Render the section for `release`, with entries from `fragments` when they are the
+    /// configured source, and otherwise from the pull requests merged since `latest_release`.
+    async fn generate_changelog(
+        &self,
+        release: &ChangelogRelease,
+        latest_release: Option<&Release>,
+        fragments: Option<&[Fragment]>,
+        config: &ChangelogConfig,
+        renderer: &ChangelogRenderer,
+    ) -> Result<String> {
+        let prs = if let Some(fragments) = fragments {
+            fragments
+                .iter()
+                .map(|fragment| fragment.to_pull_request(self.forge.as_ref()))
+                .collect()
+        } else {
+            self.forge
+                .get_prs_between(latest_release.map(|r| &r.created_at), None)
+                .await?
+        };
+
+        format_changelog_content(release, prs, config, renderer)
     }
 }
 
+/// A section of a release and its pull requests.
+#[derive(Debug, Clone)]
+pub struct CategorizedSection {
+    pub name: String,
+    pub prs: Vec<PullRequest>,
+}
+
+/// An area of the project and its sections.
+#[derive(Debug, Clone)]
+pub struct CategorizedArea {
+    pub name: String,
+    pub sections: Vec<CategorizedSection>,
+}
+
 pub struct CategorizedPRs {
-    pub sections: BTreeMap<String, Vec<GitHubPullRequest>>,
-    pub contributors: Vec<String>,
+    /// The sections, in `section-order`.
+    pub sections: Vec<CategorizedSection>,
+    /// The same pull requests grouped by area first, when `areas` are configured.
+    pub areas: Vec<CategorizedArea>,
+    /// Contributor logins, with their profile URL when the forge provides one.
+    pub contributors: BTreeMap<String, Option<String>>,
 }
 
-pub fn categorize_prs(prs: Vec<GitHubPullRequest>, config: &ChangelogConfig) -> CategorizedPRs {
+pub fn categorize_prs(prs: Vec<PullRequest>, config: &ChangelogConfig) -> CategorizedPRs {
     let ignore_labels: HashSet<&String> = config.ignore_labels().iter().collect();
-    let section_labels = config.section_labels();
 
-    let mut categorized = BTreeMap::new();
-    let mut contributors = HashSet::new();
+    let mut included = Vec::new();
+    let mut contributors = BTreeMap::new();
 
     for pr in prs {
         if pr.labels.iter().any(|l| ignore_labels.contains(l)) {
@@ -94,14 +195,68 @@ pub fn categorize_prs(prs: Vec<GitHubPullRequest>, config: &ChangelogConfig) ->
                     continue;
                 }
             }
-            contributors.insert(author.clone());
+            contributors.insert(author.clone(), pr.author_url.clone());
         }
 
+        included.push(pr);
+    }
+
+    let areas = if config.areas().is_empty() {
+        Vec::new()
+    } else {
+        let mut areas: Vec<(&str, Vec<PullRequest>)> = config
+            .areas()
+            .iter()
+            .map(|area| (area.name.as_str(), Vec::new()))
+            .chain([(OTHER_AREA, Vec::new())])
+            .collect();
+        for pr in &included {
+            let index = config
+                .areas()
+                .iter()
+                .position(|area| area_matches(area, pr))
+                .unwrap_or(areas.len() - 1);
+            areas[index].1.push(pr.clone());
+        }
+
+        areas
+            .into_iter()
+            .map(|(name, prs)| CategorizedArea {
+                name: name.to_string(),
+                sections: categorize_sections(prs, config),
+            })
+            .filter(|area| !area.sections.is_empty())
+            .collect()
+    };
+
+    CategorizedPRs {
+        sections: categorize_sections(included, config),
+        areas,
+        contributors,
+    }
+}
+
+/// Place each pull request in the first section mapped to one of its labels, and order the
+/// sections by `section-order`.
+fn categorize_sections(prs: Vec<PullRequest>, config: &ChangelogConfig) -> Vec<CategorizedSection> {
+    let section_labels = config.section_labels();
+    let area_labels: HashSet<&String> = config
+        .areas()
+        .iter()
+        .flat_map(|area| &area.labels)
+        .collect();
+
+    let mut categorized = BTreeMap::new();
+
+    for pr in prs {
+        // Area labels say where a change is, not what kind of change it is.
+        let unlabeled = pr.labels.iter().all(|label| area_labels.contains(label));
+
         let mut categorized_pr = false;
         for (section_name, section_label_list) in section_labels {
             for label in section_label_list {
                 if pr.labels.iter().any(|pr_label| pr_label == label)
-                    || pr.labels.is_empty() && label == UNKNOWN_LABEL
+                    || unlabeled && label == UNKNOWN_LABEL
                 {
                     categorized
                         .entry(section_name.clone())
@@ -117,75 +272,103 @@ pub fn categorize_prs(prs: Vec<GitHubPullRequest>, config: &ChangelogConfig) ->
         }
     }
 
-    CategorizedPRs {
-        sections: categorized,
-        contributors: contributors.into_iter().collect(),
+    let order = config.section_order();
+    let mut sections: Vec<CategorizedSection> = categorized
+        .into_iter()
+        .map(|(name, prs)| CategorizedSection { name, prs })
+        .collect();
+    // The sort is stable, so unlisted sections stay in alphabetical order.
+    sections.sort_by_key(|section| {
+        order
+            .iter()
+            .position(|name| *name == section.name)
+            .unwrap_or(order.len())
+    });
+    sections
+}
+
+/// Whether `pr` belongs to `area`, by label, conventional commit scope, or changed file.
+fn area_matches(area: &ChangelogArea, pr: &PullRequest) -> bool {
+    if area.labels.is_empty() && area.scopes.is_empty() && area.paths.is_empty() {
+        return true;
     }
+
+    area.labels.iter().any(|label| pr.labels.contains(label))
+        || conventional_commit_scopes(&pr.title)
+            .any(|scope| area.scopes.iter().any(|area_scope| area_scope == scope))
+        || area.paths.iter().any(|pattern| {
+            glob::Pattern::new(pattern)
+                .is_ok_and(|pattern| pr.changed_files.iter().any(|path| pattern.matches(path)))
+        })
+}
+
+/// The scopes of a conventional commit title, e.g. `cli` and `core` in `fix(cli, core)!: ...`.
+fn conventional_commit_scopes(title: &str) -> impl Iterator<Item = &str> {
+    let scopes = title
+        .split_once(':')
+        .and_then(|(prefix, _)| prefix.trim_end_matches('!').strip_suffix(')'))
+        .and_then(|prefix| prefix.split_once('('))
+        .filter(|(kind, _)| !kind.is_empty() && kind.chars().all(char::is_alphanumeric))
+        .map_or("", |(_, scopes)| scopes);
+
+    scopes
+        .split(',')
+        .map(str::trim)
+        .filter(|scope| !scope.is_empty())
 }
 
 pub fn format_changelog_content(
-    version: &str,
-    prs: Vec<GitHubPullRequest>,
+    release: &ChangelogRelease,
+    prs: Vec<PullRequest>,
     config: &ChangelogConfig,
+    renderer: &ChangelogRenderer,
 ) -> Result<String> {
     let categorized = categorize_prs(prs, config);
+    let heading = release.heading(config.changelog_heading());
+
+    renderer.render(&ChangelogContext::new(
+        &heading,
+        release.date,
+        Some(release),
+        &categorized,
+        config,
+    ))
+}
 
-    let mut output = String::new();
-
-    let heading = config
-        .changelog_heading()
-        .replace(VERSION_PLACEHOLDER, version);
-
-    write!(output, "## {heading}\n\n")?;
-
-    for (section_name, prs) in &categorized.sections {
-        write!(output, "### {section_name}\n\n")?;
-
-        for pr in prs {
-            writeln!(output, "- {} ([#{}]({}))", pr.title, pr.number, pr.url)?;
-        }
-
-        output.push('\n');
-    }
-
-    if config.include_contributors() {
-        write_contributors(&mut output, categorized.contributors)?;
+/// Insert `definition` at the top of the link reference definitions at the end of the changelog,
+/// replacing any existing definition with the same label.
+fn insert_link_definition(content: &str, definition: &str) -> Result<String> {
+    let label = definition
+        .split_once(": ")
+        .map_or(definition, |(label, _)| label);
+    let existing = format!("{label}:");
+
+    let mut remaining: String = content
+        .split_inclusive('\n')
+        .filter(|line| !line.starts_with(&existing))
+        .collect();
+    if !remaining.ends_with('\n') {
+        remaining.push('\n');
     }
 
-    Ok(output)
+    Ok(ChangelogDocument::parse(&remaining)?.insert_definition(definition))
 }
 
+/// Insert `new_content` as the first version section of the changelog, and `link_definition`,
+/// if any, at the top of the link reference definitions at the bottom.
 pub fn prepare_changelog_file_change(
     changelog_path: &Path,
     new_content: &str,
+    link_definition: Option<&str>,
 ) -> Result<FileChange> {
-    let existing_content = if changelog_path.exists() {
-        fs_err::read_to_string(changelog_path)?
-    } else {
-        String::new()
-    };
+    let existing_content = read_changelog(changelog_path)?;
 
-    let updated_content = {
-        let first_line_is_heading = existing_content
-            .lines()
-            .next()
-            .is_some_and(|line| line.starts_with('#'));
-
-        if first_line_is_heading {
-            let newline_pos = existing_content.find('\n');
-            if let Some(pos) = newline_pos {
-                let heading = &existing_content[..pos];
-                let after_heading = &existing_content[pos + 1..];
-                let rest = after_heading.trim_start_matches('\n');
-
-                format!("{heading}\n\n{new_content}{rest}")
-            } else {
-                format!("{existing_content}\n\n{new_content}")
-            }
-        } else {
-            format!("# Changelog\n\n{new_content}{existing_content}")
-        }
-    };
+    let mut updated_content =
+        ChangelogDocument::parse(&existing_content)?.insert_section(new_content);
+
+    if let Some(definition) = link_definition {
+        updated_content = insert_link_definition(&updated_content, definition)?;
+    }
 
     Ok(FileChange::new(
         changelog_path.to_path_buf(),
@@ -194,6 +377,14 @@ pub fn prepare_changelog_file_change(
     ))
 }
 
+fn read_changelog(changelog_path: &Path) -> Result<String> {
+    if changelog_path.exists() {
+        Ok(fs_err::read_to_string(changelog_path)?)
+    } else {
+        Ok(String::new())
+    }
+}
+
 pub struct PreparedChangelog {
     pub file_changes: FileChanges,
     pub section_body: String,
@@ -201,7 +392,12 @@ pub struct PreparedChangelog {
 
 impl PreparedChangelog {
     fn new(file_changes: FileChanges, generated_section: &str) -> Result<Self> {
-        let section_body = parse_latest_changelog_section(generated_section)?.body;
+        let document = ChangelogDocument::parse(generated_section)?;
+        let section_body = document
+            .sections()
+            .first()
+            .map(|section| section.body.to_string())
+            .unwrap_or_default();
 
         Ok(Self {
             file_changes,
@@ -214,36 +410,118 @@ pub async fn prepare_changelog_changes(
     root: &Path,
     version: &str,
     config: &ChangelogConfig,
-    github_client: &Arc<dyn GitHubService>,
+    forge: &Arc<dyn ForgeService>,
 ) -> Result<PreparedChangelog> {
-    let generator = ChangelogGenerator::new(github_client);
-    let changelog_content = generator.generate_changelog(version, config).await?;
+    let renderer = ChangelogRenderer::new(root, config)?;
+    let generator = ChangelogGenerator::new(forge);
+    let (release, latest_release) = generator.release(version, config).await;
 
     let changelog_path = if let Some(path) = config.changelog_path.as_ref() {
         root.join(path)
     } else {
         root.join("CHANGELOG.md")
     };
-    let change = prepare_changelog_file_change(&changelog_path, &changelog_content)?;
 
-    PreparedChangelog::new(FileChanges::new(vec![change]), &changelog_content)
+    // Fragments are only consumed when the generated entries are used.
+    let fragments = match &config.fragments_dir {
+        Some(dir) if config.unreleased.as_ref().is_none_or(|u| u.merge_generated) => {
+            Some(fragments::read_fragments(&root.join(dir), config)?)
+        }
+        _ => None,
+    };
+    let mut changes: Vec<FileChange> = fragments.iter().flatten().map(Fragment::deletion).collect();
+
+    if let Some(unreleased) = &config.unreleased {
+        let generated = if unreleased.merge_generated {
+            Some(
+                generator
+                    .generate_changelog(
+                        &release,
+                        latest_release.as_ref(),
+                        fragments.as_deref(),
+                        config,
+                        &renderer,
+                    )
+                    .await?,
+            )
+        } else {
+            None
+        };
+
+        let existing_content = read_changelog(&changelog_path)?;
+        let released = unreleased::release_unreleased(
+            &existing_content,
+            &release.heading(config.changelog_heading()),
+            generated.as_deref(),
+            unreleased.require_notes,
+        )
+        .with_context(|| {
+            format!(
+                "Failed to release the Unreleased section of `{}`",
+                changelog_path.display()
+            )
+        })?;
+
+        let mut updated_content = released.content;
+        if config.compare_links {
+            if let Some(definition) = release.link_definition() {
+                updated_content = insert_link_definition(&updated_content, &definition)?;
+            }
+            if let Some(url) = forge.compare_url(&release.tag, "HEAD") {
+                let definition = format!("{}: {url}", released.label);
+                updated_content = insert_link_definition(&updated_content, &definition)?;
+            }
+        }
+
+        changes.insert(
+            0,
+            FileChange::new(changelog_path, existing_content, updated_content),
+        );
+        return PreparedChangelog::new(FileChanges::new(changes), &released.section);
+    }
+
+    let changelog_content = generator
+        .generate_changelog(
+            &release,
+            latest_release.as_ref(),
+            fragments.as_deref(),
+            config,
+            &renderer,
+        )
+        .await?;
+    let link_definition = if config.compare_links {
+        release.link_definition()
+    } else {
+        None
+    };
+    changes.insert(
+        0,
+        prepare_changelog_file_change(
+            &changelog_path,
+            &changelog_content,
+            link_definition.as_deref(),
+        )?,
+    );
+
+    PreparedChangelog::new(FileChanges::new(changes), &changelog_content)
+}
+
+/// The heading of a release whose name has no version, e.g. `Release 2025-12-01`.
+pub(crate) fn unnamed_release_heading(date: NaiveDate) -> String {
+    format!("Release {}", date.format("%Y-%m-%d"))
 }
 
 pub async fn generate_full_changelog(
+    root: &Path,
     config: &ChangelogConfig,
-    github_client: &Arc<dyn GitHubService>,
-    max_prs: usize,
+    forge: &Arc<dyn ForgeService>,
 ) -> Result<String> {
-    let releases = github_client.get_all_releases().await?;
+    let renderer = ChangelogRenderer::new(root, config)?;
+    let releases = forge.get_all_releases().await?;
 
     let mut output = String::new();
 
-    let all_prs = github_client.get_prs(Some(max_prs)).await?;
-
-    let mut release_pairs: Vec<(
-        Option<&seal_github::GitHubRelease>,
-        &seal_github::GitHubRelease,
-    )> = Vec::new();
+    let mut release_pairs: Vec<(Option<&Release>, &Release)> = Vec::new();
 
     let Some(first_release) = releases.first() else {
         return Ok(output);
@@ -256,41 +534,41 @@ pub async fn generate_full_changelog(
     }
 
     for (since, until) in release_pairs.iter().rev() {
-        let filter_prs_by_date_range = filter_prs_by_date_range(
-            &all_prs,
-            since.map(|release| &release.created_at),
-            Some(&until.created_at),
-        );
+        let prs = forge
+            .get_prs_between(
+                since.map(|release| &release.created_at),
+                Some(&until.created_at),
+            )
+            .await?;
 
-        if filter_prs_by_date_range.is_empty() {
+        if prs.is_empty() {
             continue;
         }
 
-        let categorized = categorize_prs(filter_prs_by_date_range, config);
-
-        if let Some(version) = extract_version_from_release_name(until.name.as_ref()) {
-            writeln!(output, "## {version}\n")?;
-        } else {
-            writeln!(
-                output,
-                "## Release {}\n",
-                until.created_at.format("%Y-%m-%d")
-            )?;
-        }
-
-        for (section_name, prs) in &categorized.sections {
-            write!(output, "### {section_name}\n\n")?;
-
-            for pr in prs {
-                writeln!(output, "- {} ([#{}]({}))", pr.title, pr.number, pr.url)?;
-            }
-
-            output.push('\n');
-        }
+        let categorized = categorize_prs(prs, config);
+        let date = until.created_at.date_naive();
+
+        let release = extract_version_from_release_name(until.name.as_ref()).map(|version| {
+            ChangelogRelease::new(
+                version,
+                since.and_then(|since| extract_version_from_release_name(since.name.as_ref())),
+                date,
+                config,
+                forge.as_ref(),
+            )
+        });
+        let heading = release.as_ref().map_or_else(
+            || unnamed_release_heading(date),
+            |release| release.version.clone(),
+        );
 
-        if config.include_contributors() {
-            write_contributors(&mut output, categorized.contributors)?;
-        }
+        output.push_str(&renderer.render(&ChangelogContext::new(
+            &heading,
+            date,
+            release.as_ref(),
+            &categorized,
+            config,
+        ))?);
     }
 
     Ok(output)
@@ -309,76 +587,150 @@ pub struct ReleaseBody {
     pub prerelease: bool,
 }
 
+/// The latest version section of a changelog, skipping an `Unreleased` section.
 pub fn parse_latest_changelog_section(changelog_content: &str) -> Result<ChangelogSection> {
-    let lines: Vec<&str> = changelog_content.lines().collect();
-
-    let section_start = lines
-        .iter()
-        .position(|line| line.starts_with("## "))
+    let document = ChangelogDocument::parse(changelog_content)?;
+    let section = document
+        .latest_release()
         .ok_or_else(|| anyhow::anyhow!("No version sections found in changelog"))?;
 
-    let version = lines[section_start]
-        .strip_prefix("## ")
-        .unwrap()
-        .trim()
-        .to_string();
+    Ok(ChangelogSection::from(section))
+}
+
+/// The section of a changelog for `version`.
+pub fn parse_changelog_section(changelog_content: &str, version: &str) -> Result<ChangelogSection> {
+    let version =
+        Version::parse(version).with_context(|| format!("Invalid version `{version}`"))?;
+    let document = ChangelogDocument::parse(changelog_content)?;
 
-    let section_end = lines[section_start + 1..]
-        .iter()
-        .position(|line| line.starts_with("## "))
-        .map(|pos| section_start + 1 + pos)
-        .unwrap_or(lines.len());
+    if let Some(section) = document.section(&version) {
+        return Ok(ChangelogSection::from(section));
+    }
+
+    let available: Vec<String> = document
+        .releases()
+        .filter_map(|section| section.version.as_ref().map(ToString::to_string))
+        .collect();
+    if available.is_empty() {
+        anyhow::bail!("No version sections found in changelog");
+    }
+    anyhow::bail!(
+        "No section found for version {version} in changelog, available versions: {}",
+        available.join(", ")
+    )
+}
+
+impl From<&ReleaseSection<'_>> for ChangelogSection {
+    fn from(section: &ReleaseSection<'_>) -> Self {
+        Self {
+            version: section
+                .version
+                .as_ref()
+                .map(ToString::to_string)
+                .unwrap_or_default(),
+            body: section.body.to_string(),
+        }
+    }
+}
+
+impl TryFrom<ChangelogSection> for ReleaseBody {
+    type Error = anyhow::Error;
 
-    let body_lines = &lines[section_start + 1..section_end];
-    let body = body_lines.join("\n").trim().to_string();
+    fn try_from(section: ChangelogSection) -> Result<Self> {
+        let version = Version::parse(&section.version).context("Invalid current version")?;
+        let prerelease = !version.pre.is_empty();
 
-    Ok(ChangelogSection { version, body })
+        Ok(Self {
+            title: section.version,
+            body: section.body,
+            prerelease,
+        })
+    }
 }
 
+/// The release for the latest version section of a changelog.
 pub fn create_release_body(changelog_content: &str) -> Result<ReleaseBody> {
-    let section = parse_latest_changelog_section(changelog_content)?;
-    let version = Version::parse(&section.version).context("Invalid current version")?;
-    let prerelease = !version.pre.is_empty();
-
-    Ok(ReleaseBody {
-        title: section.version,
-        body: section.body,
-        prerelease,
-    })
+    parse_latest_changelog_section(changelog_content)?.try_into()
+}
+
+/// The release for the section of a changelog for `version`.
+pub fn create_release_body_for_version(
+    changelog_content: &str,
+    version: &str,
+) -> Result<ReleaseBody> {
+    parse_changelog_section(changelog_content, version)?.try_into()
+}
+
+/// The releases for every version section of a changelog, newest first.
+pub fn create_all_release_bodies(changelog_content: &str) -> Result<Vec<ReleaseBody>> {
+    let document = ChangelogDocument::parse(changelog_content)?;
+    let bodies = document
+        .releases()
+        .map(|section| ChangelogSection::from(section).try_into())
+        .collect::<Result<Vec<_>>>()?;
+
+    if bodies.is_empty() {
+        anyhow::bail!("No version sections found in changelog");
+    }
+
+    Ok(bodies)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use chrono::{TimeZone, Utc};
-    use seal_project::ChangelogHeading;
+    use seal_forge::IssueReference;
+    use seal_project::{ChangelogHeading, ChangelogTemplate, ReleaseNotesConfig};
     use std::collections::BTreeMap;
 
+    fn release(version: &str) -> ChangelogRelease {
+        ChangelogRelease {
+            version: version.to_string(),
+            previous_version: None,
+            date: NaiveDate::from_ymd_opt(2025, 12, 10).unwrap(),
+            tag: format!("v{version}"),
+            compare_url: None,
+        }
+    }
+
     #[test]
     fn test_format_changelog_with_section_labels() {
         let prs = vec![
-            GitHubPullRequest {
+            PullRequest {
                 title: "Breaking API change".to_string(),
                 number: 1,
                 url: "https://github.com/owner/repo/pull/1".to_string(),
                 labels: vec!["breaking".to_string()],
                 author: Some("alice".to_string()),
+                author_url: Some("https://github.com/alice".to_string()),
+                body: None,
+                closed_issues: Vec::new(),
+                changed_files: Vec::new(),
                 merged_at: Utc.with_ymd_and_hms(2025, 12, 1, 10, 0, 0).unwrap(),
             },
-            GitHubPullRequest {
+            PullRequest {
                 title: "Add new feature".to_string(),
                 number: 2,
                 url: "https://github.com/owner/repo/pull/2".to_string(),
                 labels: vec!["enhancement".to_string()],
                 author: Some("bob".to_string()),
+                author_url: Some("https://github.com/bob".to_string()),
+                body: None,
+                closed_issues: Vec::new(),
+                changed_files: Vec::new(),
                 merged_at: Utc.with_ymd_and_hms(2025, 12, 2, 14, 30, 0).unwrap(),
             },
-            GitHubPullRequest {
+            PullRequest {
                 title: "Fix bug".to_string(),
                 number: 3,
                 url: "https://github.com/owner/repo/pull/3".to_string(),
                 labels: vec!["bug".to_string()],
                 author: Some("alice".to_string()),
+                author_url: Some("https://github.com/alice".to_string()),
+                body: None,
+                closed_issues: Vec::new(),
+                changed_files: Vec::new(),
                 merged_at: Utc.with_ymd_and_hms(2025, 12, 3, 9, 15, 0).unwrap(),
             },
         ];
@@ -400,7 +752,13 @@ mod tests {
             ..Default::default()
         };
 
-        let result = format_changelog_content("1.0.0", prs, &config).unwrap();
+        let result = format_changelog_content(
+            &release("1.0.0"),
+            prs,
+            &config,
+            &ChangelogRenderer::default(),
+        )
+        .unwrap();
 
         insta::assert_snapshot!(result, @r###"
         ## 1.0.0
@@ -428,28 +786,40 @@ mod tests {
     #[test]
     fn test_format_changelog_with_ignored_labels() {
         let prs = vec![
-            GitHubPullRequest {
+            PullRequest {
                 title: "Add feature".to_string(),
                 number: 1,
                 url: "https://github.com/owner/repo/pull/1".to_string(),
                 labels: vec!["enhancement".to_string()],
                 author: Some("alice".to_string()),
+                author_url: Some("https://github.com/alice".to_string()),
+                body: None,
+                closed_issues: Vec::new(),
+                changed_files: Vec::new(),
                 merged_at: Utc.with_ymd_and_hms(2025, 11, 20, 11, 0, 0).unwrap(),
             },
-            GitHubPullRequest {
+            PullRequest {
                 title: "Internal refactor".to_string(),
                 number: 2,
                 url: "https://github.com/owner/repo/pull/2".to_string(),
                 labels: vec!["internal".to_string()],
                 author: Some("bob".to_string()),
+                author_url: Some("https://github.com/bob".to_string()),
+                body: None,
+                closed_issues: Vec::new(),
+                changed_files: Vec::new(),
                 merged_at: Utc.with_ymd_and_hms(2025, 11, 21, 13, 45, 0).unwrap(),
             },
-            GitHubPullRequest {
+            PullRequest {
                 title: "CI improvement".to_string(),
                 number: 3,
                 url: "https://github.com/owner/repo/pull/3".to_string(),
                 labels: vec!["ci".to_string()],
                 author: Some("charlie".to_string()),
+                author_url: Some("https://github.com/charlie".to_string()),
+                body: None,
+                closed_issues: Vec::new(),
+                changed_files: Vec::new(),
                 merged_at: Utc.with_ymd_and_hms(2025, 11, 22, 16, 20, 0).unwrap(),
             },
         ];
@@ -466,7 +836,13 @@ mod tests {
             ..Default::default()
         };
 
-        let result = format_changelog_content("1.0.0", prs, &config).unwrap();
+        let result = format_changelog_content(
+            &release("1.0.0"),
+            prs,
+            &config,
+            &ChangelogRenderer::default(),
+        )
+        .unwrap();
 
         insta::assert_snapshot!(result, @r###"
         ## 1.0.0
@@ -484,12 +860,16 @@ mod tests {
 
     #[test]
     fn test_format_changelog_with_custom_heading() {
-        let prs = vec![GitHubPullRequest {
+        let prs = vec![PullRequest {
             title: "Add feature".to_string(),
             number: 1,
             url: "https://github.com/owner/repo/pull/1".to_string(),
             labels: vec!["enhancement".to_string()],
             author: Some("alice".to_string()),
+            author_url: Some("https://github.com/alice".to_string()),
+            body: None,
+            closed_issues: Vec::new(),
+            changed_files: Vec::new(),
             merged_at: Utc.with_ymd_and_hms(2025, 10, 15, 8, 30, 0).unwrap(),
         }];
 
@@ -507,7 +887,13 @@ mod tests {
             ..Default::default()
         };
 
-        let result = format_changelog_content("1.0.0", prs, &config).unwrap();
+        let result = format_changelog_content(
+            &release("1.0.0"),
+            prs,
+            &config,
+            &ChangelogRenderer::default(),
+        )
+        .unwrap();
 
         insta::assert_snapshot!(result, @r###"
         ## Version 1.0.0 - Released
@@ -521,12 +907,16 @@ mod tests {
 
     #[test]
     fn test_format_changelog_without_contributors() {
-        let prs = vec![GitHubPullRequest {
+        let prs = vec![PullRequest {
             title: "Add feature".to_string(),
             number: 1,
             url: "https://github.com/owner/repo/pull/1".to_string(),
             labels: vec!["enhancement".to_string()],
             author: Some("alice".to_string()),
+            author_url: Some("https://github.com/alice".to_string()),
+            body: None,
+            closed_issues: Vec::new(),
+            changed_files: Vec::new(),
             merged_at: Utc.with_ymd_and_hms(2025, 9, 5, 12, 0, 0).unwrap(),
         }];
 
@@ -542,7 +932,13 @@ mod tests {
             ..Default::default()
         };
 
-        let result = format_changelog_content("1.0.0", prs, &config).unwrap();
+        let result = format_changelog_content(
+            &release("1.0.0"),
+            prs,
+            &config,
+            &ChangelogRenderer::default(),
+        )
+        .unwrap();
 
         insta::assert_snapshot!(result, @r###"
         ## 1.0.0
@@ -556,12 +952,16 @@ mod tests {
 
     #[test]
     fn test_format_changelog_with_bot_contributor() {
-        let prs = vec![GitHubPullRequest {
+        let prs = vec![PullRequest {
             title: "Update workflow".to_string(),
             number: 1,
             url: "https://github.com/owner/repo/pull/1".to_string(),
             labels: vec!["ci".to_string()],
             author: Some("github-actions[bot]".to_string()),
+            author_url: Some("https://github.com/apps/github-actions".to_string()),
+            body: None,
+            closed_issues: Vec::new(),
+            changed_files: Vec::new(),
             merged_at: Utc.with_ymd_and_hms(2025, 9, 5, 12, 0, 0).unwrap(),
         }];
 
@@ -573,30 +973,78 @@ mod tests {
             ..Default::default()
         };
 
-        let result = format_changelog_content("1.0.0", prs, &config).unwrap();
+        let result = format_changelog_content(
+            &release("1.0.0"),
+            prs,
+            &config,
+            &ChangelogRenderer::default(),
+        )
+        .unwrap();
 
         assert!(
             result.contains("- [@github-actions[bot]](https://github.com/apps/github-actions)")
         );
     }
 
+    #[test]
+    fn test_format_changelog_contributor_without_profile_url() {
+        let prs = vec![PullRequest {
+            title: "Add feature".to_string(),
+            number: 1,
+            url: "https://gitlab.example.com/group/project/-/merge_requests/1".to_string(),
+            labels: vec!["enhancement".to_string()],
+            author: Some("alice".to_string()),
+            author_url: None,
+            body: None,
+            closed_issues: Vec::new(),
+            changed_files: Vec::new(),
+            merged_at: Utc.with_ymd_and_hms(2025, 9, 5, 12, 0, 0).unwrap(),
+        }];
+
+        let mut section_labels = BTreeMap::new();
+        section_labels.insert("Enhancements".to_string(), vec!["enhancement".to_string()]);
+
+        let config = ChangelogConfig {
+            section_labels: Some(section_labels),
+            ..Default::default()
+        };
+
+        let result = format_changelog_content(
+            &release("1.0.0"),
+            prs,
+            &config,
+            &ChangelogRenderer::default(),
+        )
+        .unwrap();
+
+        assert!(result.ends_with("### Contributors\n\n- @alice\n\n"));
+    }
+
     #[test]
     fn test_format_changelog_with_other_section() {
         let prs = vec![
-            GitHubPullRequest {
+            PullRequest {
                 title: "Add feature".to_string(),
                 number: 1,
                 url: "https://github.com/owner/repo/pull/1".to_string(),
                 labels: vec!["enhancement".to_string()],
                 author: Some("alice".to_string()),
+                author_url: Some("https://github.com/alice".to_string()),
+                body: None,
+                closed_issues: Vec::new(),
+                changed_files: Vec::new(),
                 merged_at: Utc.with_ymd_and_hms(2025, 8, 12, 15, 20, 0).unwrap(),
             },
-            GitHubPullRequest {
+            PullRequest {
                 title: "Update docs".to_string(),
                 number: 2,
                 url: "https://github.com/owner/repo/pull/2".to_string(),
                 labels: vec!["documentation".to_string()],
                 author: Some("bob".to_string()),
+                author_url: Some("https://github.com/bob".to_string()),
+                body: None,
+                closed_issues: Vec::new(),
+                changed_files: Vec::new(),
                 merged_at: Utc.with_ymd_and_hms(2025, 8, 13, 9, 45, 0).unwrap(),
             },
         ];
@@ -613,7 +1061,13 @@ mod tests {
             ..Default::default()
         };
 
-        let result = format_changelog_content("1.0.0", prs, &config).unwrap();
+        let result = format_changelog_content(
+            &release("1.0.0"),
+            prs,
+            &config,
+            &ChangelogRenderer::default(),
+        )
+        .unwrap();
 
         insta::assert_snapshot!(result, @r"
         ## 1.0.0
@@ -632,20 +1086,28 @@ mod tests {
     #[test]
     fn test_format_changelog_with_unlabeled_pr() {
         let prs = vec![
-            GitHubPullRequest {
+            PullRequest {
                 title: "Unlabeled change".to_string(),
                 number: 1,
                 url: "https://github.com/owner/repo/pull/1".to_string(),
                 labels: vec![],
                 author: Some("alice".to_string()),
+                author_url: Some("https://github.com/alice".to_string()),
+                body: None,
+                closed_issues: Vec::new(),
+                changed_files: Vec::new(),
                 merged_at: Utc.with_ymd_and_hms(2025, 8, 12, 15, 20, 0).unwrap(),
             },
-            GitHubPullRequest {
+            PullRequest {
                 title: "Unmapped change".to_string(),
                 number: 2,
                 url: "https://github.com/owner/repo/pull/2".to_string(),
                 labels: vec!["documentation".to_string()],
                 author: Some("bob".to_string()),
+                author_url: Some("https://github.com/bob".to_string()),
+                body: None,
+                closed_issues: Vec::new(),
+                changed_files: Vec::new(),
                 merged_at: Utc.with_ymd_and_hms(2025, 8, 13, 9, 45, 0).unwrap(),
             },
         ];
@@ -659,7 +1121,13 @@ mod tests {
             ..Default::default()
         };
 
-        let result = format_changelog_content("1.0.0", prs, &config).unwrap();
+        let result = format_changelog_content(
+            &release("1.0.0"),
+            prs,
+            &config,
+            &ChangelogRenderer::default(),
+        )
+        .unwrap();
 
         insta::assert_snapshot!(result, @r"
         ## 1.0.0
@@ -684,7 +1152,13 @@ mod tests {
             ..Default::default()
         };
 
-        let result = format_changelog_content("1.0.0", prs, &config).unwrap();
+        let result = format_changelog_content(
+            &release("1.0.0"),
+            prs,
+            &config,
+            &ChangelogRenderer::default(),
+        )
+        .unwrap();
 
         insta::assert_snapshot!(result, @r###"
         ## 1.0.0
@@ -692,13 +1166,466 @@ mod tests {
         "###);
     }
 
+    fn release_notes_prs() -> Vec<PullRequest> {
+        let pr = |number: u64, title: &str, labels: &[&str], body: &str| PullRequest {
+            title: title.to_string(),
+            number,
+            url: format!("https://github.com/owner/repo/pull/{number}"),
+            labels: labels.iter().map(ToString::to_string).collect(),
+            author: None,
+            author_url: None,
+            body: Some(body.to_string()),
+            closed_issues: Vec::new(),
+            changed_files: Vec::new(),
+            merged_at: Utc.with_ymd_and_hms(2025, 12, 1, 10, 0, 0).unwrap(),
+        };
+
+        vec![
+            pr(
+                3,
+                "Rename the config file",
+                &["enhancement"],
+                "BREAKING CHANGE: `seal.toml` is now `.seal.toml`.\n\nRename it to upgrade.\n",
+            ),
+            pr(
+                2,
+                "Print timings",
+                &["enhancement"],
+                "```release-notes\nPass `--verbose` to see\nhow long each step takes.\n```\n",
+            ),
+            pr(1, "Fix typo", &["enhancement"], "Just a typo."),
+        ]
+    }
+
+    #[test]
+    fn test_format_changelog_with_release_notes() {
+        let config = ChangelogConfig {
+            section_labels: Some(BTreeMap::from([(
+                "Enhancements".to_string(),
+                vec!["enhancement".to_string()],
+            )])),
+            release_notes: Some(ReleaseNotesConfig::default()),
+            ..Default::default()
+        };
+
+        let result = format_changelog_content(
+            &release("1.0.0"),
+            release_notes_prs(),
+            &config,
+            &ChangelogRenderer::default(),
+        )
+        .unwrap();
+
+        insta::assert_snapshot!(result, @r"
+        ## 1.0.0
+
+        ### Enhancements
+
+        - Rename the config file ([#3](https://github.com/owner/repo/pull/3))
+
+          `seal.toml` is now `.seal.toml`.
+
+          Rename it to upgrade.
+
+        - Print timings ([#2](https://github.com/owner/repo/pull/2))
+
+          Pass `--verbose` to see
+          how long each step takes.
+
+        - Fix typo ([#1](https://github.com/owner/repo/pull/1))
+        ");
+    }
+
+    #[test]
+    fn test_format_changelog_with_breaking_preamble() {
+        let config = ChangelogConfig {
+            section_labels: Some(BTreeMap::from([(
+                "Enhancements".to_string(),
+                vec!["enhancement".to_string()],
+            )])),
+            release_notes: Some(ReleaseNotesConfig {
+                breaking_preamble: true,
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let result = format_changelog_content(
+            &release("1.0.0"),
+            release_notes_prs(),
+            &config,
+            &ChangelogRenderer::default(),
+        )
+        .unwrap();
+
+        insta::assert_snapshot!(result, @r"
+    ## 1.0.0
+
+    ### Breaking changes
+
+    - Rename the config file ([#3](https://github.com/owner/repo/pull/3))
+
+      `seal.toml` is now `.seal.toml`.
+
+      Rename it to upgrade.
+
+    ### Enhancements
+
+    - Rename the config file ([#3](https://github.com/owner/repo/pull/3))
+    - Print timings ([#2](https://github.com/owner/repo/pull/2))
+
+      Pass `--verbose` to see
+      how long each step takes.
+
+    - Fix typo ([#1](https://github.com/owner/repo/pull/1))
+    ");
+    }
+
+    #[test]
+    fn test_format_changelog_with_closed_issues() {
+        let issue = |number: u64| IssueReference {
+            number,
+            url: format!("https://github.com/owner/repo/issues/{number}"),
+        };
+        let pr = |number: u64, title: &str, closed_issues: Vec<IssueReference>| PullRequest {
+            title: title.to_string(),
+            number,
+            url: format!("https://github.com/owner/repo/pull/{number}"),
+            labels: vec!["bug".to_string()],
+            author: None,
+            author_url: None,
+            body: None,
+            closed_issues,
+            changed_files: Vec::new(),
+            merged_at: Utc.with_ymd_and_hms(2025, 12, 1, 10, 0, 0).unwrap(),
+        };
+        let prs = vec![
+            pr(14, "Fix crash on startup", vec![issue(10), issue(11)]),
+            pr(13, "Fix typo", vec![]),
+            pr(12, "Fix crash", vec![issue(9)]),
+        ];
+
+        let mut config = ChangelogConfig {
+            section_labels: Some(BTreeMap::from([(
+                "Bug fixes".to_string(),
+                vec!["bug".to_string()],
+            )])),
+            link_closed_issues: true,
+            ..Default::default()
+        };
+
+        let result = format_changelog_content(
+            &release("1.0.0"),
+            prs.clone(),
+            &config,
+            &ChangelogRenderer::default(),
+        )
+        .unwrap();
+
+        insta::assert_snapshot!(result, @r"
+        ## 1.0.0
+
+        ### Bug fixes
+
+        - Fix crash on startup ([#14](https://github.com/owner/repo/pull/14), closes [#10](https://github.com/owner/repo/issues/10), [#11](https://github.com/owner/repo/issues/11))
+        - Fix typo ([#13](https://github.com/owner/repo/pull/13))
+        - Fix crash ([#12](https://github.com/owner/repo/pull/12), closes [#9](https://github.com/owner/repo/issues/9))
+        ");
+
+        config.link_closed_issues = false;
+        let result = format_changelog_content(
+            &release("1.0.0"),
+            prs,
+            &config,
+            &ChangelogRenderer::default(),
+        )
+        .unwrap();
+
+        insta::assert_snapshot!(result, @r"
+        ## 1.0.0
+
+        ### Bug fixes
+
+        - Fix crash on startup ([#14](https://github.com/owner/repo/pull/14))
+        - Fix typo ([#13](https://github.com/owner/repo/pull/13))
+        - Fix crash ([#12](https://github.com/owner/repo/pull/12))
+        ");
+    }
+
+    #[test]
+    fn test_format_changelog_with_section_order() {
+        let pr = |number: u64, title: &str, label: &str| PullRequest {
+            title: title.to_string(),
+            number,
+            url: format!("https://github.com/owner/repo/pull/{number}"),
+            labels: vec![label.to_string()],
+            author: None,
+            author_url: None,
+            body: None,
+            closed_issues: Vec::new(),
+            changed_files: Vec::new(),
+            merged_at: Utc.with_ymd_and_hms(2025, 12, 1, 10, 0, 0).unwrap(),
+        };
+        let prs = vec![
+            pr(3, "Update docs", "docs"),
+            pr(2, "Fix bug", "bug"),
+            pr(1, "Add feature", "enhancement"),
+        ];
+
+        let config = ChangelogConfig {
+            section_labels: Some(BTreeMap::from([
+                ("Bug fixes".to_string(), vec!["bug".to_string()]),
+                ("Documentation".to_string(), vec!["docs".to_string()]),
+                ("Enhancements".to_string(), vec!["enhancement".to_string()]),
+            ])),
+            section_order: Some(vec!["Enhancements".to_string(), "Bug fixes".to_string()]),
+            ..Default::default()
+        };
+
+        let result = format_changelog_content(
+            &release("1.0.0"),
+            prs,
+            &config,
+            &ChangelogRenderer::default(),
+        )
+        .unwrap();
+
+        insta::assert_snapshot!(result, @r"
+        ## 1.0.0
+
+        ### Enhancements
+
+        - Add feature ([#1](https://github.com/owner/repo/pull/1))
+
+        ### Bug fixes
+
+        - Fix bug ([#2](https://github.com/owner/repo/pull/2))
+
+        ### Documentation
+
+        - Update docs ([#3](https://github.com/owner/repo/pull/3))
+        ");
+    }
+
+    #[test]
+    fn test_format_changelog_with_areas() {
+        let pr = |number: u64, title: &str, labels: &[&str], changed_files: &[&str]| PullRequest {
+            title: title.to_string(),
+            number,
+            url: format!("https://github.com/owner/repo/pull/{number}"),
+            labels: labels.iter().map(ToString::to_string).collect(),
+            author: None,
+            author_url: None,
+            body: None,
+            closed_issues: Vec::new(),
+            changed_files: changed_files.iter().map(ToString::to_string).collect(),
+            merged_at: Utc.with_ymd_and_hms(2025, 12, 1, 10, 0, 0).unwrap(),
+        };
+        let prs = vec![
+            pr(5, "Update the README", &[], &["README.md"]),
+            pr(4, "fix(cli): Handle missing arguments", &["bug"], &[]),
+            pr(
+                3,
+                "Speed up parsing",
+                &["enhancement"],
+                &["crates/core/src/parser.rs"],
+            ),
+            pr(2, "Add a `--quiet` flag", &["enhancement", "area:cli"], &[]),
+            pr(1, "Refactor internals", &["area:core"], &[]),
+        ];
+
+        let config = ChangelogConfig {
+            section_labels: Some(BTreeMap::from([
+                ("Bug fixes".to_string(), vec!["bug".to_string()]),
+                ("Enhancements".to_string(), vec!["enhancement".to_string()]),
+                ("Other changes".to_string(), vec![UNKNOWN_LABEL.to_string()]),
+            ])),
+            section_order: Some(vec![
+                "Enhancements".to_string(),
+                "Bug fixes".to_string(),
+                "Other changes".to_string(),
+            ]),
+            areas: Some(vec![
+                ChangelogArea {
+                    name: "CLI".to_string(),
+                    labels: vec!["area:cli".to_string()],
+                    scopes: vec!["cli".to_string()],
+                    paths: vec!["crates/cli/**".to_string()],
+                },
+                ChangelogArea {
+                    name: "Core".to_string(),
+                    labels: vec!["area:core".to_string()],
+                    scopes: vec!["core".to_string()],
+                    paths: vec!["crates/core/**".to_string()],
+                },
+            ]),
+            ..Default::default()
+        };
+
+        let result = format_changelog_content(
+            &release("1.0.0"),
+            prs,
+            &config,
+            &ChangelogRenderer::default(),
+        )
+        .unwrap();
+
+        insta::assert_snapshot!(result, @r"
+        ## 1.0.0
+
+        ### CLI
+
+        #### Enhancements
+
+        - Add a `--quiet` flag ([#2](https://github.com/owner/repo/pull/2))
+
+        #### Bug fixes
+
+        - fix(cli): Handle missing arguments ([#4](https://github.com/owner/repo/pull/4))
+
+        ### Core
+
+        #### Enhancements
+
+        - Speed up parsing ([#3](https://github.com/owner/repo/pull/3))
+
+        #### Other changes
+
+        - Refactor internals ([#1](https://github.com/owner/repo/pull/1))
+
+        ### Other
+
+        #### Other changes
+
+        - Update the README ([#5](https://github.com/owner/repo/pull/5))
+        ");
+    }
+
+    #[test]
+    fn test_conventional_commit_scopes() {
+        let scopes = |title| conventional_commit_scopes(title).collect::<Vec<_>>();
+
+        assert_eq!(scopes("fix(cli): Handle missing arguments"), ["cli"]);
+        assert_eq!(scopes("feat(cli, core)!: Rename flags"), ["cli", "core"]);
+        assert_eq!(scopes("feat: Add a flag"), Vec::<&str>::new());
+        assert_eq!(
+            scopes("Update (deprecated) docs: part 2"),
+            Vec::<&str>::new()
+        );
+        assert_eq!(scopes("Add a flag"), Vec::<&str>::new());
+    }
+
+    #[test]
+    fn test_format_changelog_with_custom_template() {
+        let prs = vec![
+            PullRequest {
+                title: "Add feature".to_string(),
+                number: 1,
+                url: "https://github.com/owner/repo/pull/1".to_string(),
+                labels: vec!["enhancement".to_string(), "cli".to_string()],
+                author: Some("alice".to_string()),
+                author_url: Some("https://github.com/alice".to_string()),
+                body: Some("Adds a `--verbose` flag.".to_string()),
+                closed_issues: Vec::new(),
+                changed_files: Vec::new(),
+                merged_at: Utc.with_ymd_and_hms(2025, 8, 12, 15, 20, 0).unwrap(),
+            },
+            PullRequest {
+                title: "Fix <bug>".to_string(),
+                number: 2,
+                url: "https://github.com/owner/repo/pull/2".to_string(),
+                labels: vec!["bug".to_string()],
+                author: Some("bob".to_string()),
+                author_url: None,
+                body: None,
+                closed_issues: Vec::new(),
+                changed_files: Vec::new(),
+                merged_at: Utc.with_ymd_and_hms(2025, 8, 13, 9, 45, 0).unwrap(),
+            },
+        ];
+
+        let mut section_labels = BTreeMap::new();
+        section_labels.insert("Enhancements".to_string(), vec!["enhancement".to_string()]);
+        section_labels.insert("Bug fixes".to_string(), vec!["bug".to_string()]);
+
+        let config = ChangelogConfig {
+            section_labels: Some(section_labels),
+            ..Default::default()
+        };
+        let renderer = ChangelogRenderer::from_source(
+            r"# v{{ version }}
+{% for section in sections %}
+{{ section.name | upper }}
+{% for pr in section.prs -%}
+* {{ pr.title }} by @{{ pr.author }} [{{ pr.labels | join(', ') }}]
+{%- if pr.body %}: {{ pr.body }}{% endif %}
+{% endfor -%}
+{% endfor %}
+Thanks to {{ contributors | map(attribute='login') | join(' and ') }}!
+"
+            .to_string(),
+        )
+        .unwrap();
+
+        let result = format_changelog_content(&release("1.0.0"), prs, &config, &renderer).unwrap();
+
+        insta::assert_snapshot!(result, @r"
+        # v1.0.0
+
+        BUG FIXES
+        * Fix <bug> by @bob [bug]
+
+        ENHANCEMENTS
+        * Add feature by @alice [enhancement, cli]: Adds a `--verbose` flag.
+
+        Thanks to alice and bob!
+        ");
+    }
+
+    #[test]
+    fn test_changelog_renderer_reads_template_file() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        fs_err::write(
+            temp_dir.path().join("changelog.md.jinja"),
+            "Release {{ heading }}\n",
+        )
+        .unwrap();
+
+        let config = ChangelogConfig {
+            template: Some(ChangelogTemplate::File {
+                path: "changelog.md.jinja".into(),
+            }),
+            ..Default::default()
+        };
+        let renderer = ChangelogRenderer::new(temp_dir.path(), &config).unwrap();
+
+        let result =
+            format_changelog_content(&release("1.0.0"), vec![], &config, &renderer).unwrap();
+
+        assert_eq!(result, "Release 1.0.0\n");
+    }
+
+    #[test]
+    fn test_changelog_renderer_invalid_template() {
+        let config = ChangelogConfig {
+            template: Some(ChangelogTemplate::Inline("{% for pr in prs %}".to_string())),
+            ..Default::default()
+        };
+
+        let err = ChangelogRenderer::new(Path::new("."), &config)
+            .err()
+            .unwrap();
+
+        assert_eq!(err.to_string(), "Invalid changelog template");
+    }
+
     #[test]
     fn test_update_changelog_file_creates_new() {
         let temp_dir = tempfile::tempdir().unwrap();
         let changelog_path = temp_dir.path().join("CHANGELOG.md");
 
         let content = "## 1.0.0\n\n- Feature A\n\n";
-        let change = prepare_changelog_file_change(&changelog_path, content).unwrap();
+        let change = prepare_changelog_file_change(&changelog_path, content, None).unwrap();
         change.apply().unwrap();
 
         let result = fs_err::read_to_string(&changelog_path).unwrap();
@@ -724,7 +1651,7 @@ mod tests {
         .unwrap();
 
         let new_content = "## 1.0.0\n\n- New feature\n\n";
-        let change = prepare_changelog_file_change(&changelog_path, new_content).unwrap();
+        let change = prepare_changelog_file_change(&changelog_path, new_content, None).unwrap();
         change.apply().unwrap();
 
         let result = fs_err::read_to_string(&changelog_path).unwrap();
@@ -742,6 +1669,104 @@ mod tests {
         "###);
     }
 
+    #[test]
+    fn test_update_changelog_file_adds_link_definitions() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let changelog_path = temp_dir.path().join("CHANGELOG.md");
+
+        let change = prepare_changelog_file_change(
+            &changelog_path,
+            "## [1.0.0] - 2025-11-01\n\n- Feature A\n\n",
+            Some("[1.0.0]: https://github.com/owner/repo/compare/v0.9.0...v1.0.0"),
+        )
+        .unwrap();
+        change.apply().unwrap();
+
+        let change = prepare_changelog_file_change(
+            &changelog_path,
+            "## [1.1.0] - 2025-12-10\n\n- Feature B\n\n",
+            Some("[1.1.0]: https://github.com/owner/repo/compare/v1.0.0...v1.1.0"),
+        )
+        .unwrap();
+        change.apply().unwrap();
+
+        let result = fs_err::read_to_string(&changelog_path).unwrap();
+        insta::assert_snapshot!(result, @r"
+        # Changelog
+
+        ## [1.1.0] - 2025-12-10
+
+        - Feature B
+
+        ## [1.0.0] - 2025-11-01
+
+        - Feature A
+
+        [1.1.0]: https://github.com/owner/repo/compare/v1.0.0...v1.1.0
+        [1.0.0]: https://github.com/owner/repo/compare/v0.9.0...v1.0.0
+        ");
+    }
+
+    #[test]
+    fn test_update_changelog_file_replaces_link_definition() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let changelog_path = temp_dir.path().join("CHANGELOG.md");
+
+        fs_err::write(
+            &changelog_path,
+            "# Changelog\n\n## [1.0.0]\n\n- Feature A\n\n[1.0.1]: https://example.com/stale\n[1.0.0]: https://example.com/v1.0.0\n",
+        )
+        .unwrap();
+
+        let change = prepare_changelog_file_change(
+            &changelog_path,
+            "## [1.0.1]\n\n- Fix A\n\n",
+            Some("[1.0.1]: https://example.com/v1.0.1"),
+        )
+        .unwrap();
+        change.apply().unwrap();
+
+        let result = fs_err::read_to_string(&changelog_path).unwrap();
+        insta::assert_snapshot!(result, @r"
+        # Changelog
+
+        ## [1.0.1]
+
+        - Fix A
+
+        ## [1.0.0]
+
+        - Feature A
+
+        [1.0.1]: https://example.com/v1.0.1
+        [1.0.0]: https://example.com/v1.0.0
+        ");
+    }
+
+    #[test]
+    fn test_changelog_release_heading_placeholders() {
+        let release = ChangelogRelease {
+            version: "1.2.0".to_string(),
+            previous_version: Some("1.1.0".to_string()),
+            date: NaiveDate::from_ymd_opt(2026, 10, 17).unwrap(),
+            tag: "v1.2.0".to_string(),
+            compare_url: Some("https://github.com/owner/repo/compare/v1.1.0...v1.2.0".to_string()),
+        };
+
+        assert_eq!(
+            release.heading("[{version}] - {date}"),
+            "[1.2.0] - 2026-10-17"
+        );
+        assert_eq!(
+            release.heading("[{tag}]({compare_url}) (since {previous_version})"),
+            "[v1.2.0](https://github.com/owner/repo/compare/v1.1.0...v1.2.0) (since 1.1.0)"
+        );
+        assert_eq!(
+            release.link_definition().as_deref(),
+            Some("[1.2.0]: https://github.com/owner/repo/compare/v1.1.0...v1.2.0")
+        );
+    }
+
     #[test]
     fn test_prepared_changelog_has_section_body_without_heading() {
         let prepared = PreparedChangelog::new(
@@ -758,12 +1783,16 @@ mod tests {
 
     #[test]
     fn test_format_changelog_with_ignored_contributors() {
-        let prs = vec![GitHubPullRequest {
+        let prs = vec![PullRequest {
             title: "Add feature".to_string(),
             number: 1,
             url: "https://github.com/owner/repo/pull/1".to_string(),
             labels: vec!["enhancement".to_string()],
             author: Some("alice".to_string()),
+            author_url: Some("https://github.com/alice".to_string()),
+            body: None,
+            closed_issues: Vec::new(),
+            changed_files: Vec::new(),
             merged_at: Utc.with_ymd_and_hms(2025, 6, 25, 14, 15, 0).unwrap(),
         }];
 
@@ -779,7 +1808,13 @@ mod tests {
             ..Default::default()
         };
 
-        let result = format_changelog_content("1.0.0", prs, &config).unwrap();
+        let result = format_changelog_content(
+            &release("1.0.0"),
+            prs,
+            &config,
+            &ChangelogRenderer::default(),
+        )
+        .unwrap();
 
         insta::assert_snapshot!(result, @"## 1.0.0");
     }
@@ -843,6 +1878,44 @@ mod tests {
         assert_eq!(section.body, "### Changes\n\n- Breaking change");
     }
 
+    #[test]
+    fn test_parse_latest_changelog_section_keep_a_changelog() {
+        let changelog = r"# Changelog
+
+## [Unreleased]
+
+- Upcoming
+
+## [1.2.0](https://github.com/owner/repo/compare/v1.1.0...v1.2.0) - 2025-12-10
+
+### Added
+
+```markdown
+## Not a heading
+```
+
+## [1.1.0] - 2025-11-01
+
+- Older
+";
+
+        let section = parse_latest_changelog_section(changelog).unwrap();
+        assert_eq!(section.version, "1.2.0");
+        assert_eq!(
+            section.body,
+            "### Added\n\n```markdown\n## Not a heading\n```"
+        );
+
+        let section = parse_changelog_section(changelog, "1.1.0").unwrap();
+        assert_eq!(section.body, "- Older");
+
+        let err = parse_changelog_section(changelog, "1.0.0").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "No section found for version 1.0.0 in changelog, available versions: 1.2.0, 1.1.0"
+        );
+    }
+
     #[test]
     fn test_create_release_body_stable() {
         let changelog = r"# Changelog
@@ -866,6 +1939,33 @@ mod tests {
         assert!(!release_body.prerelease);
     }
 
+    #[test]
+    fn test_create_release_bodies_for_versions() {
+        let changelog = r"# Changelog
+
+## [Unreleased]
+
+## 1.1.0-rc.1
+
+- Candidate
+
+## 1.0.0
+
+- First
+";
+
+        let release_body = create_release_body_for_version(changelog, "1.0.0").unwrap();
+        assert_eq!(release_body.title, "1.0.0");
+        assert_eq!(release_body.body, "- First");
+
+        let release_bodies = create_all_release_bodies(changelog).unwrap();
+        let summary: Vec<_> = release_bodies
+            .iter()
+            .map(|release_body| (release_body.title.as_str(), release_body.prerelease))
+            .collect();
+        assert_eq!(summary, vec![("1.1.0-rc.1", true), ("1.0.0", false)]);
+    }
+
     #[test]
     fn test_create_release_body_prerelease() {
         let changelog = r"# Changelog
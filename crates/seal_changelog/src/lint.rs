@@ -0,0 +1,371 @@
+//! Checks that a changelog is well formed.
+
+use std::collections::HashMap;
+use std::fmt;
+
+use anyhow::Result;
+use seal_project::ChangelogConfig;
+use semver::Version;
+
+use crate::{ChangelogDocument, ReleaseSection, unnamed_release_heading};
+
+const HEADING_PLACEHOLDERS: [&str; 5] = [
+    "{version}",
+    "{date}",
+    "{previous_version}",
+    "{tag}",
+    "{compare_url}",
+];
+
+/// A problem found in a changelog.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChangelogDiagnostic {
+    /// The 1-based line of the problem, when it is about a particular line.
+    pub line: Option<usize>,
+    source_line: String,
+    pub message: String,
+}
+
+impl fmt::Display for ChangelogDiagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Some(line) = self.line else {
+            return write!(f, "Changelog error: {}", self.message);
+        };
+
+        let number = line.to_string();
+        let gutter = " ".repeat(number.len());
+        writeln!(f, "Changelog error at line {line}")?;
+        writeln!(f, "{gutter} |")?;
+        writeln!(f, "{number} | {}", self.source_line)?;
+        writeln!(
+            f,
+            "{gutter} | {}",
+            "^".repeat(self.source_line.chars().count())
+        )?;
+        write!(f, "{}", self.message)
+    }
+}
+
+/// Check the heading hierarchy, versions, and section contents of `changelog`, and that it has a
+/// section for `current_version`, if given.
+pub fn lint_changelog(
+    changelog: &str,
+    config: &ChangelogConfig,
+    current_version: Option<&str>,
+) -> Result<Vec<ChangelogDiagnostic>> {
+    let document = ChangelogDocument::parse(changelog)?;
+    let mut diagnostics = Vec::new();
+
+    let at = |offset: usize, message: String| ChangelogDiagnostic {
+        line: Some(document.line_number(offset)),
+        source_line: document.line(offset).to_string(),
+        message,
+    };
+
+    let headings = document.headings();
+    match headings.first() {
+        Some(heading) if heading.depth == 1 => {}
+        Some(heading) => diagnostics.push(at(
+            heading.offset,
+            "The changelog must start with a `#` title".to_string(),
+        )),
+        None => diagnostics.push(ChangelogDiagnostic {
+            line: None,
+            source_line: String::new(),
+            message: "The changelog must start with a `#` title".to_string(),
+        }),
+    }
+    for pair in headings.windows(2) {
+        let [previous, heading] = pair else {
+            continue;
+        };
+        if heading.depth == 1 {
+            diagnostics.push(at(
+                heading.offset,
+                "Only the changelog title can be a `#` heading".to_string(),
+            ));
+        } else if heading.depth > previous.depth + 1 {
+            diagnostics.push(at(
+                heading.offset,
+                format!(
+                    "A `{}` heading cannot follow a `{}` heading",
+                    "#".repeat(heading.depth.into()),
+                    "#".repeat(previous.depth.into())
+                ),
+            ));
+        }
+    }
+
+    let template = config.changelog_heading();
+    let mut seen: HashMap<&Version, usize> = HashMap::new();
+    let mut previous: Option<&Version> = None;
+
+    for section in document.sections() {
+        if section.is_unreleased() {
+            continue;
+        }
+
+        let offset = section.range.start;
+        let Some(version) = &section.version else {
+            if is_unnamed_release(section) {
+                continue;
+            }
+            diagnostics.push(at(
+                offset,
+                format!(
+                    "Section heading `{}` does not contain a valid version",
+                    section.heading
+                ),
+            ));
+            continue;
+        };
+
+        if let Some(first_line) = seen.get(version) {
+            diagnostics.push(at(
+                offset,
+                format!("Duplicate section for version {version}, first at line {first_line}"),
+            ));
+            continue;
+        }
+        seen.insert(version, document.line_number(offset));
+
+        if let Some(previous) = previous
+            && version > previous
+        {
+            diagnostics.push(at(
+                offset,
+                format!(
+                    "Version {version} is listed below older version {previous}, but versions must be in descending order"
+                ),
+            ));
+        }
+        previous = Some(version);
+
+        if section.body.is_empty() {
+            diagnostics.push(at(
+                offset,
+                format!("Section for version {version} is empty"),
+            ));
+        }
+
+        if !matches_heading(section.heading, template) {
+            diagnostics.push(at(
+                offset,
+                format!("Heading does not match `changelog-heading` \"{template}\""),
+            ));
+        }
+    }
+
+    if let Some(current_version) = current_version
+        && let Ok(version) = Version::parse(current_version)
+        && document.section(&version).is_none()
+    {
+        diagnostics.push(ChangelogDiagnostic {
+            line: None,
+            source_line: String::new(),
+            message: format!("No section found for `release.current-version` {current_version}"),
+        });
+    }
+
+    diagnostics.sort_by_key(|diagnostic| diagnostic.line.unwrap_or(usize::MAX));
+
+    Ok(diagnostics)
+}
+
+/// Whether `section` is a release without a version, as written for releases whose name has no
+/// version when generating the full changelog.
+fn is_unnamed_release(section: &ReleaseSection) -> bool {
+    section
+        .date
+        .is_some_and(|date| section.heading == unnamed_release_heading(date))
+}
+
+/// Whether `heading` could have been produced by the `changelog-heading` `template`.
+fn matches_heading(heading: &str, template: &str) -> bool {
+    // The text between placeholders, which must appear in order.
+    let mut literals = Vec::new();
+    let mut rest = template;
+    while let Some((position, placeholder)) = HEADING_PLACEHOLDERS
+        .iter()
+        .filter_map(|placeholder| {
+            rest.find(placeholder)
+                .map(|position| (position, placeholder))
+        })
+        .min()
+    {
+        literals.push(&rest[..position]);
+        rest = &rest[position + placeholder.len()..];
+    }
+    literals.push(rest);
+
+    let Some((first, literals)) = literals.split_first() else {
+        return true;
+    };
+    let Some(mut remaining) = heading.strip_prefix(first) else {
+        return false;
+    };
+    let Some((last, middle)) = literals.split_last() else {
+        return remaining.is_empty();
+    };
+    // Placeholders fill in a single word, which may be empty.
+    let is_word = |text: &str| !text.contains(char::is_whitespace);
+    for literal in middle {
+        let Some(position) = remaining.find(literal) else {
+            return false;
+        };
+        if !is_word(&remaining[..position]) {
+            return false;
+        }
+        remaining = &remaining[position + literal.len()..];
+    }
+    remaining.strip_suffix(last).is_some_and(is_word)
+}
+
+#[cfg(test)]
+mod tests {
+    use seal_project::ChangelogHeading;
+
+    use super::*;
+
+    fn render(diagnostics: &[ChangelogDiagnostic]) -> String {
+        diagnostics
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join("\n\n")
+    }
+
+    #[test]
+    fn test_lint_valid_changelog() {
+        let changelog = "# Changelog
+
+## [Unreleased]
+
+## [1.1.0] - 2025-12-10
+
+### Added
+
+- Feature
+
+## [1.0.0] - 2025-11-01
+
+- Initial release
+
+## Release 2025-10-01
+
+- Preview release
+
+[1.1.0]: https://github.com/owner/repo/compare/v1.0.0...v1.1.0
+";
+        let config = ChangelogConfig {
+            changelog_heading: Some(
+                ChangelogHeading::new("[{version}] - {date}".to_string()).unwrap(),
+            ),
+            ..Default::default()
+        };
+
+        let diagnostics = lint_changelog(changelog, &config, Some("1.1.0")).unwrap();
+        assert_eq!(diagnostics, Vec::new());
+    }
+
+    #[test]
+    fn test_lint_changelog_problems() {
+        let changelog = "# Changelog
+
+## 1.0.0
+
+- Older
+
+## 1.1.0
+
+#### Added
+
+- Newer
+
+## 1.0.0
+
+- Duplicate
+
+## 1.0
+
+- Not a version
+
+## 0.9.0
+
+# Notes
+";
+
+        let diagnostics =
+            lint_changelog(changelog, &ChangelogConfig::default(), Some("1.2.0")).unwrap();
+
+        insta::assert_snapshot!(render(&diagnostics), @r"
+        Changelog error at line 7
+          |
+        7 | ## 1.1.0
+          | ^^^^^^^^
+        Version 1.1.0 is listed below older version 1.0.0, but versions must be in descending order
+
+        Changelog error at line 9
+          |
+        9 | #### Added
+          | ^^^^^^^^^^
+        A `####` heading cannot follow a `##` heading
+
+        Changelog error at line 13
+           |
+        13 | ## 1.0.0
+           | ^^^^^^^^
+        Duplicate section for version 1.0.0, first at line 3
+
+        Changelog error at line 17
+           |
+        17 | ## 1.0
+           | ^^^^^^
+        Section heading `1.0` does not contain a valid version
+
+        Changelog error at line 21
+           |
+        21 | ## 0.9.0
+           | ^^^^^^^^
+        Section for version 0.9.0 is empty
+
+        Changelog error at line 23
+           |
+        23 | # Notes
+           | ^^^^^^^
+        Only the changelog title can be a `#` heading
+
+        Changelog error: No section found for `release.current-version` 1.2.0
+        ");
+    }
+
+    #[test]
+    fn test_lint_changelog_without_title() {
+        let diagnostics =
+            lint_changelog("## 1.0.0\n\n- First\n", &ChangelogConfig::default(), None).unwrap();
+
+        insta::assert_snapshot!(render(&diagnostics), @r"
+        Changelog error at line 1
+          |
+        1 | ## 1.0.0
+          | ^^^^^^^^
+        The changelog must start with a `#` title
+        ");
+    }
+
+    #[test]
+    fn test_matches_heading() {
+        assert!(matches_heading("1.0.0", "{version}"));
+        assert!(matches_heading(
+            "[1.0.0] - 2025-12-10",
+            "[{version}] - {date}"
+        ));
+        assert!(matches_heading("Release v1.0.0", "Release {tag}"));
+        assert!(!matches_heading(
+            "1.0.0 - 2025-12-10",
+            "[{version}] - {date}"
+        ));
+        assert!(!matches_heading("[1.0.0]", "[{version}] - {date}"));
+        assert!(!matches_heading("1.0.0 (2025-12-10)", "{version}"));
+    }
+}
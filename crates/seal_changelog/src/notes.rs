@@ -0,0 +1,229 @@
+//! Release notes written in pull request descriptions.
+
+use markdown::mdast::Node;
+use markdown::{ParseOptions, to_mdast};
+use seal_forge::PullRequest;
+use seal_project::ReleaseNotesConfig;
+
+use crate::document::{end, start};
+
+const BREAKING_CHANGE_MARKERS: [&str; 2] = ["BREAKING CHANGE:", "BREAKING-CHANGE:"];
+
+/// What a pull request's description says about its release.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub(crate) struct PullRequestNotes {
+    /// The release notes to show with the pull request's entry.
+    pub(crate) text: Option<String>,
+    pub(crate) breaking: bool,
+}
+
+impl PullRequestNotes {
+    pub(crate) fn new(pr: &PullRequest, config: &ReleaseNotesConfig) -> Self {
+        let has_label = |labels: &[String]| pr.labels.iter().any(|label| labels.contains(label));
+        let labelled_breaking = has_label(config.breaking_labels());
+
+        if has_label(config.ignore_labels()) {
+            return Self {
+                text: None,
+                breaking: labelled_breaking,
+            };
+        }
+
+        let body = pr.body.as_deref().unwrap_or_default().replace("\r\n", "\n");
+        let Ok(Node::Root(root)) = to_mdast(&body, &ParseOptions::default()) else {
+            return Self {
+                text: None,
+                breaking: labelled_breaking,
+            };
+        };
+
+        let breaking_change = breaking_change(&body, &root.children);
+        let text = fenced_block(&root.children, config.fence())
+            .or_else(|| release_notes_section(&body, &root.children))
+            .or_else(|| breaking_change.clone());
+
+        Self {
+            text,
+            breaking: labelled_breaking || breaking_change.is_some(),
+        }
+    }
+}
+
+/// The contents of the first fenced code block whose info string is `fence`.
+fn fenced_block(content: &[Node], fence: &str) -> Option<String> {
+    content.iter().find_map(|node| match node {
+        Node::Code(code) if code.lang.as_deref() == Some(fence) => non_empty(&code.value),
+        _ => None,
+    })
+}
+
+/// The text under a `Release notes` heading, up to the next heading of the same or higher level.
+fn release_notes_section(source: &str, content: &[Node]) -> Option<String> {
+    let (index, depth) = content
+        .iter()
+        .enumerate()
+        .find_map(|(index, node)| match node {
+            Node::Heading(heading)
+                if node
+                    .to_string()
+                    .trim()
+                    .eq_ignore_ascii_case("release notes") =>
+            {
+                Some((index, heading.depth))
+            }
+            _ => None,
+        })?;
+
+    let section_end = content[index + 1..]
+        .iter()
+        .find(|node| matches!(node, Node::Heading(heading) if heading.depth <= depth))
+        .map_or(source.len(), start);
+
+    non_empty(&source[end(&content[index])..section_end])
+}
+
+/// The text after a `BREAKING CHANGE:` paragraph marker, up to the next heading.
+fn breaking_change(source: &str, content: &[Node]) -> Option<String> {
+    content.iter().enumerate().find_map(|(index, node)| {
+        if !matches!(node, Node::Paragraph(_)) {
+            return None;
+        }
+        let text = &source[start(node)..];
+        let marker = BREAKING_CHANGE_MARKERS
+            .iter()
+            .find(|marker| text.starts_with(**marker))?;
+
+        let section_end = content[index + 1..]
+            .iter()
+            .find(|node| matches!(node, Node::Heading(_)))
+            .map_or(source.len(), start);
+
+        non_empty(&source[start(node) + marker.len()..section_end])
+    })
+}
+
+fn non_empty(text: &str) -> Option<String> {
+    let text = text.trim();
+    (!text.is_empty()).then(|| text.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+
+    use super::*;
+
+    fn pr(body: &str, labels: &[&str]) -> PullRequest {
+        PullRequest {
+            title: "Change things".to_string(),
+            number: 1,
+            url: "https://github.com/owner/repo/pull/1".to_string(),
+            labels: labels.iter().map(ToString::to_string).collect(),
+            author: None,
+            author_url: None,
+            body: Some(body.to_string()),
+            closed_issues: Vec::new(),
+            changed_files: Vec::new(),
+            merged_at: Utc::now(),
+        }
+    }
+
+    fn config() -> ReleaseNotesConfig {
+        ReleaseNotesConfig {
+            breaking_labels: Some(vec!["breaking".to_string()]),
+            ignore_labels: Some(vec!["internal".to_string()]),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_notes_from_fenced_block() {
+        let body = "Some context for reviewers.\r\n\r\n```release-notes\r\nThe `--verbose` flag now \
+                    prints timings.\r\n```\r\n\r\n## Release notes\r\n\r\nNot this.\r\n";
+
+        let notes = PullRequestNotes::new(&pr(body, &[]), &config());
+        assert_eq!(
+            notes,
+            PullRequestNotes {
+                text: Some("The `--verbose` flag now prints timings.".to_string()),
+                breaking: false,
+            }
+        );
+    }
+
+    #[test]
+    fn test_notes_from_heading() {
+        let body = "## Summary
+
+Refactor the parser.
+
+## Release notes
+
+Parsing is twice as fast.
+
+### Details
+
+Benchmarks are in the PR.
+
+## Checklist
+
+- [x] Tests
+";
+
+        let notes = PullRequestNotes::new(&pr(body, &[]), &config());
+        insta::assert_snapshot!(notes.text.unwrap(), @r"
+        Parsing is twice as fast.
+
+        ### Details
+
+        Benchmarks are in the PR.
+        ");
+        assert!(!notes.breaking);
+    }
+
+    #[test]
+    fn test_notes_from_breaking_change() {
+        let body = "Rename the config file.
+
+BREAKING CHANGE: `seal.toml` is now `.seal.toml`.
+
+Rename the file to upgrade:
+
+```console
+mv seal.toml .seal.toml
+```
+
+## Checklist
+
+- [x] Docs
+";
+
+        let notes = PullRequestNotes::new(&pr(body, &[]), &config());
+        insta::assert_snapshot!(notes.text.unwrap(), @r"
+        `seal.toml` is now `.seal.toml`.
+
+        Rename the file to upgrade:
+
+        ```console
+        mv seal.toml .seal.toml
+        ```
+        ");
+        assert!(notes.breaking);
+    }
+
+    #[test]
+    fn test_notes_labels() {
+        let notes = PullRequestNotes::new(&pr("No notes here.", &["breaking"]), &config());
+        assert_eq!(
+            notes,
+            PullRequestNotes {
+                text: None,
+                breaking: true,
+            }
+        );
+
+        let body = "```release-notes\nBump dependencies.\n```\n";
+        let notes = PullRequestNotes::new(&pr(body, &["internal"]), &config());
+        assert_eq!(notes, PullRequestNotes::default());
+    }
+}
@@ -0,0 +1,234 @@
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use minijinja::{AutoEscape, Environment};
+use seal_forge::{IssueReference, PullRequest};
+use seal_project::{ChangelogConfig, ChangelogTemplate};
+use serde::Serialize;
+
+use crate::notes::PullRequestNotes;
+use crate::{CategorizedPRs, CategorizedSection, ChangelogRelease};
+
+/// The built-in changelog layout, used when `[changelog] template` is unset.
+pub const DEFAULT_TEMPLATE: &str = include_str!("../templates/changelog.md.jinja");
+
+const TEMPLATE_NAME: &str = "changelog";
+
+/// Renders changelog sections with the configured template.
+pub struct ChangelogRenderer {
+    env: Environment<'static>,
+}
+
+impl ChangelogRenderer {
+    /// Compile the template configured in `config`, resolving template files against `root`.
+    pub fn new(root: &Path, config: &ChangelogConfig) -> Result<Self> {
+        let source = match &config.template {
+            None => DEFAULT_TEMPLATE.to_string(),
+            Some(ChangelogTemplate::Inline(source)) => source.clone(),
+            Some(ChangelogTemplate::File { path }) => {
+                let path = root.join(path);
+                fs_err::read_to_string(&path).with_context(|| {
+                    format!("Failed to read changelog template `{}`", path.display())
+                })?
+            }
+        };
+
+        Self::from_source(source)
+    }
+
+    pub fn from_source(source: String) -> Result<Self> {
+        let mut env = Environment::new();
+        // Changelogs are markdown, so nothing should be HTML-escaped.
+        env.set_auto_escape_callback(|_| AutoEscape::None);
+        env.set_keep_trailing_newline(true);
+        env.add_template_owned(TEMPLATE_NAME, source)
+            .context("Invalid changelog template")?;
+
+        Ok(Self { env })
+    }
+
+    pub(crate) fn render(&self, context: &ChangelogContext<'_>) -> Result<String> {
+        self.env
+            .get_template(TEMPLATE_NAME)?
+            .render(context)
+            .context("Failed to render changelog template")
+    }
+}
+
+impl Default for ChangelogRenderer {
+    fn default() -> Self {
+        Self::from_source(DEFAULT_TEMPLATE.to_string()).expect("default template is valid")
+    }
+}
+
+/// The values available to a changelog template for one release.
+#[derive(Debug, Serialize)]
+pub(crate) struct ChangelogContext<'a> {
+    /// The released version, when it is known.
+    version: Option<&'a str>,
+    previous_version: Option<&'a str>,
+    tag: Option<&'a str>,
+    heading: &'a str,
+    /// The release date, as `YYYY-MM-DD`.
+    date: String,
+    compare_url: Option<&'a str>,
+    sections: Vec<SectionContext<'a>>,
+    /// The same sections grouped by area first, when `areas` are configured.
+    areas: Vec<AreaContext<'a>>,
+    /// The breaking changes, with their notes, when `breaking-preamble` is set.
+    breaking_changes: Vec<PullRequestContext<'a>>,
+    contributors: Vec<ContributorContext<'a>>,
+}
+
+#[derive(Debug, Serialize)]
+struct AreaContext<'a> {
+    name: &'a str,
+    sections: Vec<SectionContext<'a>>,
+}
+
+#[derive(Debug, Serialize)]
+struct SectionContext<'a> {
+    name: &'a str,
+    prs: Vec<PullRequestContext<'a>>,
+}
+
+#[derive(Debug, Serialize)]
+struct PullRequestContext<'a> {
+    title: &'a str,
+    number: u64,
+    url: &'a str,
+    author: Option<&'a str>,
+    author_url: Option<&'a str>,
+    labels: &'a [String],
+    body: Option<&'a str>,
+    /// The issues the pull request closed, when `link-closed-issues` is set.
+    closed_issues: &'a [IssueReference],
+    /// The release notes from the description, unless they are shown with the breaking changes.
+    notes: Option<String>,
+    breaking: bool,
+}
+
+#[derive(Debug, Serialize)]
+struct ContributorContext<'a> {
+    login: &'a str,
+    url: Option<&'a str>,
+}
+
+impl<'a> ChangelogContext<'a> {
+    pub(crate) fn new(
+        heading: &'a str,
+        date: chrono::NaiveDate,
+        release: Option<&'a ChangelogRelease>,
+        categorized: &'a CategorizedPRs,
+        config: &ChangelogConfig,
+    ) -> Self {
+        let mut breaking_changes = Vec::new();
+        let sections = section_contexts(&categorized.sections, config, Some(&mut breaking_changes));
+        let areas = categorized
+            .areas
+            .iter()
+            .map(|area| AreaContext {
+                name: &area.name,
+                sections: section_contexts(&area.sections, config, None),
+            })
+            .collect();
+
+        let contributors = if config.include_contributors() {
+            categorized
+                .contributors
+                .iter()
+                .map(|(login, url)| ContributorContext {
+                    login,
+                    url: url.as_deref(),
+                })
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        Self {
+            version: release.map(|release| release.version.as_str()),
+            previous_version: release.and_then(|release| release.previous_version.as_deref()),
+            tag: release.map(|release| release.tag.as_str()),
+            heading,
+            date: date.format("%Y-%m-%d").to_string(),
+            compare_url: release.and_then(|release| release.compare_url.as_deref()),
+            sections,
+            areas,
+            breaking_changes,
+            contributors,
+        }
+    }
+}
+
+/// The template values for `sections`. With `breaking-preamble`, the notes of breaking changes
+/// are moved to `breaking_changes`, if given.
+fn section_contexts<'a>(
+    sections: &'a [CategorizedSection],
+    config: &ChangelogConfig,
+    mut breaking_changes: Option<&mut Vec<PullRequestContext<'a>>>,
+) -> Vec<SectionContext<'a>> {
+    let release_notes = config.release_notes.as_ref();
+    let breaking_preamble = release_notes.is_some_and(|config| config.breaking_preamble);
+
+    let mut contexts = Vec::new();
+    for section in sections {
+        let mut prs = Vec::new();
+        for pr in &section.prs {
+            let closed_issues = if config.link_closed_issues {
+                pr.closed_issues.as_slice()
+            } else {
+                &[]
+            };
+            let notes = release_notes
+                .map(|config| PullRequestNotes::new(pr, config))
+                .unwrap_or_default();
+
+            if breaking_preamble && notes.breaking {
+                if let Some(breaking_changes) = breaking_changes.as_deref_mut() {
+                    breaking_changes.push(PullRequestContext::new(
+                        pr,
+                        closed_issues,
+                        notes.text,
+                        true,
+                    ));
+                }
+                prs.push(PullRequestContext::new(pr, closed_issues, None, true));
+            } else {
+                prs.push(PullRequestContext::new(
+                    pr,
+                    closed_issues,
+                    notes.text,
+                    notes.breaking,
+                ));
+            }
+        }
+        contexts.push(SectionContext {
+            name: &section.name,
+            prs,
+        });
+    }
+    contexts
+}
+
+impl<'a> PullRequestContext<'a> {
+    fn new(
+        pr: &'a PullRequest,
+        closed_issues: &'a [IssueReference],
+        notes: Option<String>,
+        breaking: bool,
+    ) -> Self {
+        Self {
+            title: &pr.title,
+            number: pr.number,
+            url: &pr.url,
+            author: pr.author.as_deref(),
+            author_url: pr.author_url.as_deref(),
+            labels: &pr.labels,
+            body: pr.body.as_deref(),
+            closed_issues,
+            notes,
+            breaking,
+        }
+    }
+}
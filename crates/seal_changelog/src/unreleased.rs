@@ -0,0 +1,386 @@
+//! Releasing a hand-written Keep a Changelog `## [Unreleased]` section.
+
+use anyhow::{Result, bail};
+
+use crate::{ChangelogDocument, EntryGroup, ReleaseSection};
+
+/// A changelog whose `Unreleased` section has been released as a new version.
+pub(crate) struct ReleasedUnreleased {
+    /// The changelog, with an empty `Unreleased` section above the new version's section.
+    pub(crate) content: String,
+    /// The new version's section, from its heading to the end of its notes.
+    pub(crate) section: String,
+    /// The link label for the `Unreleased` heading, e.g. `[Unreleased]`.
+    pub(crate) label: String,
+}
+
+/// Move the notes under the `Unreleased` heading of `changelog` to a new section titled
+/// `heading`, merging in the entries of the `generated` section, if any.
+pub(crate) fn release_unreleased(
+    changelog: &str,
+    heading: &str,
+    generated: Option<&str>,
+    require_notes: bool,
+) -> Result<ReleasedUnreleased> {
+    let document = ChangelogDocument::parse(changelog)?;
+    let Some(unreleased) = document.unreleased() else {
+        bail!("No `## [Unreleased]` section found in the changelog");
+    };
+    let title = unreleased.heading;
+
+    if require_notes && !has_notes(changelog, &document, unreleased) {
+        bail!("The `## {title}` section has no release notes");
+    }
+    let generated = generated.map(ChangelogDocument::parse).transpose()?;
+    let generated_groups = generated
+        .as_ref()
+        .and_then(|generated| generated.sections().first())
+        .map(|section| section.groups.as_slice())
+        .unwrap_or_default();
+
+    let body = merge_entries(changelog, unreleased, generated_groups);
+    let section = if body.is_empty() {
+        format!("## {heading}\n")
+    } else {
+        format!("## {heading}\n\n{body}\n")
+    };
+
+    let mut content = format!(
+        "{}## {title}\n\n{section}",
+        &changelog[..unreleased.range.start]
+    );
+    let rest = changelog[unreleased.range.end..].trim_start();
+    if !rest.is_empty() {
+        content.push('\n');
+        content.push_str(rest);
+    }
+
+    let label = if title.starts_with('[') {
+        title.to_string()
+    } else {
+        format!("[{title}]")
+    };
+
+    Ok(ReleasedUnreleased {
+        content,
+        section,
+        label,
+    })
+}
+
+/// Whether `section` has notes besides its subheadings.
+fn has_notes(changelog: &str, document: &ChangelogDocument, section: &ReleaseSection) -> bool {
+    let subheadings = document
+        .headings()
+        .iter()
+        .map(|heading| heading.offset)
+        .filter(|offset| section.body_range.contains(offset));
+
+    let mut start = section.body_range.start;
+    for end in subheadings.chain([section.body_range.end]) {
+        if !changelog[start..end].trim().is_empty() {
+            return true;
+        }
+        start = (end + document.line(end).len()).min(section.body_range.end);
+    }
+    false
+}
+
+/// The body of `section` with the entries of `generated` that it is missing.
+///
+/// Whole list items are compared, so notes below an entry's title stay with it. Missing entries
+/// continue the group with the same title, and groups the section does not have are added at its
+/// end.
+fn merge_entries(changelog: &str, section: &ReleaseSection, generated: &[EntryGroup]) -> String {
+    let mut insertions: Vec<(usize, String)> = Vec::new();
+    let mut new_groups = Vec::new();
+
+    for group in generated {
+        let existing =
+            section
+                .groups
+                .iter()
+                .find(|existing| match (&existing.title, &group.title) {
+                    (Some(existing), Some(title)) => existing.eq_ignore_ascii_case(title),
+                    (existing, title) => existing.is_none() && title.is_none(),
+                });
+        let items: Vec<String> = group
+            .entries
+            .iter()
+            .filter(|entry| existing.is_none_or(|existing| !existing.entries.contains(entry)))
+            .map(|entry| format!("- {entry}"))
+            .collect();
+        if items.is_empty() {
+            continue;
+        }
+        // Items with notes are separated by blank lines, like the generated list.
+        let loose = group
+            .entries
+            .iter()
+            .chain(
+                existing
+                    .map(|existing| &existing.entries)
+                    .into_iter()
+                    .flatten(),
+            )
+            .any(|entry| entry.contains("\n\n"));
+        let item_separator = if loose { "\n\n" } else { "\n" };
+        let items = items.join(item_separator);
+
+        if let Some(existing) = existing {
+            let content = changelog[existing.range.clone()].trim_end();
+            let ends_with_entry = existing
+                .entries
+                .last()
+                .is_some_and(|entry| content.ends_with(entry.as_str()));
+            let separator = if ends_with_entry {
+                item_separator
+            } else {
+                "\n\n"
+            };
+            insertions.push((
+                existing.range.start + content.len(),
+                format!("{separator}{items}"),
+            ));
+        } else if let Some(title) = &group.title {
+            new_groups.push(format!("### {title}\n\n{items}"));
+        } else if let Some(first) = section.groups.first() {
+            // Entries without a group go before the first subheading.
+            insertions.push((first.range.start, format!("{items}\n\n")));
+        } else {
+            new_groups.insert(0, items);
+        }
+    }
+
+    let mut body = changelog[section.body_range.clone()].to_string();
+    insertions.sort_by_key(|(offset, _)| *offset);
+    for (offset, text) in insertions.into_iter().rev() {
+        body.insert_str(
+            offset.min(section.body_range.end) - section.body_range.start,
+            &text,
+        );
+    }
+    for group in new_groups {
+        if !body.is_empty() {
+            body.push_str("\n\n");
+        }
+        body.push_str(&group);
+    }
+    body
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CHANGELOG: &str = "# Changelog
+
+## [Unreleased]
+
+### Added
+
+- Curated feature note
+
+### Fixed
+
+- Curated fix note
+
+## [1.0.0] - 2025-11-01
+
+- Initial release
+
+[Unreleased]: https://github.com/owner/repo/compare/v1.0.0...HEAD
+[1.0.0]: https://github.com/owner/repo/releases/tag/v1.0.0
+";
+
+    #[test]
+    fn test_release_unreleased() {
+        let released = release_unreleased(CHANGELOG, "[1.1.0] - 2025-12-10", None, false).unwrap();
+
+        insta::assert_snapshot!(released.content, @r"
+        # Changelog
+
+        ## [Unreleased]
+
+        ## [1.1.0] - 2025-12-10
+
+        ### Added
+
+        - Curated feature note
+
+        ### Fixed
+
+        - Curated fix note
+
+        ## [1.0.0] - 2025-11-01
+
+        - Initial release
+
+        [Unreleased]: https://github.com/owner/repo/compare/v1.0.0...HEAD
+        [1.0.0]: https://github.com/owner/repo/releases/tag/v1.0.0
+        ");
+        assert_eq!(
+            released.section,
+            "## [1.1.0] - 2025-12-10\n\n### Added\n\n- Curated feature note\n\n### Fixed\n\n- Curated fix note\n"
+        );
+        assert_eq!(released.label, "[Unreleased]");
+    }
+
+    #[test]
+    fn test_release_unreleased_merges_generated_entries() {
+        let generated = "## 1.1.0
+
+### Added
+
+- Add feature ([#2](https://github.com/owner/repo/pull/2))
+
+### Fixed
+
+- Curated fix note
+
+### Contributors
+
+- [@alice](https://github.com/alice)
+
+";
+
+        let released = release_unreleased(CHANGELOG, "1.1.0", Some(generated), false).unwrap();
+
+        insta::assert_snapshot!(released.section, @r"
+        ## 1.1.0
+
+        ### Added
+
+        - Curated feature note
+        - Add feature ([#2](https://github.com/owner/repo/pull/2))
+
+        ### Fixed
+
+        - Curated fix note
+
+        ### Contributors
+
+        - [@alice](https://github.com/alice)
+        ");
+    }
+
+    #[test]
+    fn test_release_unreleased_merges_whole_entries() {
+        let changelog = "# Changelog
+
+## [Unreleased]
+
+Upgrade with:
+
+```sh
+### Not a heading
+seal bump
+```
+
+### Fixed
+
+- Curated fix
+
+  See the docs.
+
+## [1.0.0]
+
+- Initial release
+";
+        let generated = "## 1.1.0
+
+- Ungrouped change
+
+### Fixed
+
+- Fix parser ([#3](https://github.com/owner/repo/pull/3))
+
+  See the docs.
+
+- Curated fix
+
+  See the docs.
+
+### Added
+
+- Add feature ([#2](https://github.com/owner/repo/pull/2))
+";
+
+        let released = release_unreleased(changelog, "1.1.0", Some(generated), true).unwrap();
+
+        insta::assert_snapshot!(released.section, @r"
+        ## 1.1.0
+
+        Upgrade with:
+
+        ```sh
+        ### Not a heading
+        seal bump
+        ```
+
+        - Ungrouped change
+
+        ### Fixed
+
+        - Curated fix
+
+          See the docs.
+
+        - Fix parser ([#3](https://github.com/owner/repo/pull/3))
+
+          See the docs.
+
+        ### Added
+
+        - Add feature ([#2](https://github.com/owner/repo/pull/2))
+        ");
+    }
+
+    #[test]
+    fn test_release_unreleased_last_section() {
+        let changelog = "# Changelog\n\n## Unreleased\n\n- First release\n\n[Unreleased]: https://example.com\n";
+
+        let released = release_unreleased(changelog, "1.0.0", None, true).unwrap();
+
+        insta::assert_snapshot!(released.content, @r"
+        # Changelog
+
+        ## Unreleased
+
+        ## 1.0.0
+
+        - First release
+
+        [Unreleased]: https://example.com
+        ");
+        assert_eq!(released.label, "[Unreleased]");
+    }
+
+    #[test]
+    fn test_release_unreleased_requires_notes() {
+        let changelog =
+            "# Changelog\n\n## [Unreleased]\n\n### Added\n\n## [1.0.0]\n\n- Initial release\n";
+
+        let err = release_unreleased(changelog, "1.1.0", None, true)
+            .err()
+            .unwrap();
+        assert_eq!(
+            err.to_string(),
+            "The `## [Unreleased]` section has no release notes"
+        );
+
+        let released = release_unreleased(changelog, "1.1.0", None, false).unwrap();
+        assert_eq!(released.section, "## 1.1.0\n\n### Added\n");
+    }
+
+    #[test]
+    fn test_release_unreleased_missing_section() {
+        let err = release_unreleased("# Changelog\n\n## 1.0.0\n", "1.1.0", None, false)
+            .err()
+            .unwrap();
+        assert_eq!(
+            err.to_string(),
+            "No `## [Unreleased]` section found in the changelog"
+        );
+    }
+}
@@ -12,6 +12,14 @@ pub enum VersionFormat {
     Json,
 }
 
+#[derive(Debug, Clone, Copy, clap::ValueEnum)]
+pub enum ReleaseFormat {
+    /// Display the release title, body, and pre-release status as JSON.
+    Json,
+    /// Display the release notes as markdown.
+    Markdown,
+}
+
 #[derive(Debug, Copy, Clone, clap::ValueEnum)]
 pub enum ColorChoice {
     /// Enables colored output only when the output is going to a terminal or TTY with support.
@@ -81,6 +89,9 @@ pub struct TopLevelArgs {
     /// Display the seal version.
     #[arg(short = 'V', long, action = clap::ArgAction::Version)]
     version: Option<bool>,
+
+    #[command(flatten)]
+    pub cache_args: Box<CacheArgs>,
 }
 
 #[derive(Parser, Debug, Clone)]
@@ -118,6 +129,57 @@ pub struct GlobalArgs {
         value_name = "COLOR_CHOICE"
     )]
     pub color: Option<ColorChoice>,
+
+    /// Answer forge requests from a fixtures file instead of the network.
+    ///
+    /// The file lists releases and merged pull requests, as TOML when its name ends in `.toml`
+    /// and as JSON otherwise.
+    #[arg(
+        global = true,
+        long,
+        hide = true,
+        env = "SEAL_GITHUB_FIXTURES",
+        value_name = "PATH"
+    )]
+    pub forge_fixtures: Option<PathBuf>,
+
+    /// Record the forge's responses into the `--forge-fixtures` file instead of replaying it.
+    #[arg(
+        global = true,
+        long,
+        hide = true,
+        env = "SEAL_GITHUB_FIXTURES_RECORD",
+        value_parser = clap::builder::BoolishValueParser::new(),
+        requires = "forge_fixtures"
+    )]
+    pub record_fixtures: bool,
+}
+
+#[derive(Parser, Debug, Clone)]
+#[command(next_help_heading = "Cache options", next_display_order = 2000)]
+pub struct CacheArgs {
+    /// Avoid reading from or writing to the cache.
+    ///
+    /// Every forge API request is sent in full, without a conditional request.
+    #[arg(global = true, long, conflicts_with = "refresh")]
+    pub no_cache: bool,
+
+    /// Ignore cached forge API responses, replacing them with fresh ones.
+    #[arg(global = true, long, conflicts_with = "no_cache")]
+    pub refresh: bool,
+
+    /// Path to the cache directory.
+    ///
+    /// Defaults to `$XDG_CACHE_HOME/seal` or `$HOME/.cache/seal` on Linux,
+    /// `$HOME/Library/Caches/seal` on macOS, and `%LOCALAPPDATA%\seal\cache` on Windows.
+    #[arg(
+        global = true,
+        long,
+        env = "SEAL_CACHE_DIR",
+        hide_env_values = true,
+        value_name = "CACHE_DIR"
+    )]
+    pub cache_dir: Option<PathBuf>,
 }
 
 #[derive(Subcommand)]
@@ -131,6 +193,12 @@ pub enum Commands {
     Bump(BumpArgs),
     /// Generate project files.
     Generate(GenerateNamespace),
+    /// Manage changelog entries.
+    Changelog(ChangelogNamespace),
+    /// Manage forge authentication.
+    Auth(AuthNamespace),
+    /// Manage seal's cache.
+    Cache(CacheNamespace),
     /// Display documentation for a command.
     #[command(help_template = "\
 {about-with-newline}
@@ -159,6 +227,12 @@ pub struct BumpArgs {
     /// Skip generating or updating the changelog
     #[arg(long)]
     pub no_changelog: bool,
+
+    /// Git remote to push to and to resolve the GitHub repository from.
+    ///
+    /// Overrides `release.remote`.
+    #[arg(long, value_name = "NAME")]
+    pub remote: Option<String>,
 }
 
 #[derive(Args, Debug)]
@@ -212,6 +286,76 @@ pub enum ValidateCommand {
         #[arg(long, short)]
         project: Option<PathBuf>,
     },
+    /// Lint the changelog's headings, versions, and sections
+    ///
+    /// Checks that headings do not skip levels, that version sections are valid, unique, non-empty,
+    /// and in descending order, that they match `changelog-heading`, and that `current-version`
+    /// has a section. If no changelog path is provided, uses `changelog-path` or `CHANGELOG.md`.
+    Changelog {
+        /// Path to the changelog file
+        #[arg(long)]
+        changelog_file: Option<PathBuf>,
+    },
+}
+
+#[derive(Args)]
+pub struct AuthNamespace {
+    #[command(subcommand)]
+    pub command: AuthCommand,
+}
+
+#[derive(Subcommand)]
+pub enum AuthCommand {
+    /// Show the GitHub token seal would use, where it was found, and its scopes
+    ///
+    /// Tokens are read from `GITHUB_TOKEN` or `GH_TOKEN` (`GH_ENTERPRISE_TOKEN` or
+    /// `GITHUB_ENTERPRISE_TOKEN` for GitHub Enterprise Server), then from `github.token-command`,
+    /// then from the GitHub CLI's login, and finally from Git credential helpers.
+    Status {
+        /// Git remote to resolve the GitHub host from.
+        ///
+        /// Overrides `release.remote`.
+        #[arg(long, value_name = "NAME")]
+        remote: Option<String>,
+    },
+}
+
+#[derive(Args)]
+pub struct CacheNamespace {
+    #[command(subcommand)]
+    pub command: CacheCommand,
+}
+
+#[derive(Subcommand)]
+pub enum CacheCommand {
+    /// Clear the cache, removing all entries
+    Clean,
+}
+
+#[derive(Args)]
+pub struct ChangelogNamespace {
+    #[command(subcommand)]
+    pub command: ChangelogCommand,
+}
+
+#[derive(Subcommand)]
+pub enum ChangelogCommand {
+    /// Add a changelog fragment for a pull request
+    ///
+    /// Writes `<number>.<type>.md` to `changelog.fragments-dir`. Values that are not passed are
+    /// prompted for.
+    Add {
+        /// The pull request number
+        number: Option<u64>,
+
+        /// The fragment type, one of the labels in `changelog.section-labels`
+        #[arg(long = "type", value_name = "TYPE")]
+        kind: Option<String>,
+
+        /// The changelog entry
+        #[arg(long, short)]
+        message: Option<String>,
+    },
 }
 
 #[derive(Args)]
@@ -233,21 +377,30 @@ pub enum GenerateCommand {
         #[arg(long)]
         dry_run: bool,
 
-        /// Maximum number of PRs to fetch.
-        ///
-        /// Be aware that this can be slow or can fail due to high number of requests if the number is too high.
-        ///
-        /// Note that this does not mean that you will see this number of PRs in the changelog, this just means
-        /// before filtering, we will fetch this number of PRs.
-        ///
-        /// Defaults to 100.
-        #[arg(long)]
-        max_prs: Option<usize>,
-
         /// Overwrite the changelog file if it already exists
         #[arg(long, default_missing_value = "true", num_args = 0..1)]
         overwrite: Option<bool>,
+
+        /// Git remote to resolve the GitHub repository from.
+        ///
+        /// Overrides `release.remote`.
+        #[arg(long, value_name = "NAME")]
+        remote: Option<String>,
     },
     /// Generate release body JSON from latest changelog section
-    Release,
+    ///
+    /// The latest section is the first one whose heading contains a version. Pass `--version` or
+    /// `--all` to backfill releases for older versions.
+    Release {
+        /// Use the section for this version instead of the latest one
+        #[arg(long, value_name = "VERSION", conflicts_with = "all")]
+        version: Option<String>,
+
+        /// Use every version section: a JSON array, or one `##` section per release in markdown
+        #[arg(long)]
+        all: bool,
+
+        #[arg(long, value_enum, default_value = "json")]
+        output_format: ReleaseFormat,
+    },
 }
@@ -1,5 +1,9 @@
 use anyhow::{Context, Result, bail};
-use std::{path::Path, process::Command};
+use std::io::{BufRead, BufReader, Read};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::sync::mpsc;
+use std::time::{Duration, Instant};
 
 /// Result of executing a command.
 #[derive(Debug)]
@@ -7,6 +11,22 @@ pub struct CommandResult {
     pub success: bool,
     pub exit_code: Option<i32>,
     pub stderr: String,
+    /// The command was killed because it exceeded its timeout.
+    pub timed_out: bool,
+}
+
+impl CommandResult {
+    /// Describe why the command failed, e.g. ` (exit code 1)`.
+    pub fn failure_info(&self, timeout: Option<Duration>) -> String {
+        if self.timed_out {
+            let seconds = timeout.map(|timeout| timeout.as_secs()).unwrap_or_default();
+            format!(" (timed out after {seconds}s)")
+        } else {
+            self.exit_code
+                .map(|code| format!(" (exit code {code})"))
+                .unwrap_or_default()
+        }
+    }
 }
 
 pub struct CommandWrapper {
@@ -14,6 +34,16 @@ pub struct CommandWrapper {
     ///
     /// Like `["git", "add", "-A"]`
     command_with_args: Vec<String>,
+    /// The command as written by the user, if it was parsed from a command line.
+    display: Option<String>,
+    /// Directory to run the command in, relative to the execution directory.
+    current_directory: Option<PathBuf>,
+    /// Extra environment variables for the command.
+    env: Vec<(String, String)>,
+    /// Maximum time the command may run before it is killed.
+    timeout: Option<Duration>,
+    /// Whether to forward the command's stdout and stderr while it runs.
+    stream_output: bool,
 }
 
 impl CommandWrapper {
@@ -27,32 +57,72 @@ impl CommandWrapper {
                 .into_iter()
                 .map(|arg| arg.to_string())
                 .collect(),
+            display: None,
+            current_directory: None,
+            env: Vec::new(),
+            timeout: None,
+            stream_output: false,
         }
     }
 
+    /// The command as a shell-quoted command line, e.g. `sh -c 'echo "hello"'`.
     pub fn as_string(&self) -> String {
-        self.command_with_args.join(" ")
+        self.display.clone().unwrap_or_else(|| {
+            shlex::try_join(self.command_with_args.iter().map(String::as_str))
+                .unwrap_or_else(|_| self.command_with_args.join(" "))
+        })
+    }
+
+    /// Run the command in `directory`, resolved against the execution directory.
+    #[must_use]
+    pub fn with_current_directory(mut self, directory: impl Into<PathBuf>) -> Self {
+        self.current_directory = Some(directory.into());
+        self
+    }
+
+    /// Set an environment variable for the command.
+    #[must_use]
+    pub fn with_env(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.env.push((key.into(), value.into()));
+        self
+    }
+
+    /// Kill the command if it runs for longer than `timeout`.
+    #[must_use]
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Forward the command's stdout and stderr line by line while it runs.
+    #[must_use]
+    pub fn with_streamed_output(mut self) -> Self {
+        self.stream_output = true;
+        self
+    }
+
+    pub fn timeout(&self) -> Option<Duration> {
+        self.timeout
     }
 
     /// Execute the command and return an error if it fails.
     pub fn execute(
         &self,
         stdout: &mut dyn std::fmt::Write,
+        stderr: &mut dyn std::fmt::Write,
         current_directory: &Path,
     ) -> Result<()> {
-        let result = self.execute_with_result(stdout, current_directory)?;
+        let result = self.execute_with_result(stdout, stderr, current_directory)?;
         if !result.success {
-            let exit_info = result
-                .exit_code
-                .map(|code| format!(" (exit code {code})"))
-                .unwrap_or_default();
-            let stderr_info = if result.stderr.is_empty() {
+            let failure_info = result.failure_info(self.timeout);
+            // Streamed commands have already shown their stderr.
+            let stderr_info = if result.stderr.is_empty() || self.stream_output {
                 String::new()
             } else {
                 format!("\n{}", result.stderr.trim())
             };
             bail!(
-                "Command `{}` failed{exit_info}{stderr_info}",
+                "Command `{}` failed{failure_info}{stderr_info}",
                 self.as_string()
             );
         }
@@ -63,47 +133,264 @@ impl CommandWrapper {
     pub fn execute_with_result(
         &self,
         stdout: &mut dyn std::fmt::Write,
+        stderr: &mut dyn std::fmt::Write,
         current_directory: &Path,
     ) -> Result<CommandResult> {
         let command_str = self.as_string();
         writeln!(stdout, "Executing command: `{command_str}`")?;
 
-        let output = Command::new(&self.command_with_args[0])
-            .args(&self.command_with_args[1..])
-            .current_dir(current_directory)
-            .output()
+        let Some((program, args)) = self.command_with_args.split_first() else {
+            bail!("Cannot execute an empty command");
+        };
+
+        let working_directory = match &self.current_directory {
+            Some(directory) => current_directory.join(directory),
+            None => current_directory.to_path_buf(),
+        };
+
+        let mut command = Command::new(program);
+        command
+            .args(args)
+            .envs(self.env.iter().map(|(key, value)| (key, value)))
+            .current_dir(&working_directory)
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped());
+        // A command that can time out runs in its own process group, so that killing it also
+        // kills what it started, such as the program run by `sh -c`.
+        #[cfg(unix)]
+        if self.timeout.is_some() {
+            std::os::unix::process::CommandExt::process_group(&mut command, 0);
+        }
+        let mut child = command
+            .spawn()
             .with_context(|| format!("Failed to execute `{command_str}`"))?;
 
+        let (sender, receiver) = mpsc::channel();
+        let readers = [
+            child
+                .stdout
+                .take()
+                .map(|pipe| spawn_reader(pipe, OutputStream::Stdout, sender.clone())),
+            child
+                .stderr
+                .take()
+                .map(|pipe| spawn_reader(pipe, OutputStream::Stderr, sender.clone())),
+        ];
+        drop(sender);
+
+        let deadline = self.timeout.map(|timeout| Instant::now() + timeout);
+        let mut captured_stderr = String::new();
+        let mut timed_out = false;
+
+        loop {
+            let line = match deadline {
+                Some(deadline) => {
+                    match receiver.recv_timeout(deadline.saturating_duration_since(Instant::now()))
+                    {
+                        Ok(line) => line,
+                        Err(mpsc::RecvTimeoutError::Timeout) => {
+                            timed_out = true;
+                            break;
+                        }
+                        Err(mpsc::RecvTimeoutError::Disconnected) => break,
+                    }
+                }
+                None => match receiver.recv() {
+                    Ok(line) => line,
+                    Err(mpsc::RecvError) => break,
+                },
+            };
+
+            match line {
+                (OutputStream::Stdout, line) => {
+                    if self.stream_output {
+                        write!(stdout, "{line}")?;
+                    }
+                }
+                (OutputStream::Stderr, line) => {
+                    if self.stream_output {
+                        write!(stderr, "{line}")?;
+                    }
+                    captured_stderr.push_str(&line);
+                }
+            }
+        }
+
+        // The output pipes can close before the process exits, so keep honouring the deadline.
+        let status = loop {
+            if timed_out {
+                kill(&mut child);
+                break child.wait()?;
+            }
+            if let Some(status) = child.try_wait()? {
+                break status;
+            }
+            match deadline {
+                Some(deadline) if Instant::now() >= deadline => timed_out = true,
+                Some(_) => std::thread::sleep(Duration::from_millis(10)),
+                None => break child.wait()?,
+            }
+        };
+
+        if !timed_out {
+            for reader in readers.into_iter().flatten() {
+                reader.join().ok();
+            }
+        }
+
         Ok(CommandResult {
-            success: output.status.success(),
-            exit_code: output.status.code(),
-            stderr: String::from_utf8_lossy(&output.stderr).to_string(),
+            success: status.success() && !timed_out,
+            exit_code: status.code(),
+            stderr: captured_stderr,
+            timed_out,
         })
     }
 
-    pub fn git_add_all() -> Self {
-        Self::new(vec!["git", "add", "-A"])
+    /// Create a custom command from a command line.
+    ///
+    /// The command line is split into arguments using POSIX shell quoting rules, so
+    /// `npm run build -- --mode 'production build'` passes `production build` as a single
+    /// argument. Shell syntax such as pipes, `&&`, and variable assignments is not interpreted;
+    /// use [`CommandWrapper::shell`] for that.
+    pub fn custom(command: &str) -> Result<Self> {
+        let parts = split_command(command)?;
+        let mut wrapper = Self::new(parts);
+        wrapper.display = Some(command.to_string());
+        Ok(wrapper)
     }
 
-    pub fn git_commit(message: &str) -> Self {
-        Self::new(vec!["git", "commit", "-m", message])
+    /// Create a command that runs the command line through `sh -c`.
+    pub fn shell(command: &str) -> Self {
+        let mut wrapper = Self::new(vec!["sh", "-c", command]);
+        wrapper.display = Some(command.to_string());
+        wrapper
     }
+}
 
-    pub fn create_branch(name: &str) -> Self {
-        Self::new(vec!["git", "checkout", "-b", name])
+/// Split a command line into arguments using POSIX shell quoting rules.
+pub fn split_command(command: &str) -> Result<Vec<String>> {
+    let Some(parts) = shlex::split(command) else {
+        bail!("Command `{command}` has unbalanced quotes or a trailing escape");
+    };
+    if parts.is_empty() {
+        bail!("Command cannot be empty");
     }
+    Ok(parts)
+}
+
+/// Kill `child` and, on Unix, the rest of its process group.
+fn kill(child: &mut std::process::Child) {
+    #[cfg(unix)]
+    if let Ok(pid) = i32::try_from(child.id()) {
+        use nix::sys::signal::{Signal, killpg};
+        use nix::unistd::Pid;
 
-    pub fn git_push_branch(branch_name: &str) -> Self {
-        Self::new(vec!["git", "push", "origin", branch_name])
+        killpg(Pid::from_raw(pid), Signal::SIGKILL).ok();
     }
+    child.kill().ok();
+}
 
-    /// Create a custom command from a shell command string.
-    ///
-    /// The command string is split on whitespace. For complex commands with
-    /// quoted arguments, consider using `new` directly with a properly
-    /// constructed argument vector.
-    pub fn custom(command: &str) -> Self {
-        let parts: Vec<&str> = command.split_whitespace().collect();
-        Self::new(parts)
+#[derive(Debug, Clone, Copy)]
+enum OutputStream {
+    Stdout,
+    Stderr,
+}
+
+fn spawn_reader(
+    pipe: impl Read + Send + 'static,
+    stream: OutputStream,
+    sender: mpsc::Sender<(OutputStream, String)>,
+) -> std::thread::JoinHandle<()> {
+    std::thread::spawn(move || {
+        let mut reader = BufReader::new(pipe);
+        let mut buffer = Vec::new();
+        loop {
+            buffer.clear();
+            match reader.read_until(b'\n', &mut buffer) {
+                Ok(0) | Err(_) => break,
+                Ok(_) => {
+                    let line = String::from_utf8_lossy(&buffer).into_owned();
+                    if sender.send((stream, line)).is_err() {
+                        break;
+                    }
+                }
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_command_respects_quotes() {
+        let parts = split_command(r#"npm run build -- --mode 'production build' --name "a \"b\"""#)
+            .unwrap();
+        assert_eq!(
+            parts,
+            [
+                "npm",
+                "run",
+                "build",
+                "--",
+                "--mode",
+                "production build",
+                "--name",
+                r#"a "b""#
+            ]
+        );
+    }
+
+    #[test]
+    fn test_split_command_rejects_unbalanced_quotes() {
+        let error = split_command("echo 'unterminated").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Command `echo 'unterminated` has unbalanced quotes or a trailing escape"
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_timeout_kills_processes_started_by_the_command() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let command = CommandWrapper::shell("(sleep 1; touch finished) ; wait")
+            .with_timeout(Duration::from_millis(200));
+
+        let result = command
+            .execute_with_result(&mut String::new(), &mut String::new(), temp.path())
+            .unwrap();
+        std::thread::sleep(Duration::from_millis(1500));
+
+        assert!(result.timed_out);
+        assert!(!temp.path().join("finished").exists());
+    }
+
+    #[test]
+    fn test_split_command_rejects_empty_command() {
+        assert!(split_command("   ").is_err());
+    }
+
+    #[test]
+    fn test_custom_command_displays_original_command_line() {
+        let command = CommandWrapper::custom("echo 'hello world'").unwrap();
+        assert_eq!(command.as_string(), "echo 'hello world'");
+        assert_eq!(command.command_with_args, ["echo", "hello world"]);
+    }
+
+    #[test]
+    fn test_command_with_args_displays_quoted_command_line() {
+        let command =
+            CommandWrapper::new(vec!["npm", "run", "build", "--mode", "production build"]);
+        assert_eq!(
+            command.as_string(),
+            "npm run build --mode 'production build'"
+        );
+        assert_eq!(
+            split_command(&command.as_string()).unwrap(),
+            command.command_with_args
+        );
     }
 }
@@ -39,7 +39,8 @@ impl<'a> IntoIterator for &'a FileChanges {
 pub struct FileChange {
     abslute_path: PathBuf,
     old_content: String,
-    new_content: String,
+    /// The content to write, or `None` to delete the file.
+    new_content: Option<String>,
 }
 
 impl FileChange {
@@ -47,16 +48,34 @@ impl FileChange {
         Self {
             abslute_path: path,
             old_content,
-            new_content,
+            new_content: Some(new_content),
+        }
+    }
+
+    /// A change that deletes the file at `path`.
+    pub fn delete(path: PathBuf, old_content: String) -> Self {
+        Self {
+            abslute_path: path,
+            old_content,
+            new_content: None,
         }
     }
 
     pub fn apply(&self) -> Result<()> {
-        fs_err::write(&self.abslute_path, &self.new_content)
-            .context(format!("Failed to write {}", self.abslute_path.display()))?;
+        if let Some(new_content) = &self.new_content {
+            fs_err::write(&self.abslute_path, new_content)
+                .context(format!("Failed to write {}", self.abslute_path.display()))?;
+        } else {
+            fs_err::remove_file(&self.abslute_path)
+                .context(format!("Failed to delete {}", self.abslute_path.display()))?;
+        }
         Ok(())
     }
 
+    pub fn is_deletion(&self) -> bool {
+        self.new_content.is_none()
+    }
+
     pub fn display_diff(
         &self,
         stdout: &mut impl std::fmt::Write,
@@ -69,11 +88,18 @@ impl FileChange {
             .display()
             .to_string();
 
-        writeln!(stdout, "Source: {path_string}")?;
+        if self.is_deletion() {
+            writeln!(stdout, "Source: {path_string} (deleted)")?;
+        } else {
+            writeln!(stdout, "Source: {path_string}")?;
+        }
 
         let diff = TextDiff::configure()
             .algorithm(Algorithm::Patience)
-            .diff_lines(&self.old_content, &self.new_content);
+            .diff_lines(
+                &self.old_content,
+                self.new_content.as_deref().unwrap_or_default(),
+            );
 
         // The following diff output is very similar to what `insta` uses.
 
@@ -0,0 +1,368 @@
+use std::future::Future;
+use std::path::{Path, PathBuf};
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    ForgeError, ForgeService, PullRequest, PullRequestOptions, PullRequestReference, Release,
+    filter_prs_by_date_range,
+};
+
+/// Releases and merged pull requests recorded from a forge.
+///
+/// Stored as TOML when the file name ends in `.toml`, and as JSON otherwise.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct Fixtures {
+    #[serde(default)]
+    pub releases: Vec<Release>,
+    #[serde(default)]
+    pub pull_requests: Vec<PullRequest>,
+}
+
+impl Fixtures {
+    /// Read fixtures from `path`.
+    pub fn read(path: &Path) -> Result<Self> {
+        let contents = fs_err::read_to_string(path)?;
+        let fixtures = if is_toml(path) {
+            toml::from_str(&contents).map_err(anyhow::Error::from)
+        } else {
+            serde_json::from_str(&contents).map_err(anyhow::Error::from)
+        };
+        fixtures.with_context(|| format!("Failed to parse forge fixtures `{}`", path.display()))
+    }
+
+    /// Write the fixtures to `path`, replacing the file.
+    pub fn write(&self, path: &Path) -> Result<()> {
+        let mut contents = if is_toml(path) {
+            toml::to_string_pretty(self)?
+        } else {
+            serde_json::to_string_pretty(self)?
+        };
+        if !contents.ends_with('\n') {
+            contents.push('\n');
+        }
+        fs_err::write(path, contents)?;
+        Ok(())
+    }
+
+    /// Add releases, keeping them sorted by creation date and without duplicates.
+    fn add_releases(&mut self, releases: impl IntoIterator<Item = Release>) {
+        self.releases.extend(releases);
+        self.releases
+            .sort_by(|a, b| a.created_at.cmp(&b.created_at).then(a.name.cmp(&b.name)));
+        self.releases.dedup();
+    }
+
+    /// Add pull requests, keeping them sorted newest first and without duplicates.
+    fn add_pull_requests(&mut self, prs: impl IntoIterator<Item = PullRequest>) {
+        self.pull_requests.extend(prs);
+        self.pull_requests
+            .sort_by(|a, b| b.merged_at.cmp(&a.merged_at).then(b.number.cmp(&a.number)));
+        self.pull_requests.dedup_by_key(|pr| pr.number);
+    }
+}
+
+fn is_toml(path: &Path) -> bool {
+    path.extension()
+        .is_some_and(|extension| extension == "toml")
+}
+
+/// Answers forge requests from a fixtures file, without network access.
+#[derive(Debug, Clone)]
+pub struct ReplayClient {
+    path: PathBuf,
+    fixtures: Fixtures,
+}
+
+impl ReplayClient {
+    /// Load the fixtures to replay from `path`.
+    pub fn new(path: &Path) -> Result<Self> {
+        Ok(Self {
+            path: path.to_path_buf(),
+            fixtures: Fixtures::read(path)?,
+        })
+    }
+}
+
+impl ForgeService for ReplayClient {
+    fn ensure_authenticated(&self) -> Result<()> {
+        Ok(())
+    }
+
+    fn compare_url(&self, _base: &str, _head: &str) -> Option<String> {
+        None
+    }
+
+    fn pull_request_url(&self, number: u64) -> Option<String> {
+        self.fixtures
+            .pull_requests
+            .iter()
+            .find(|pr| pr.number == number)
+            .map(|pr| pr.url.clone())
+    }
+
+    fn get_latest_release(&self) -> Pin<Box<dyn Future<Output = Result<Release>> + Send + '_>> {
+        Box::pin(async {
+            self.fixtures
+                .releases
+                .iter()
+                .max_by_key(|release| release.created_at)
+                .cloned()
+                .ok_or_else(|| {
+                    ForgeError::NoFixtureReleases {
+                        path: self.path.display().to_string(),
+                    }
+                    .into()
+                })
+        })
+    }
+
+    fn get_all_releases(&self) -> Pin<Box<dyn Future<Output = Result<Vec<Release>>> + Send + '_>> {
+        Box::pin(async {
+            let mut releases = self.fixtures.releases.clone();
+            releases.sort_by_key(|release| release.created_at);
+            Ok(releases)
+        })
+    }
+
+    fn get_prs_between(
+        &self,
+        since: Option<&DateTime<Utc>>,
+        until: Option<&DateTime<Utc>>,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<PullRequest>>> + Send + '_>> {
+        let prs = filter_prs_by_date_range(&self.fixtures.pull_requests, since, until);
+        Box::pin(async { Ok(prs) })
+    }
+
+    fn create_or_update_pull_request(
+        &self,
+        _options: PullRequestOptions,
+    ) -> Pin<Box<dyn Future<Output = Result<PullRequestReference>> + Send + '_>> {
+        Box::pin(async {
+            Err(ForgeError::FixturesReadOnly {
+                path: self.path.display().to_string(),
+            }
+            .into())
+        })
+    }
+}
+
+/// Forwards requests to another forge client, recording the releases and pull requests it returns
+/// into a fixtures file that [`ReplayClient`] can answer from.
+///
+/// The file is rewritten after every response, so it is complete even if a later request fails.
+pub struct RecordingClient {
+    inner: Arc<dyn ForgeService>,
+    path: PathBuf,
+    fixtures: Mutex<Fixtures>,
+}
+
+impl RecordingClient {
+    /// Record the responses from `inner` to `path`, replacing the file.
+    pub fn new(inner: Arc<dyn ForgeService>, path: &Path) -> Self {
+        Self {
+            inner,
+            path: path.to_path_buf(),
+            fixtures: Mutex::new(Fixtures::default()),
+        }
+    }
+
+    fn record(&self, update: impl FnOnce(&mut Fixtures)) -> Result<()> {
+        let mut fixtures = self
+            .fixtures
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        update(&mut fixtures);
+        fixtures.write(&self.path).with_context(|| {
+            format!(
+                "Failed to record forge fixtures to `{}`",
+                self.path.display()
+            )
+        })
+    }
+}
+
+impl ForgeService for RecordingClient {
+    fn ensure_authenticated(&self) -> Result<()> {
+        self.inner.ensure_authenticated()
+    }
+
+    fn compare_url(&self, base: &str, head: &str) -> Option<String> {
+        self.inner.compare_url(base, head)
+    }
+
+    fn pull_request_url(&self, number: u64) -> Option<String> {
+        self.inner.pull_request_url(number)
+    }
+
+    fn get_latest_release(&self) -> Pin<Box<dyn Future<Output = Result<Release>> + Send + '_>> {
+        Box::pin(async {
+            let release = self.inner.get_latest_release().await?;
+            self.record(|fixtures| fixtures.add_releases([release.clone()]))?;
+            Ok(release)
+        })
+    }
+
+    fn get_all_releases(&self) -> Pin<Box<dyn Future<Output = Result<Vec<Release>>> + Send + '_>> {
+        Box::pin(async {
+            let releases = self.inner.get_all_releases().await?;
+            self.record(|fixtures| fixtures.add_releases(releases.iter().cloned()))?;
+            Ok(releases)
+        })
+    }
+
+    fn get_prs_between(
+        &self,
+        since: Option<&DateTime<Utc>>,
+        until: Option<&DateTime<Utc>>,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<PullRequest>>> + Send + '_>> {
+        let since = since.copied();
+        let until = until.copied();
+        Box::pin(async move {
+            let prs = self
+                .inner
+                .get_prs_between(since.as_ref(), until.as_ref())
+                .await?;
+            self.record(|fixtures| fixtures.add_pull_requests(prs.iter().cloned()))?;
+            Ok(prs)
+        })
+    }
+
+    fn create_or_update_pull_request(
+        &self,
+        options: PullRequestOptions,
+    ) -> Pin<Box<dyn Future<Output = Result<PullRequestReference>> + Send + '_>> {
+        self.inner.create_or_update_pull_request(options)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use anyhow::Result;
+    use chrono::{DateTime, Utc};
+
+    use super::{Fixtures, RecordingClient, ReplayClient};
+    use crate::{ForgeService, PullRequest, Release};
+
+    fn date(date: &str) -> DateTime<Utc> {
+        format!("{date}T00:00:00Z").parse().unwrap()
+    }
+
+    fn release(name: &str, created_at: &str) -> Release {
+        Release {
+            created_at: date(created_at),
+            name: Some(name.to_string()),
+        }
+    }
+
+    fn pull_request(number: u64, merged_at: &str) -> PullRequest {
+        PullRequest {
+            title: format!("Pull request {number}"),
+            number,
+            url: format!("https://github.com/owner/repo/pull/{number}"),
+            labels: vec!["bug".to_string()],
+            author: Some("alice".to_string()),
+            author_url: None,
+            body: None,
+            closed_issues: Vec::new(),
+            changed_files: Vec::new(),
+            merged_at: date(merged_at),
+        }
+    }
+
+    fn fixtures() -> Fixtures {
+        Fixtures {
+            releases: vec![
+                release("v1.0.0", "2026-01-01"),
+                release("v1.1.0", "2026-02-01"),
+            ],
+            pull_requests: vec![pull_request(2, "2026-01-15"), pull_request(1, "2025-12-15")],
+        }
+    }
+
+    #[tokio::test]
+    async fn replays_releases_and_pull_requests() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("fixtures.toml");
+        fs_err::write(
+            &path,
+            r#"
+[[releases]]
+name = "v1.0.0"
+created-at = "2026-01-01T00:00:00Z"
+
+[[pull-requests]]
+number = 1
+title = "Fix a bug"
+url = "https://github.com/owner/repo/pull/1"
+labels = ["bug"]
+author = "alice"
+merged-at = "2026-01-15T00:00:00Z"
+"#,
+        )?;
+
+        let client = ReplayClient::new(&path)?;
+
+        let latest = client.get_latest_release().await?;
+        assert_eq!(latest.name.as_deref(), Some("v1.0.0"));
+        let prs = client
+            .get_prs_between(Some(&latest.created_at), None)
+            .await?;
+        assert_eq!(prs.len(), 1);
+        assert_eq!(prs[0].title, "Fix a bug");
+        assert!(
+            client
+                .get_prs_between(None, Some(&latest.created_at))
+                .await?
+                .is_empty()
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn records_responses_that_replay_identically() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let source = dir.path().join("source.json");
+        let recording = dir.path().join("recording.toml");
+        fixtures().write(&source)?;
+
+        let client = RecordingClient::new(Arc::new(ReplayClient::new(&source)?), &recording);
+        let releases = client.get_all_releases().await?;
+        client
+            .get_prs_between(None, Some(&releases[0].created_at))
+            .await?;
+        client
+            .get_prs_between(Some(&releases[0].created_at), Some(&releases[1].created_at))
+            .await?;
+        client.get_latest_release().await?;
+
+        assert_eq!(Fixtures::read(&recording)?, fixtures());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn replay_without_releases_fails() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("fixtures.json");
+        Fixtures::default().write(&path)?;
+        let client = ReplayClient::new(&path)?;
+
+        let error = client
+            .get_latest_release()
+            .await
+            .expect_err("fixtures without releases should fail");
+        assert!(
+            error
+                .to_string()
+                .starts_with("No releases found in forge fixtures")
+        );
+        Ok(())
+    }
+}
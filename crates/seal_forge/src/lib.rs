@@ -0,0 +1,152 @@
+//! Forge-agnostic access to releases and pull requests.
+//!
+//! Each forge (GitHub, GitLab, ...) implements [`ForgeService`] in its own crate.
+
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+mod fixtures;
+mod remote;
+mod rest;
+
+pub use fixtures::{Fixtures, RecordingClient, ReplayClient};
+pub use remote::RemoteRepo;
+pub use rest::{RestClient, RestResponse};
+
+pub trait ForgeService: Send + Sync {
+    fn ensure_authenticated(&self) -> Result<()>;
+
+    /// The web page comparing the refs `base` and `head`, e.g. two release tags.
+    fn compare_url(&self, base: &str, head: &str) -> Option<String>;
+
+    /// The web page of the pull request numbered `number`.
+    fn pull_request_url(&self, number: u64) -> Option<String>;
+
+    fn get_latest_release(
+        &self,
+    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<Release>> + Send + '_>>;
+
+    /// Get all releases for a repository.
+    ///
+    /// Sorted by creation date in ascending order.
+    fn get_all_releases(
+        &self,
+    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<Vec<Release>>> + Send + '_>>;
+
+    /// Get the pull requests merged after `since` and up to `until`.
+    fn get_prs_between(
+        &self,
+        since: Option<&DateTime<Utc>>,
+        until: Option<&DateTime<Utc>>,
+    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<Vec<PullRequest>>> + Send + '_>>;
+
+    /// Open a pull request for the release branch, or update the open one.
+    fn create_or_update_pull_request(
+        &self,
+        options: PullRequestOptions,
+    ) -> std::pin::Pin<
+        Box<dyn std::future::Future<Output = Result<PullRequestReference>> + Send + '_>,
+    >;
+}
+
+#[derive(Debug, Error)]
+pub enum ForgeError {
+    #[error("Invalid API URL `{url}`")]
+    InvalidApiUrl { url: String },
+    #[error("Cannot determine the repository from remote URL `{url}`")]
+    InvalidRemoteUrl { url: String },
+    #[error("{method} {url} failed with status {status}: {message}")]
+    RequestFailed {
+        method: String,
+        url: String,
+        status: u16,
+        message: String,
+    },
+    #[error("No releases found in forge fixtures `{path}`")]
+    NoFixtureReleases { path: String },
+    #[error("Cannot open or update pull requests while replaying forge fixtures `{path}`")]
+    FixturesReadOnly { path: String },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct Release {
+    pub created_at: DateTime<Utc>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+/// A merged pull request (or merge request).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct PullRequest {
+    pub title: String,
+    pub number: u64,
+    pub url: String,
+    #[serde(default)]
+    pub labels: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub author: Option<String>,
+    /// The author's profile page.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub author_url: Option<String>,
+    /// The description, when it is not empty.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+    /// The issues that merging the pull request closed.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub closed_issues: Vec<IssueReference>,
+    /// The paths of the files the pull request changed.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub changed_files: Vec<String>,
+    pub merged_at: DateTime<Utc>,
+}
+
+/// An issue referenced by a pull request.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct IssueReference {
+    pub number: u64,
+    pub url: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct PullRequestOptions {
+    pub title: String,
+    pub body: String,
+    pub head: String,
+    pub base: String,
+    pub draft: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PullRequestReference {
+    pub url: String,
+}
+
+pub fn filter_prs_by_date_range(
+    prs: &[PullRequest],
+    since: Option<&DateTime<Utc>>,
+    until: Option<&DateTime<Utc>>,
+) -> Vec<PullRequest> {
+    prs.iter()
+        .filter(|pr| {
+            let after_since = if let Some(since_date) = since {
+                pr.merged_at > *since_date
+            } else {
+                true
+            };
+
+            let before_until = if let Some(until_date) = until {
+                pr.merged_at <= *until_date
+            } else {
+                true
+            };
+
+            after_since && before_until
+        })
+        .cloned()
+        .collect()
+}
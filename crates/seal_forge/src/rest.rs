@@ -0,0 +1,268 @@
+use anyhow::{Context, Result};
+use bytes::Bytes;
+use http_body_util::{BodyExt, Full};
+use hyper::header::{ACCEPT, CONTENT_TYPE, HeaderMap, HeaderName, HeaderValue, USER_AGENT};
+use hyper::{Method, Request};
+use hyper_rustls::{HttpsConnector, HttpsConnectorBuilder};
+use hyper_util::client::legacy::Client;
+use hyper_util::client::legacy::connect::HttpConnector;
+use hyper_util::rt::TokioExecutor;
+use serde::de::DeserializeOwned;
+use url::Url;
+
+use crate::ForgeError;
+
+/// A minimal JSON client for forge REST APIs.
+#[derive(Debug, Clone)]
+pub struct RestClient {
+    base_url: String,
+    headers: HeaderMap,
+    client: Client<HttpsConnector<HttpConnector>, Full<Bytes>>,
+}
+
+/// A deserialized response body, with the response headers for pagination.
+#[derive(Debug)]
+pub struct RestResponse<T> {
+    pub body: T,
+    pub headers: HeaderMap,
+}
+
+impl<T> RestResponse<T> {
+    /// The value of a response header, if it is present and not empty.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .get(name)
+            .and_then(|value| value.to_str().ok())
+            .filter(|value| !value.is_empty())
+    }
+}
+
+impl RestClient {
+    /// Create a client for the API rooted at `base_url`, e.g. `https://gitlab.com/api/v4`.
+    pub fn new(base_url: &str) -> Result<Self> {
+        if Url::parse(base_url).is_err() {
+            return Err(ForgeError::InvalidApiUrl {
+                url: base_url.to_string(),
+            }
+            .into());
+        }
+
+        let connector = HttpsConnectorBuilder::new()
+            .with_native_roots()
+            .context("Failed to load the system's root certificates")?
+            .https_or_http()
+            .enable_http1()
+            .build();
+
+        let mut headers = HeaderMap::new();
+        headers.insert(USER_AGENT, HeaderValue::from_static("seal"));
+        headers.insert(ACCEPT, HeaderValue::from_static("application/json"));
+
+        Ok(Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            headers,
+            client: Client::builder(TokioExecutor::new()).build(connector),
+        })
+    }
+
+    /// Send `value` in the `name` header of every request.
+    pub fn with_header(mut self, name: &'static str, value: &str) -> Result<Self> {
+        let mut value =
+            HeaderValue::from_str(value).with_context(|| format!("Invalid `{name}` header"))?;
+        value.set_sensitive(true);
+        self.headers.insert(HeaderName::from_static(name), value);
+        Ok(self)
+    }
+
+    /// `GET` the resource at `path`, relative to the base URL.
+    pub async fn get<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        query: &[(&str, &str)],
+    ) -> Result<RestResponse<T>> {
+        let url = self.url(path, query)?;
+        self.request(Method::GET, url, None).await
+    }
+
+    /// Send `body` as JSON to the resource at `path`, relative to the base URL.
+    pub async fn send<T: DeserializeOwned>(
+        &self,
+        method: Method,
+        path: &str,
+        body: &serde_json::Value,
+    ) -> Result<T> {
+        let url = self.url(path, &[])?;
+        let body = serde_json::to_vec(body)?;
+        Ok(self.request(method, url, Some(body)).await?.body)
+    }
+
+    fn url(&self, path: &str, query: &[(&str, &str)]) -> Result<Url> {
+        let mut url = Url::parse(&format!("{}{path}", self.base_url)).map_err(|_| {
+            ForgeError::InvalidApiUrl {
+                url: format!("{}{path}", self.base_url),
+            }
+        })?;
+        if !query.is_empty() {
+            url.query_pairs_mut().extend_pairs(query);
+        }
+        Ok(url)
+    }
+
+    async fn request<T: DeserializeOwned>(
+        &self,
+        method: Method,
+        url: Url,
+        body: Option<Vec<u8>>,
+    ) -> Result<RestResponse<T>> {
+        let mut request = Request::builder().method(method.clone()).uri(url.as_str());
+        for (name, value) in &self.headers {
+            request = request.header(name, value);
+        }
+        if body.is_some() {
+            request = request.header(CONTENT_TYPE, "application/json");
+        }
+        let request = request.body(Full::new(Bytes::from(body.unwrap_or_default())))?;
+
+        let response = self
+            .client
+            .request(request)
+            .await
+            .with_context(|| format!("Failed to send {method} {url}"))?;
+        let status = response.status();
+        let headers = response.headers().clone();
+        let bytes = response
+            .into_body()
+            .collect()
+            .await
+            .with_context(|| format!("Failed to read the response to {method} {url}"))?
+            .to_bytes();
+
+        if !status.is_success() {
+            return Err(ForgeError::RequestFailed {
+                method: method.to_string(),
+                url: url.to_string(),
+                status: status.as_u16(),
+                message: error_message(&bytes),
+            }
+            .into());
+        }
+
+        let body = serde_json::from_slice(&bytes)
+            .with_context(|| format!("Unexpected response to {method} {url}"))?;
+        Ok(RestResponse { body, headers })
+    }
+}
+
+/// The error message from a JSON error response, or the raw body.
+fn error_message(body: &[u8]) -> String {
+    let json: Option<serde_json::Value> = serde_json::from_slice(body).ok();
+    let message = json.as_ref().and_then(|json| {
+        ["message", "error"]
+            .into_iter()
+            .find_map(|key| json.get(key))
+            .map(|value| match value {
+                serde_json::Value::String(message) => message.clone(),
+                value => value.to_string(),
+            })
+    });
+    message.unwrap_or_else(|| String::from_utf8_lossy(body).trim().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use serde_json::{Value, json};
+    use wiremock::matchers::{body_json, header, method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    use super::RestClient;
+    use crate::ForgeError;
+
+    #[tokio::test]
+    async fn get_sends_headers_and_query() -> Result<()> {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/api/v4/projects/group%2Fproject/releases"))
+            .and(query_param("per_page", "1"))
+            .and(header("private-token", "secret"))
+            .and(header("user-agent", "seal"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("x-next-page", "2")
+                    .set_body_json(json!([{ "name": "v1.0.0" }])),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = RestClient::new(&format!("{}/api/v4/", server.uri()))?
+            .with_header("private-token", "secret")?;
+        let response = client
+            .get::<Value>("/projects/group%2Fproject/releases", &[("per_page", "1")])
+            .await?;
+
+        assert_eq!(response.body, json!([{ "name": "v1.0.0" }]));
+        assert_eq!(response.header("x-next-page"), Some("2"));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn send_posts_json() -> Result<()> {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/items"))
+            .and(header("content-type", "application/json"))
+            .and(body_json(json!({ "title": "Release" })))
+            .respond_with(ResponseTemplate::new(201).set_body_json(json!({ "id": 1 })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let created: Value = RestClient::new(&server.uri())?
+            .send(
+                hyper::Method::POST,
+                "/items",
+                &json!({ "title": "Release" }),
+            )
+            .await?;
+
+        assert_eq!(created, json!({ "id": 1 }));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn failed_requests_include_the_error_message() -> Result<()> {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/items"))
+            .respond_with(
+                ResponseTemplate::new(401).set_body_json(json!({ "message": "401 Unauthorized" })),
+            )
+            .mount(&server)
+            .await;
+
+        let error = RestClient::new(&server.uri())?
+            .get::<Value>("/items", &[])
+            .await
+            .expect_err("unauthorized request should fail");
+
+        assert!(matches!(
+            error.downcast_ref::<ForgeError>(),
+            Some(ForgeError::RequestFailed { status: 401, .. })
+        ));
+        assert_eq!(
+            error.to_string(),
+            format!(
+                "GET {}/items failed with status 401: 401 Unauthorized",
+                server.uri()
+            )
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn rejects_invalid_base_url() {
+        let error = RestClient::new("not a url").expect_err("invalid URL should fail");
+        assert_eq!(error.to_string(), "Invalid API URL `not a url`");
+    }
+}
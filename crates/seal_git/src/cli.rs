@@ -0,0 +1,258 @@
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output, Stdio};
+
+use crate::{CommitOptions, GitError, Repository, Result, UrlRewrite, rewrite_url};
+
+/// A [`Repository`] backed by the `git` binary.
+#[derive(Debug, Clone)]
+pub struct CliRepository {
+    root: PathBuf,
+}
+
+impl CliRepository {
+    /// Open the repository containing `path`.
+    pub fn open(path: &Path) -> Result<Self> {
+        let output = run(path, &["rev-parse", "--show-toplevel"], &[])?;
+        if !output.status.success() {
+            return Err(GitError::NotARepository {
+                path: path.to_path_buf(),
+            });
+        }
+        let root = stdout(output)?;
+        Ok(Self {
+            root: PathBuf::from(root),
+        })
+    }
+
+    /// Use `root` as the working tree root without checking it.
+    pub(crate) fn from_root(root: PathBuf) -> Self {
+        Self { root }
+    }
+
+    /// Run `git` in the repository root and return its trimmed stdout.
+    fn git(&self, args: &[&str]) -> Result<String> {
+        self.git_with_env(args, &[])
+    }
+
+    fn git_with_env(&self, args: &[&str], env: &[(&str, &str)]) -> Result<String> {
+        let output = run(&self.root, args, env)?;
+        if !output.status.success() {
+            return Err(command_failed(args, &output));
+        }
+        stdout(output)
+    }
+}
+
+fn run(dir: &Path, args: &[&str], env: &[(&str, &str)]) -> Result<Output> {
+    Command::new("git")
+        .args(args)
+        .envs(env.iter().copied())
+        .current_dir(dir)
+        .stdin(Stdio::null())
+        .output()
+        .map_err(GitError::Spawn)
+}
+
+fn stdout(output: Output) -> Result<String> {
+    let stdout = String::from_utf8(output.stdout).map_err(|_| GitError::InvalidUtf8)?;
+    Ok(stdout.trim_end_matches('\n').to_string())
+}
+
+fn command_failed(args: &[&str], output: &Output) -> GitError {
+    GitError::CommandFailed {
+        command: format!("git {}", args.join(" ")),
+        exit_code: output.status.code(),
+        stderr: String::from_utf8_lossy(&output.stderr).trim().to_string(),
+    }
+}
+
+impl Repository for CliRepository {
+    fn root(&self) -> &Path {
+        &self.root
+    }
+
+    fn current_branch(&self) -> Result<String> {
+        let branch = self.git(&["branch", "--show-current"])?;
+        if branch.is_empty() {
+            return Err(GitError::DetachedHead);
+        }
+        Ok(branch)
+    }
+
+    fn status(&self) -> Result<Vec<String>> {
+        let output = self.git(&["status", "--porcelain", "-z", "--untracked-files=all"])?;
+        let mut paths = Vec::new();
+        let mut entries = output.split('\0').filter(|entry| !entry.is_empty());
+        while let Some(entry) = entries.next() {
+            let (code, path) = entry.split_at_checked(3).unwrap_or((entry, ""));
+            paths.push(path.to_string());
+            // Renames and copies are followed by the original path.
+            if code.contains(['R', 'C']) {
+                entries.next();
+            }
+        }
+        paths.sort();
+        paths.dedup();
+        Ok(paths)
+    }
+
+    fn reference_exists(&self, name: &str) -> Result<bool> {
+        let args = ["show-ref", "--verify", "--quiet", name];
+        let output = run(&self.root, &args, &[])?;
+        match output.status.code() {
+            Some(0) => Ok(true),
+            Some(1) => Ok(false),
+            _ => Err(command_failed(&args, &output)),
+        }
+    }
+
+    fn create_branch(&self, name: &str) -> Result<()> {
+        self.git(&["checkout", "-b", name])?;
+        Ok(())
+    }
+
+    fn stage_all(&self) -> Result<()> {
+        self.git(&["add", "-A"])?;
+        Ok(())
+    }
+
+    fn commit(&self, message: &str, options: &CommitOptions) -> Result<()> {
+        if run(&self.root, &["diff", "--cached", "--quiet"], &[])?
+            .status
+            .success()
+        {
+            return Err(GitError::NothingToCommit);
+        }
+
+        let format_config;
+        let mut args = Vec::new();
+        if let Some(format) = &options.signing_format {
+            format_config = format!("gpg.format={format}");
+            args.extend(["-c", format_config.as_str()]);
+        }
+        args.push("commit");
+        if options.signing_format.is_some() {
+            args.push("-S");
+        }
+        args.extend(["-m", message]);
+
+        let mut env = Vec::new();
+        if let Some(author) = &options.author {
+            env.extend([
+                ("GIT_AUTHOR_NAME", author.name.as_str()),
+                ("GIT_AUTHOR_EMAIL", author.email.as_str()),
+            ]);
+        }
+        if let Some(committer) = &options.committer {
+            env.extend([
+                ("GIT_COMMITTER_NAME", committer.name.as_str()),
+                ("GIT_COMMITTER_EMAIL", committer.email.as_str()),
+            ]);
+        }
+
+        self.git_with_env(&args, &env)?;
+        Ok(())
+    }
+
+    fn upstream(&self, branch: &str) -> Result<Option<String>> {
+        let upstream = self.git(&[
+            "for-each-ref",
+            "--format=%(upstream)",
+            &format!("refs/heads/{branch}"),
+        ])?;
+        Ok((!upstream.is_empty()).then_some(upstream))
+    }
+
+    fn commits_behind(&self, reference: &str) -> Result<usize> {
+        let commit = format!("{reference}^{{commit}}");
+        if !run(
+            &self.root,
+            &["rev-parse", "--verify", "--quiet", &commit],
+            &[],
+        )?
+        .status
+        .success()
+        {
+            return Err(GitError::ReferenceNotFound {
+                name: reference.to_string(),
+            });
+        }
+        let count = self.git(&["rev-list", "--count", &format!("HEAD..{reference}")])?;
+        Ok(count.parse().unwrap_or(0))
+    }
+
+    fn remotes(&self) -> Result<Vec<String>> {
+        let output = self.git(&["remote"])?;
+        Ok(output.lines().map(ToString::to_string).collect())
+    }
+
+    fn remote_url(&self, name: &str) -> Result<String> {
+        if !self.remote_exists(name)? {
+            return Err(GitError::RemoteNotFound {
+                name: name.to_string(),
+            });
+        }
+        let url = self
+            .config_value(&format!("remote.{name}.url"))?
+            .ok_or_else(|| GitError::RemoteWithoutUrl {
+                name: name.to_string(),
+            })?;
+        Ok(rewrite_url(&url, &self.url_rewrites()?))
+    }
+
+    fn url_rewrites(&self) -> Result<Vec<UrlRewrite>> {
+        let args = ["config", "-z", "--get-regexp", r"^url\..*\.insteadof$"];
+        let output = run(&self.root, &args, &[])?;
+        match output.status.code() {
+            Some(0) => {}
+            // `git config --get-regexp` exits with 1 when no key matches.
+            Some(1) => return Ok(Vec::new()),
+            _ => return Err(command_failed(&args, &output)),
+        }
+
+        // With `-z`, each entry is the key and value separated by a newline, ending with NUL.
+        let output = String::from_utf8(output.stdout).map_err(|_| GitError::InvalidUtf8)?;
+        Ok(output
+            .split('\0')
+            .filter_map(|entry| {
+                let (key, instead_of) = entry.split_once('\n')?;
+                let base = key.strip_prefix("url.")?.strip_suffix(".insteadof")?;
+                Some(UrlRewrite {
+                    base: base.to_string(),
+                    instead_of: instead_of.to_string(),
+                })
+            })
+            .collect())
+    }
+
+    fn remote_branch_exists(&self, remote: &str, branch: &str) -> Result<bool> {
+        let reference = format!("refs/heads/{branch}");
+        let args = ["ls-remote", "--exit-code", "--heads", remote, &reference];
+        let output = run(&self.root, &args, &[])?;
+        match output.status.code() {
+            Some(0) => Ok(true),
+            // `--exit-code` exits with 2 when no matching refs are found.
+            Some(2) => Ok(false),
+            _ => Err(command_failed(&args, &output)),
+        }
+    }
+
+    fn push(&self, remote: &str, branch: &str) -> Result<()> {
+        self.git(&["push", remote, branch])?;
+        Ok(())
+    }
+
+    fn config_value(&self, key: &str) -> Result<Option<String>> {
+        let args = ["config", "--get", key];
+        let output = run(&self.root, &args, &[])?;
+        match output.status.code() {
+            Some(0) => {
+                let value = stdout(output)?;
+                Ok((!value.is_empty()).then_some(value))
+            }
+            // `git config --get` exits with 1 when the key is unset.
+            Some(1) => Ok(None),
+            _ => Err(command_failed(&args, &output)),
+        }
+    }
+}
@@ -0,0 +1,80 @@
+use std::path::PathBuf;
+
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum GitError {
+    #[error("Not in a git repository: {}", path.display())]
+    NotARepository { path: PathBuf },
+
+    #[error("HEAD is not on a branch")]
+    DetachedHead,
+
+    #[error("Git remote `{name}` does not exist")]
+    RemoteNotFound { name: String },
+
+    #[error("Git remote `{name}` has no URL configured")]
+    RemoteWithoutUrl { name: String },
+
+    #[error("Git reference `{name}` does not exist")]
+    ReferenceNotFound { name: String },
+
+    #[error("Git reference `{name}` already exists")]
+    ReferenceExists { name: String },
+
+    #[error(
+        "Command `{command}` failed{}{}",
+        .exit_code.map(|code| format!(" (exit code {code})")).unwrap_or_default(),
+        if .stderr.is_empty() { String::new() } else { format!("\n{}", .stderr) }
+    )]
+    CommandFailed {
+        command: String,
+        exit_code: Option<i32>,
+        stderr: String,
+    },
+
+    #[error("Nothing to commit: the staged changes match `HEAD`")]
+    NothingToCommit,
+
+    #[error("Cannot commit with unmerged paths: {}", .paths.join(", "))]
+    UnmergedPaths { paths: Vec<String> },
+
+    #[error("Git {role} identity is unknown; set `user.name` and `user.email`")]
+    UnknownIdentity { role: &'static str },
+
+    #[error("`user.signingkey` must be set to sign commits with an SSH key")]
+    MissingSigningKey,
+
+    #[error("Failed to run `git`: {0}")]
+    Spawn(#[source] std::io::Error),
+
+    #[error("Failed to run `{program}`: {source}")]
+    SpawnProgram {
+        program: String,
+        #[source]
+        source: std::io::Error,
+    },
+
+    #[error("Git returned output that is not valid UTF-8")]
+    InvalidUtf8,
+
+    #[error("Failed to {operation}: {source}")]
+    Gix {
+        operation: &'static str,
+        #[source]
+        source: Box<dyn std::error::Error + Send + Sync>,
+    },
+}
+
+impl GitError {
+    /// Wrap an error from the `gix` backend, describing the operation that failed.
+    pub(crate) fn gix<E>(operation: &'static str) -> impl FnOnce(E) -> Self
+    where
+        E: std::error::Error + Send + Sync + 'static,
+    {
+        move |source| Self::Gix {
+            operation,
+            source: Box::new(source),
+        }
+    }
+}
@@ -0,0 +1,580 @@
+use std::io::Read as _;
+use std::path::Path;
+
+use gix::bstr::{BStr, BString, ByteSlice};
+use gix::refs::transaction::{Change, LogChange, PreviousValue, RefEdit, RefLog};
+use gix::refs::{FullName, Target};
+use gix::status::UntrackedFiles;
+use gix::status::index_worktree::Item;
+
+use crate::{
+    CliRepository, CommitOptions, GitError, Repository, Result, Signature, UrlRewrite, rewrite_url,
+    sign,
+};
+
+/// A [`Repository`] that works in process with `gix`.
+///
+/// Git hooks do not run. Commits are signed with the programs Git uses for `gpg.format`.
+pub struct GixRepository {
+    repo: gix::ThreadSafeRepository,
+    cli: CliRepository,
+}
+
+impl GixRepository {
+    /// Open the repository containing `path`.
+    pub fn open(path: &Path) -> Result<Self> {
+        let not_a_repository = || GitError::NotARepository {
+            path: path.to_path_buf(),
+        };
+        let repo = gix::ThreadSafeRepository::discover(path).map_err(|_| not_a_repository())?;
+        let root = repo
+            .work_dir()
+            .map(Path::to_path_buf)
+            .ok_or_else(not_a_repository)?;
+        Ok(Self {
+            repo,
+            cli: CliRepository::from_root(root),
+        })
+    }
+
+    fn repo(&self) -> gix::Repository {
+        self.repo.to_thread_local()
+    }
+}
+
+fn full_name(name: String) -> Result<FullName> {
+    FullName::try_from(name).map_err(GitError::gix("parse reference name"))
+}
+
+fn ensure_reference_is_new(repo: &gix::Repository, name: &str) -> Result<()> {
+    let exists = repo
+        .try_find_reference(name)
+        .map_err(GitError::gix("look up reference"))?
+        .is_some();
+    if exists {
+        return Err(GitError::ReferenceExists {
+            name: name.to_string(),
+        });
+    }
+    Ok(())
+}
+
+/// The paths that differ between the index and the working tree, including untracked files.
+fn worktree_changes(repo: &gix::Repository) -> Result<Vec<BString>> {
+    let items = repo
+        .status(gix::progress::Discard)
+        .map_err(GitError::gix("compute status"))?
+        .untracked_files(UntrackedFiles::Files)
+        .index_worktree_rewrites(None)
+        .into_index_worktree_iter(Vec::new())
+        .map_err(GitError::gix("compute status"))?;
+
+    let mut paths = Vec::new();
+    for item in items {
+        match item.map_err(GitError::gix("compute status"))? {
+            Item::Modification { rela_path, .. } => paths.push(rela_path),
+            Item::DirectoryContents { entry, .. } => {
+                if entry.status == gix::dir::entry::Status::Untracked {
+                    paths.push(entry.rela_path);
+                }
+            }
+            Item::Rewrite {
+                source,
+                dirwalk_entry,
+                ..
+            } => {
+                paths.push(source.rela_path().to_owned());
+                paths.push(dirwalk_entry.rela_path);
+            }
+        }
+    }
+    Ok(paths)
+}
+
+/// Replace the index entries at `path` with the file in the working tree, like `git add`.
+fn stage_path(
+    repo: &gix::Repository,
+    index: &mut gix::index::File,
+    pipeline: &mut gix::filter::Pipeline<'_>,
+    filter_index: &gix::index::State,
+    path: &BStr,
+) -> Result<()> {
+    let previous_mode = index.entry_by_path(path).map(|entry| entry.mode);
+    index.remove_entries(|_, entry_path, _| entry_path == path);
+
+    let worktree = repo.workdir().expect("opened with a working tree");
+    let file = worktree.join(gix::path::from_bstr(path));
+    let metadata = match gix::index::fs::Metadata::from_path_no_follow(&file) {
+        Ok(metadata) => metadata,
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => return Ok(()),
+        Err(error) => return Err(GitError::gix("read working tree file")(error)),
+    };
+
+    let (data, mode) = if metadata.is_symlink() {
+        let target = std::fs::read_link(&file).map_err(GitError::gix("read symlink"))?;
+        let target = gix::path::into_bstr(target).into_owned();
+        (Vec::from(target), gix::index::entry::Mode::SYMLINK)
+    } else if metadata.is_file() {
+        let contents =
+            std::fs::File::open(&file).map_err(GitError::gix("read working tree file"))?;
+        let mut data = Vec::new();
+        pipeline
+            .convert_to_git(contents, gix::path::from_bstr(path).as_ref(), filter_index)
+            .map_err(GitError::gix("apply filters"))?
+            .read_to_end(&mut data)
+            .map_err(GitError::gix("apply filters"))?;
+
+        let file_mode = repo
+            .config_snapshot()
+            .boolean("core.fileMode")
+            .unwrap_or(true);
+        let mode = match previous_mode {
+            Some(mode) if !file_mode => mode,
+            _ if metadata.is_executable() => gix::index::entry::Mode::FILE_EXECUTABLE,
+            _ => gix::index::entry::Mode::FILE,
+        };
+        (data, mode)
+    } else {
+        // Nested repositories and other special files are not staged.
+        return Ok(());
+    };
+
+    let id = repo
+        .write_blob(data)
+        .map_err(GitError::gix("write blob"))?
+        .detach();
+    let stat = gix::index::entry::Stat::from_fs(&metadata)
+        .map_err(GitError::gix("read working tree file"))?;
+    index.dangerously_push_entry(stat, id, gix::index::entry::Flags::empty(), mode, path);
+    Ok(())
+}
+
+/// Write the tree recorded by the stage 0 entries of `index`.
+fn write_tree(repo: &gix::Repository, index: &gix::index::State) -> Result<gix::ObjectId> {
+    let unmerged: Vec<String> = index
+        .entries()
+        .iter()
+        .filter(|entry| entry.stage_raw() != 0)
+        .map(|entry| entry.path(index).to_str_lossy().into_owned())
+        .collect();
+    if !unmerged.is_empty() {
+        let mut paths = unmerged;
+        paths.dedup();
+        return Err(GitError::UnmergedPaths { paths });
+    }
+
+    let mut editor = repo
+        .edit_tree(gix::ObjectId::empty_tree(repo.object_hash()))
+        .map_err(GitError::gix("write tree"))?;
+    for entry in index.entries() {
+        if entry
+            .flags
+            .contains(gix::index::entry::Flags::INTENT_TO_ADD)
+        {
+            continue;
+        }
+        let Some(mode) = entry.mode.to_tree_entry_mode() else {
+            continue;
+        };
+        editor
+            .upsert(entry.path(index), mode.kind(), entry.id)
+            .map_err(GitError::gix("write tree"))?;
+    }
+    Ok(editor
+        .write()
+        .map_err(GitError::gix("write tree"))?
+        .detach())
+}
+
+/// The identity for a commit's `role`, from `configured` unless overridden.
+///
+/// Like Git, the time is now unless the `date_variable` environment variable sets it.
+fn signature(
+    role: &'static str,
+    configured: Option<std::result::Result<gix::actor::SignatureRef<'_>, gix::config::time::Error>>,
+    overridden: Option<&Signature>,
+    date_variable: &str,
+) -> Result<gix::actor::Signature> {
+    let configured = configured
+        .transpose()
+        .map_err(GitError::gix("read identity"))?;
+    let time = match configured {
+        Some(configured) if std::env::var_os(date_variable).is_some() => {
+            configured.time().map_err(GitError::gix("read identity"))?
+        }
+        _ => gix::date::Time::now_local_or_utc(),
+    };
+
+    let (name, email) = match (overridden, configured) {
+        (Some(signature), _) => (
+            signature.name.as_str().into(),
+            signature.email.as_str().into(),
+        ),
+        (None, Some(configured)) => (configured.name.to_owned(), configured.email.to_owned()),
+        (None, None) => return Err(GitError::UnknownIdentity { role }),
+    };
+    Ok(gix::actor::Signature { name, email, time })
+}
+
+/// Git's default `whitespace` cleanup of a commit message given with `-m`.
+fn cleanup_message(message: &str) -> String {
+    let mut cleaned = String::new();
+    let mut blank_lines = 0;
+    for line in message.lines().map(str::trim_end) {
+        if line.is_empty() {
+            blank_lines += 1;
+            continue;
+        }
+        if !cleaned.is_empty() && blank_lines > 0 {
+            cleaned.push('\n');
+        }
+        blank_lines = 0;
+        cleaned.push_str(line);
+        cleaned.push('\n');
+    }
+    cleaned
+}
+
+fn head_commit_id(repo: &gix::Repository) -> Result<gix::ObjectId> {
+    repo.head_id()
+        .map(gix::Id::detach)
+        .map_err(GitError::gix("resolve HEAD"))
+}
+
+impl Repository for GixRepository {
+    fn root(&self) -> &Path {
+        self.cli.root()
+    }
+
+    fn current_branch(&self) -> Result<String> {
+        let name = self
+            .repo()
+            .head_name()
+            .map_err(GitError::gix("read HEAD"))?
+            .ok_or(GitError::DetachedHead)?;
+        Ok(name.shorten().to_string())
+    }
+
+    fn status(&self) -> Result<Vec<String>> {
+        let repo = self.repo();
+        let items = repo
+            .status(gix::progress::Discard)
+            .map_err(GitError::gix("compute status"))?
+            .untracked_files(UntrackedFiles::Files)
+            .into_iter(Vec::new())
+            .map_err(GitError::gix("compute status"))?;
+
+        let mut paths = Vec::new();
+        for item in items {
+            let item = item.map_err(GitError::gix("compute status"))?;
+            paths.push(item.location().to_str_lossy().into_owned());
+        }
+        paths.sort();
+        paths.dedup();
+        Ok(paths)
+    }
+
+    fn reference_exists(&self, name: &str) -> Result<bool> {
+        Ok(self
+            .repo()
+            .try_find_reference(name)
+            .map_err(GitError::gix("look up reference"))?
+            .is_some())
+    }
+
+    fn create_branch(&self, name: &str) -> Result<()> {
+        let repo = self.repo();
+        let branch = full_name(format!("refs/heads/{name}"))?;
+        ensure_reference_is_new(&repo, branch.as_bstr().to_str_lossy().as_ref())?;
+
+        let head = repo.head().map_err(GitError::gix("read HEAD"))?;
+        let mut edits = Vec::new();
+        // An unborn `HEAD` has nothing to branch from, so only `HEAD` itself moves.
+        if let Some(id) = head.id() {
+            edits.push(RefEdit {
+                change: Change::Update {
+                    log: LogChange {
+                        mode: RefLog::AndReference,
+                        force_create_reflog: false,
+                        message: "branch: Created from HEAD".into(),
+                    },
+                    expected: PreviousValue::MustNotExist,
+                    new: Target::Object(id.detach()),
+                },
+                name: branch.clone(),
+                deref: false,
+            });
+        }
+        let previous = head
+            .referent_name()
+            .map_or_else(|| "HEAD".to_string(), |name| name.shorten().to_string());
+        edits.push(RefEdit {
+            change: Change::Update {
+                log: LogChange {
+                    mode: RefLog::AndReference,
+                    force_create_reflog: false,
+                    message: format!("checkout: moving from {previous} to {name}").into(),
+                },
+                expected: PreviousValue::Any,
+                new: Target::Symbolic(branch),
+            },
+            name: full_name("HEAD".to_string())?,
+            deref: false,
+        });
+
+        repo.edit_references(edits)
+            .map_err(GitError::gix("create branch"))?;
+        Ok(())
+    }
+
+    fn stage_all(&self) -> Result<()> {
+        let repo = self.repo();
+        let changes = worktree_changes(&repo)?;
+        if changes.is_empty() {
+            return Ok(());
+        }
+
+        let snapshot = repo.index_or_empty().map_err(GitError::gix("read index"))?;
+        let mut index = gix::index::File::clone(&snapshot);
+        let (mut pipeline, filter_index) = repo
+            .filter_pipeline(None)
+            .map_err(GitError::gix("read attributes"))?;
+        for path in &changes {
+            stage_path(
+                &repo,
+                &mut index,
+                &mut pipeline,
+                &filter_index,
+                path.as_ref(),
+            )?;
+        }
+
+        // The cached trees no longer match the entries.
+        index.remove_tree();
+        index.sort_entries();
+        index
+            .write(gix::index::write::Options::default())
+            .map_err(GitError::gix("write index"))?;
+        Ok(())
+    }
+
+    fn commit(&self, message: &str, options: &CommitOptions) -> Result<()> {
+        let repo = self.repo();
+        let index = repo.index_or_empty().map_err(GitError::gix("read index"))?;
+        let tree = write_tree(&repo, &index)?;
+
+        let parent = repo
+            .head()
+            .map_err(GitError::gix("read HEAD"))?
+            .id()
+            .map(gix::Id::detach);
+        let parent_tree = match parent {
+            Some(parent) => repo
+                .find_commit(parent)
+                .map_err(GitError::gix("read commit"))?
+                .tree_id()
+                .map_err(GitError::gix("read commit"))?
+                .detach(),
+            None => gix::ObjectId::empty_tree(repo.object_hash()),
+        };
+        if tree == parent_tree {
+            return Err(GitError::NothingToCommit);
+        }
+
+        let author = signature(
+            "author",
+            repo.author(),
+            options.author.as_ref(),
+            "GIT_AUTHOR_DATE",
+        )?;
+        let committer = signature(
+            "committer",
+            repo.committer(),
+            options.committer.as_ref(),
+            "GIT_COMMITTER_DATE",
+        )?;
+
+        let mut commit = gix::objs::Commit {
+            tree,
+            parents: parent.into_iter().collect(),
+            author,
+            committer,
+            encoding: None,
+            message: cleanup_message(message).into(),
+            extra_headers: Vec::new(),
+        };
+        if let Some(format) = &options.signing_format {
+            let mut payload = Vec::new();
+            gix::objs::WriteTo::write_to(&commit, &mut payload)
+                .map_err(GitError::gix("serialize commit"))?;
+            let identity = format!("{} <{}>", commit.committer.name, commit.committer.email);
+            let signature = sign::sign(self, format, &identity, &payload)?;
+            commit
+                .extra_headers
+                .push(("gpgsig".into(), signature.trim_end().into()));
+        }
+
+        let id = repo
+            .write_object(&commit)
+            .map_err(GitError::gix("write commit"))?
+            .detach();
+        let mut time = gix::date::parse::TimeBuf::default();
+        repo.edit_references_as(
+            [RefEdit {
+                change: Change::Update {
+                    log: LogChange {
+                        mode: RefLog::AndReference,
+                        force_create_reflog: false,
+                        message: gix::reference::log::message(
+                            "commit",
+                            commit.message.as_ref(),
+                            commit.parents.len(),
+                        ),
+                    },
+                    expected: match parent {
+                        Some(parent) => PreviousValue::MustExistAndMatch(Target::Object(parent)),
+                        None => PreviousValue::MustNotExist,
+                    },
+                    new: Target::Object(id),
+                },
+                name: full_name("HEAD".to_string())?,
+                deref: true,
+            }],
+            Some(commit.committer.to_ref(&mut time)),
+        )
+        .map_err(GitError::gix("update HEAD"))?;
+        Ok(())
+    }
+
+    fn upstream(&self, branch: &str) -> Result<Option<String>> {
+        let branch = full_name(format!("refs/heads/{branch}"))?;
+        self.repo()
+            .branch_remote_tracking_ref_name(branch.as_ref(), gix::remote::Direction::Fetch)
+            .transpose()
+            .map(|upstream| upstream.map(|name| name.as_bstr().to_str_lossy().into_owned()))
+            .map_err(GitError::gix("read upstream branch"))
+    }
+
+    fn commits_behind(&self, reference: &str) -> Result<usize> {
+        let repo = self.repo();
+        let target = repo
+            .rev_parse_single(format!("{reference}^{{commit}}").as_str())
+            .map_err(|_| GitError::ReferenceNotFound {
+                name: reference.to_string(),
+            })?
+            .detach();
+        let head = head_commit_id(&repo)?;
+
+        let mut count = 0;
+        for info in repo
+            .rev_walk([target])
+            .with_hidden([head])
+            .all()
+            .map_err(GitError::gix("walk history"))?
+        {
+            info.map_err(GitError::gix("walk history"))?;
+            count += 1;
+        }
+        Ok(count)
+    }
+
+    fn remotes(&self) -> Result<Vec<String>> {
+        Ok(self
+            .repo()
+            .remote_names()
+            .into_iter()
+            .map(|name| name.to_str_lossy().into_owned())
+            .collect())
+    }
+
+    fn remote_url(&self, name: &str) -> Result<String> {
+        if !self.remote_exists(name)? {
+            return Err(GitError::RemoteNotFound {
+                name: name.to_string(),
+            });
+        }
+        let url = self
+            .config_value(&format!("remote.{name}.url"))?
+            .ok_or_else(|| GitError::RemoteWithoutUrl {
+                name: name.to_string(),
+            })?;
+        Ok(rewrite_url(&url, &self.url_rewrites()?))
+    }
+
+    fn url_rewrites(&self) -> Result<Vec<UrlRewrite>> {
+        let repo = self.repo();
+        let config = repo.config_snapshot();
+        let Some(sections) = config.plumbing().sections_by_name("url") else {
+            return Ok(Vec::new());
+        };
+
+        let mut rewrites = Vec::new();
+        for section in sections {
+            let Some(base) = section.header().subsection_name() else {
+                continue;
+            };
+            for instead_of in section.values("insteadOf") {
+                rewrites.push(UrlRewrite {
+                    base: base.to_str_lossy().into_owned(),
+                    instead_of: instead_of.to_str_lossy().into_owned(),
+                });
+            }
+        }
+        Ok(rewrites)
+    }
+
+    fn remote_branch_exists(&self, remote: &str, branch: &str) -> Result<bool> {
+        let repo = self.repo();
+        let reference = format!("refs/heads/{branch}");
+        let spec = gix::refspec::parse(
+            reference.as_str().into(),
+            gix::refspec::parse::Operation::Fetch,
+        )
+        .map_err(GitError::gix("parse refspec"))?
+        .to_owned();
+
+        let (ref_map, _) = repo
+            .find_fetch_remote(Some(remote.into()))
+            .map_err(|_| GitError::RemoteNotFound {
+                name: remote.to_string(),
+            })?
+            .connect(gix::remote::Direction::Fetch)
+            .map_err(GitError::gix("connect to remote"))?
+            .ref_map(
+                gix::progress::Discard,
+                gix::remote::ref_map::Options {
+                    extra_refspecs: vec![spec],
+                    ..Default::default()
+                },
+            )
+            .map_err(GitError::gix("list remote references"))?;
+
+        Ok(ref_map
+            .remote_refs
+            .iter()
+            .any(|remote_ref| remote_ref.unpack().0 == reference.as_str()))
+    }
+
+    fn push(&self, remote: &str, branch: &str) -> Result<()> {
+        // `gix` cannot push yet, so pushes run `git push`, with the user's credential helpers
+        // and `pre-push` hooks.
+        self.cli.push(remote, branch)
+    }
+
+    fn config_value(&self, key: &str) -> Result<Option<String>> {
+        Ok(self
+            .repo()
+            .config_snapshot()
+            .string(key)
+            .map(|value| value.to_str_lossy().into_owned())
+            .filter(|value| !value.is_empty()))
+    }
+}
+
+impl std::fmt::Debug for GixRepository {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("GixRepository")
+            .field("root", &self.root())
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,643 @@
+//! Access to the Git repository seal releases from.
+//!
+//! [`Repository`] abstracts the operations seal needs. [`GixRepository`] works in process with
+//! `gix`, running the `git` binary only to push, and [`CliRepository`] runs the `git` binary.
+//! Set `SEAL_GIT_BACKEND=cli` to use the `git` binary for everything.
+
+use std::path::{Path, PathBuf};
+
+mod cli;
+mod error;
+mod gitoxide;
+mod sign;
+
+pub use cli::CliRepository;
+pub use error::GitError;
+pub use gitoxide::GixRepository;
+
+pub type Result<T, E = GitError> = std::result::Result<T, E>;
+
+/// Environment variable that selects the [`Backend`].
+pub const BACKEND_ENV_VAR: &str = "SEAL_GIT_BACKEND";
+
+/// The implementation behind a [`Repository`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Backend {
+    /// Work in process with `gix`, and run the `git` binary for pushes.
+    #[default]
+    Gix,
+    /// Run the `git` binary for every operation.
+    Cli,
+}
+
+impl Backend {
+    /// The backend selected by `SEAL_GIT_BACKEND`, defaulting to [`Backend::Gix`].
+    pub fn from_env() -> Self {
+        match std::env::var(BACKEND_ENV_VAR) {
+            Ok(value) if value.eq_ignore_ascii_case("cli") => Self::Cli,
+            _ => Self::Gix,
+        }
+    }
+}
+
+/// An identity recorded on a commit.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Signature {
+    pub name: String,
+    pub email: String,
+}
+
+/// How to create a commit.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CommitOptions {
+    /// Sign the commit, using this value as Git's `gpg.format` (`openpgp` or `ssh`).
+    pub signing_format: Option<String>,
+    /// Override the commit author.
+    pub author: Option<Signature>,
+    /// Override the committer.
+    pub committer: Option<Signature>,
+}
+
+/// A `url.<base>.insteadOf` rule: URLs starting with `instead_of` are rewritten to start with
+/// `base` instead.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UrlRewrite {
+    pub base: String,
+    pub instead_of: String,
+}
+
+/// Apply the rewrite with the longest matching prefix to `url`, as Git does.
+pub fn rewrite_url(url: &str, rewrites: &[UrlRewrite]) -> String {
+    let mut best: Option<&UrlRewrite> = None;
+    for rewrite in rewrites {
+        if url.starts_with(&rewrite.instead_of)
+            && best.is_none_or(|best| rewrite.instead_of.len() > best.instead_of.len())
+        {
+            best = Some(rewrite);
+        }
+    }
+
+    match best {
+        Some(rewrite) => format!("{}{}", rewrite.base, &url[rewrite.instead_of.len()..]),
+        None => url.to_string(),
+    }
+}
+
+/// The Git operations seal performs on a repository.
+pub trait Repository: Send + Sync {
+    /// The root of the working tree.
+    fn root(&self) -> &Path;
+
+    /// The short name of the branch `HEAD` points to.
+    ///
+    /// Returns [`GitError::DetachedHead`] when `HEAD` is not on a branch.
+    fn current_branch(&self) -> Result<String>;
+
+    /// Paths with staged, unstaged, or untracked changes, relative to the root, sorted.
+    fn status(&self) -> Result<Vec<String>>;
+
+    /// Whether [`Repository::status`] reports any changes.
+    fn is_dirty(&self) -> Result<bool> {
+        Ok(!self.status()?.is_empty())
+    }
+
+    /// Whether the full reference name (e.g. `refs/heads/main`) exists.
+    fn reference_exists(&self, name: &str) -> Result<bool>;
+
+    /// Whether the local branch `name` exists.
+    fn branch_exists(&self, name: &str) -> Result<bool> {
+        self.reference_exists(&format!("refs/heads/{name}"))
+    }
+
+    /// Whether the tag `name` exists.
+    fn tag_exists(&self, name: &str) -> Result<bool> {
+        self.reference_exists(&format!("refs/tags/{name}"))
+    }
+
+    /// Create the branch `name` at `HEAD` and switch to it, keeping the working tree as is.
+    fn create_branch(&self, name: &str) -> Result<()>;
+
+    /// Stage every change in the working tree, including untracked files.
+    fn stage_all(&self) -> Result<()>;
+
+    /// Commit the staged changes.
+    fn commit(&self, message: &str, options: &CommitOptions) -> Result<()>;
+
+    /// The number of commits reachable from `reference` but not from `HEAD`.
+    fn commits_behind(&self, reference: &str) -> Result<usize>;
+
+    /// The remote-tracking branch that the local branch `branch` tracks through
+    /// `branch.<name>.remote` and `branch.<name>.merge`, e.g. `refs/remotes/origin/main`, or
+    /// `None` if it has no upstream.
+    fn upstream(&self, branch: &str) -> Result<Option<String>>;
+
+    /// The names of the configured remotes.
+    fn remotes(&self) -> Result<Vec<String>>;
+
+    /// Whether the remote `name` is configured.
+    fn remote_exists(&self, name: &str) -> Result<bool> {
+        Ok(self.remotes()?.iter().any(|remote| remote == name))
+    }
+
+    /// The URL of the remote `name`, with `url.<base>.insteadOf` rewrites applied.
+    fn remote_url(&self, name: &str) -> Result<String>;
+
+    /// The configured `url.<base>.insteadOf` rules.
+    fn url_rewrites(&self) -> Result<Vec<UrlRewrite>>;
+
+    /// Whether `branch` exists on `remote`. This contacts the remote.
+    fn remote_branch_exists(&self, remote: &str, branch: &str) -> Result<bool>;
+
+    /// Push `branch` to `remote`.
+    fn push(&self, remote: &str, branch: &str) -> Result<()>;
+
+    /// The value of a Git configuration key, or `None` if it is unset or empty.
+    fn config_value(&self, key: &str) -> Result<Option<String>>;
+}
+
+/// Open the repository containing `path` with the backend selected by `SEAL_GIT_BACKEND`.
+pub fn open(path: &Path) -> Result<Box<dyn Repository>> {
+    open_with(path, Backend::from_env())
+}
+
+/// Open the repository containing `path` with the given backend.
+pub fn open_with(path: &Path, backend: Backend) -> Result<Box<dyn Repository>> {
+    Ok(match backend {
+        Backend::Gix => Box::new(GixRepository::open(path)?),
+        Backend::Cli => Box::new(CliRepository::open(path)?),
+    })
+}
+
+/// The root of the working tree containing `path`.
+pub fn discover_root(path: &Path) -> Result<PathBuf> {
+    Ok(open(path)?.root().to_path_buf())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+    use std::process::Command;
+
+    use tempfile::TempDir;
+
+    use super::*;
+
+    fn git(dir: &Path, args: &[&str]) -> String {
+        let output = Command::new("git")
+            .args(args)
+            .current_dir(dir)
+            .output()
+            .unwrap();
+        assert!(
+            output.status.success(),
+            "`git {}` failed: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr)
+        );
+        String::from_utf8(output.stdout).unwrap().trim().to_string()
+    }
+
+    fn init_repo() -> TempDir {
+        let temp = TempDir::new().unwrap();
+        git(temp.path(), &["init", "-b", "main"]);
+        git(temp.path(), &["config", "user.email", "test@example.com"]);
+        git(temp.path(), &["config", "user.name", "Test User"]);
+        temp
+    }
+
+    fn init_repo_with_commit() -> TempDir {
+        let temp = init_repo();
+        fs_write(temp.path(), "README.md", "# Test");
+        git(temp.path(), &["add", "-A"]);
+        git(temp.path(), &["commit", "-m", "Initial commit"]);
+        temp
+    }
+
+    fn fs_write(dir: &Path, path: &str, content: &str) {
+        let path = dir.join(path);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, content).unwrap();
+    }
+
+    fn add_remote(dir: &Path, name: &str) -> PathBuf {
+        let remote = dir.join(format!(".git/{name}.git"));
+        git(dir, &["init", "--bare", remote.to_str().unwrap()]);
+        git(dir, &["remote", "add", name, remote.to_str().unwrap()]);
+        remote
+    }
+
+    #[test]
+    fn rewrite_url_prefers_the_longest_match() {
+        let rewrite = |base: &str, instead_of: &str| UrlRewrite {
+            base: base.to_string(),
+            instead_of: instead_of.to_string(),
+        };
+        let rewrites = [
+            rewrite("https://a.example.com/", "work:"),
+            rewrite("https://b.example.com/", "work:team/"),
+            rewrite("https://c.example.com/", "work:"),
+        ];
+
+        assert_eq!(
+            rewrite_url("work:team/repo", &rewrites),
+            "https://b.example.com/repo"
+        );
+        assert_eq!(
+            rewrite_url("work:other/repo", &rewrites),
+            "https://a.example.com/other/repo"
+        );
+        assert_eq!(
+            rewrite_url("https://github.com/o/r", &rewrites),
+            "https://github.com/o/r"
+        );
+    }
+
+    /// Tests run against every backend, so both behave the same.
+    macro_rules! backend_tests {
+        ($module:ident, $backend:expr) => {
+            mod $module {
+                use super::*;
+
+                fn open(temp: &TempDir) -> Box<dyn Repository> {
+                    open_with(temp.path(), $backend).unwrap()
+                }
+
+                #[test]
+                fn open_finds_root_from_subdirectory() {
+                    let temp = init_repo();
+                    let subdir = temp.path().join("subdir");
+                    std::fs::create_dir(&subdir).unwrap();
+
+                    let repository = open_with(&subdir, $backend).unwrap();
+
+                    assert_eq!(
+                        dunce::canonicalize(repository.root()).unwrap(),
+                        dunce::canonicalize(temp.path()).unwrap()
+                    );
+                }
+
+                #[test]
+                fn open_outside_repository() {
+                    let temp = TempDir::new().unwrap();
+                    let error = open_with(temp.path(), $backend).err().unwrap();
+                    assert!(matches!(error, GitError::NotARepository { .. }));
+                }
+
+                #[test]
+                fn current_branch() {
+                    let temp = init_repo();
+                    assert_eq!(open(&temp).current_branch().unwrap(), "main");
+                }
+
+                #[test]
+                fn current_branch_detached_head() {
+                    let temp = init_repo_with_commit();
+                    git(temp.path(), &["checkout", "--detach"]);
+
+                    let error = open(&temp).current_branch().unwrap_err();
+                    assert!(matches!(error, GitError::DetachedHead));
+                }
+
+                #[test]
+                fn status_lists_changed_and_untracked_files() {
+                    let temp = init_repo_with_commit();
+                    let repository = open(&temp);
+                    assert!(!repository.is_dirty().unwrap());
+
+                    fs_write(temp.path(), "README.md", "# Changed");
+                    fs_write(temp.path(), "notes.txt", "new");
+                    fs_write(temp.path(), "staged.txt", "staged");
+                    git(temp.path(), &["add", "staged.txt"]);
+
+                    assert_eq!(
+                        repository.status().unwrap(),
+                        ["README.md", "notes.txt", "staged.txt"]
+                    );
+                    assert!(repository.is_dirty().unwrap());
+                }
+
+                #[test]
+                fn references() {
+                    let temp = init_repo_with_commit();
+                    git(temp.path(), &["branch", "feature"]);
+                    git(temp.path(), &["tag", "v1.0.0"]);
+                    let repository = open(&temp);
+
+                    assert!(repository.branch_exists("feature").unwrap());
+                    assert!(!repository.branch_exists("missing").unwrap());
+                    assert!(repository.tag_exists("v1.0.0").unwrap());
+                    assert!(!repository.tag_exists("v2.0.0").unwrap());
+                }
+
+                #[test]
+                fn create_branch_switches_to_it() {
+                    let temp = init_repo_with_commit();
+                    fs_write(temp.path(), "README.md", "# Changed");
+                    let repository = open(&temp);
+
+                    repository.create_branch("release/v1.0.0").unwrap();
+
+                    assert_eq!(
+                        git(temp.path(), &["branch", "--show-current"]),
+                        "release/v1.0.0"
+                    );
+                    assert_eq!(
+                        std::fs::read_to_string(temp.path().join("README.md")).unwrap(),
+                        "# Changed"
+                    );
+
+                    let error = repository.create_branch("release/v1.0.0").unwrap_err();
+                    assert!(error.to_string().contains("release/v1.0.0"));
+                }
+
+                #[test]
+                fn create_branch_on_unborn_head() {
+                    let temp = init_repo();
+                    let repository = open(&temp);
+
+                    repository.create_branch("release/v1.0.0").unwrap();
+
+                    assert_eq!(repository.current_branch().unwrap(), "release/v1.0.0");
+                }
+
+                #[test]
+                fn stage_and_commit() {
+                    let temp = init_repo_with_commit();
+                    fs_write(temp.path(), "notes.txt", "new");
+                    let repository = open(&temp);
+
+                    repository.stage_all().unwrap();
+                    repository
+                        .commit(
+                            "Release v1.0.0",
+                            &CommitOptions {
+                                author: Some(Signature {
+                                    name: "release-bot".to_string(),
+                                    email: "bot@example.com".to_string(),
+                                }),
+                                ..CommitOptions::default()
+                            },
+                        )
+                        .unwrap();
+
+                    assert!(!repository.is_dirty().unwrap());
+                    assert_eq!(
+                        git(temp.path(), &["log", "-1", "--format=%an <%ae> / %cn"]),
+                        "release-bot <bot@example.com> / Test User"
+                    );
+                    assert_eq!(
+                        git(temp.path(), &["log", "--format=%s"]),
+                        "Release v1.0.0\nInitial commit"
+                    );
+                }
+
+                #[test]
+                fn stage_all_stages_modifications_deletions_and_new_files() {
+                    let temp = init_repo_with_commit();
+                    fs_write(temp.path(), "src/lib.rs", "fn main() {}");
+                    fs_write(temp.path(), ".gitignore", "*.log\n");
+                    git(temp.path(), &["add", "-A"]);
+                    git(temp.path(), &["commit", "-m", "Add sources"]);
+
+                    fs_write(temp.path(), "README.md", "# Changed");
+                    std::fs::remove_file(temp.path().join("src/lib.rs")).unwrap();
+                    fs_write(temp.path(), "docs/guide.md", "Guide");
+                    fs_write(temp.path(), "debug.log", "ignored");
+                    let repository = open(&temp);
+
+                    repository.stage_all().unwrap();
+
+                    assert_eq!(
+                        git(temp.path(), &["diff", "--cached", "--name-status"]),
+                        "M\tREADME.md\nA\tdocs/guide.md\nD\tsrc/lib.rs"
+                    );
+                    assert_eq!(git(temp.path(), &["diff", "--name-only"]), "");
+                    assert_eq!(git(temp.path(), &["show", ":docs/guide.md"]), "Guide");
+                }
+
+                #[test]
+                fn commit_without_changes() {
+                    let temp = init_repo_with_commit();
+                    let repository = open(&temp);
+
+                    let error = repository
+                        .commit("Nothing to commit", &CommitOptions::default())
+                        .unwrap_err();
+
+                    assert!(matches!(error, GitError::NothingToCommit));
+                    assert_eq!(git(temp.path(), &["rev-list", "--count", "HEAD"]), "1");
+                }
+
+                #[test]
+                fn commit_on_unborn_head() {
+                    let temp = init_repo();
+                    fs_write(temp.path(), "README.md", "# Test");
+                    let repository = open(&temp);
+
+                    repository.stage_all().unwrap();
+                    repository
+                        .commit("Initial commit", &CommitOptions::default())
+                        .unwrap();
+
+                    assert_eq!(
+                        git(temp.path(), &["log", "--format=%s%n%b"]),
+                        "Initial commit"
+                    );
+                    assert_eq!(git(temp.path(), &["ls-files"]), "README.md");
+                }
+
+                #[test]
+                fn commit_signed_with_ssh_key() {
+                    if Command::new("ssh-keygen").arg("-?").output().is_err() {
+                        return;
+                    }
+                    let temp = init_repo_with_commit();
+                    let key = temp.path().join(".git/signing_key");
+                    let output = Command::new("ssh-keygen")
+                        .args(["-q", "-t", "ed25519", "-N", "", "-C", "test", "-f"])
+                        .arg(&key)
+                        .output()
+                        .unwrap();
+                    assert!(output.status.success());
+                    git(
+                        temp.path(),
+                        &["config", "user.signingkey", key.to_str().unwrap()],
+                    );
+                    fs_write(temp.path(), "notes.txt", "new");
+                    let repository = open(&temp);
+
+                    repository.stage_all().unwrap();
+                    repository
+                        .commit(
+                            "Signed",
+                            &CommitOptions {
+                                signing_format: Some("ssh".to_string()),
+                                ..CommitOptions::default()
+                            },
+                        )
+                        .unwrap();
+
+                    let commit = git(temp.path(), &["cat-file", "commit", "HEAD"]);
+                    assert!(commit.contains("gpgsig -----BEGIN SSH SIGNATURE-----"));
+                    assert!(commit.ends_with("-----END SSH SIGNATURE-----\n\nSigned"));
+
+                    let public_key = std::fs::read_to_string(key.with_extension("pub")).unwrap();
+                    fs_write(
+                        temp.path(),
+                        ".git/allowed_signers",
+                        &format!("test@example.com {public_key}"),
+                    );
+                    git(
+                        temp.path(),
+                        &[
+                            "config",
+                            "gpg.ssh.allowedSignersFile",
+                            ".git/allowed_signers",
+                        ],
+                    );
+                    git(temp.path(), &["verify-commit", "HEAD"]);
+                }
+
+                #[test]
+                fn remotes() {
+                    let temp = init_repo_with_commit();
+                    git(
+                        temp.path(),
+                        &[
+                            "remote",
+                            "add",
+                            "upstream",
+                            "https://github.com/owner/repo.git",
+                        ],
+                    );
+                    let repository = open(&temp);
+
+                    assert_eq!(repository.remotes().unwrap(), ["upstream"]);
+                    assert!(repository.remote_exists("upstream").unwrap());
+                    assert!(!repository.remote_exists("origin").unwrap());
+                    assert_eq!(
+                        repository.remote_url("upstream").unwrap(),
+                        "https://github.com/owner/repo.git"
+                    );
+                    assert!(matches!(
+                        repository.remote_url("origin").unwrap_err(),
+                        GitError::RemoteNotFound { .. }
+                    ));
+                }
+
+                #[test]
+                fn remote_url_applies_instead_of_rewrites() {
+                    let temp = init_repo_with_commit();
+                    git(
+                        temp.path(),
+                        &["remote", "add", "origin", "gh:owner/repo.git"],
+                    );
+                    git(
+                        temp.path(),
+                        &["config", "url.git@github.com:.insteadOf", "gh:"],
+                    );
+                    git(
+                        temp.path(),
+                        &[
+                            "config",
+                            "url.https://github.example.com/.insteadOf",
+                            "gh:enterprise/",
+                        ],
+                    );
+                    let repository = open(&temp);
+
+                    assert_eq!(
+                        repository.url_rewrites().unwrap(),
+                        [
+                            UrlRewrite {
+                                base: "git@github.com:".to_string(),
+                                instead_of: "gh:".to_string(),
+                            },
+                            UrlRewrite {
+                                base: "https://github.example.com/".to_string(),
+                                instead_of: "gh:enterprise/".to_string(),
+                            },
+                        ]
+                    );
+                    assert_eq!(
+                        repository.remote_url("origin").unwrap(),
+                        "git@github.com:owner/repo.git"
+                    );
+
+                    git(
+                        temp.path(),
+                        &["remote", "set-url", "origin", "gh:enterprise/owner/repo"],
+                    );
+                    assert_eq!(
+                        open(&temp).remote_url("origin").unwrap(),
+                        "https://github.example.com/owner/repo"
+                    );
+                }
+
+                #[test]
+                fn push_and_compare_with_remote() {
+                    let temp = init_repo_with_commit();
+                    add_remote(temp.path(), "origin");
+                    let repository = open(&temp);
+
+                    assert!(!repository.remote_branch_exists("origin", "main").unwrap());
+                    repository.push("origin", "main").unwrap();
+                    assert!(repository.remote_branch_exists("origin", "main").unwrap());
+
+                    git(temp.path(), &["fetch", "origin"]);
+                    git(temp.path(), &["commit", "--allow-empty", "-m", "Second"]);
+                    git(temp.path(), &["push", "origin", "main"]);
+                    git(temp.path(), &["reset", "--hard", "HEAD~1"]);
+
+                    assert_eq!(
+                        repository
+                            .commits_behind("refs/remotes/origin/main")
+                            .unwrap(),
+                        1
+                    );
+                    assert_eq!(repository.commits_behind("HEAD").unwrap(), 0);
+                }
+
+                #[test]
+                fn upstream_follows_branch_configuration() {
+                    let temp = init_repo_with_commit();
+                    add_remote(temp.path(), "origin");
+                    assert_eq!(open(&temp).upstream("main").unwrap(), None);
+
+                    git(temp.path(), &["push", "origin", "main:trunk"]);
+                    git(temp.path(), &["fetch", "origin"]);
+                    git(
+                        temp.path(),
+                        &["branch", "--set-upstream-to", "origin/trunk"],
+                    );
+
+                    assert_eq!(
+                        open(&temp).upstream("main").unwrap().as_deref(),
+                        Some("refs/remotes/origin/trunk")
+                    );
+                }
+
+                #[test]
+                fn config_value() {
+                    let temp = init_repo();
+                    git(temp.path(), &["config", "user.signingkey", "ABC123"]);
+                    git(temp.path(), &["config", "gpg.program", ""]);
+                    let repository = open(&temp);
+
+                    assert_eq!(
+                        repository
+                            .config_value("user.signingkey")
+                            .unwrap()
+                            .as_deref(),
+                        Some("ABC123")
+                    );
+                    assert_eq!(repository.config_value("gpg.program").unwrap(), None);
+                    assert_eq!(repository.config_value("seal.missing").unwrap(), None);
+                }
+            }
+        };
+    }
+
+    backend_tests!(cli_backend, Backend::Cli);
+    backend_tests!(gix_backend, Backend::Gix);
+}
@@ -0,0 +1,109 @@
+//! Commit signing with the programs Git uses for `gpg.format`.
+
+use std::io::Write as _;
+use std::path::PathBuf;
+use std::process::{Command, Stdio};
+
+use crate::{GitError, Repository, Result};
+
+/// Sign `payload`, the serialized commit, the way `git commit -S` does with `gpg.format` set to
+/// `format`, and return the signature for the commit's `gpgsig` header.
+pub(crate) fn sign(
+    repository: &dyn Repository,
+    format: &str,
+    committer: &str,
+    payload: &[u8],
+) -> Result<String> {
+    match format {
+        "ssh" => sign_ssh(repository, payload),
+        _ => sign_openpgp(repository, committer, payload),
+    }
+}
+
+fn sign_openpgp(repository: &dyn Repository, committer: &str, payload: &[u8]) -> Result<String> {
+    let program = match repository.config_value("gpg.openpgp.program")? {
+        Some(program) => program,
+        None => repository
+            .config_value("gpg.program")?
+            .unwrap_or_else(|| "gpg".to_string()),
+    };
+    let key = repository
+        .config_value("user.signingkey")?
+        .unwrap_or_else(|| committer.to_string());
+
+    run(&program, &["--status-fd=2", "-bsau", &key], payload)
+}
+
+fn sign_ssh(repository: &dyn Repository, payload: &[u8]) -> Result<String> {
+    let program = repository
+        .config_value("gpg.ssh.program")?
+        .unwrap_or_else(|| "ssh-keygen".to_string());
+    let key = repository
+        .config_value("user.signingkey")?
+        .ok_or(GitError::MissingSigningKey)?;
+
+    // Like Git, a public key literal signs with the matching key in `ssh-agent`.
+    let literal = key
+        .strip_prefix("key::")
+        .or_else(|| key.starts_with("ssh-").then_some(key.as_str()));
+    let Some(literal) = literal else {
+        let key = expand_home(&key);
+        let key = key.to_string_lossy();
+        return run(&program, &["-Y", "sign", "-n", "git", "-f", &key], payload);
+    };
+
+    let spawn_failed = |source| GitError::SpawnProgram {
+        program: program.clone(),
+        source,
+    };
+    let mut key_file = tempfile::Builder::new()
+        .prefix("seal-signing-key-")
+        .suffix(".pub")
+        .tempfile()
+        .map_err(spawn_failed)?;
+    writeln!(key_file, "{literal}").map_err(spawn_failed)?;
+    let key = key_file.path().to_string_lossy().into_owned();
+    // The key file is removed when it is dropped, after `ssh-keygen` has signed.
+    run(
+        &program,
+        &["-Y", "sign", "-n", "git", "-f", &key, "-U"],
+        payload,
+    )
+}
+
+fn expand_home(path: &str) -> PathBuf {
+    match (path.strip_prefix("~/"), std::env::var_os("HOME")) {
+        (Some(rest), Some(home)) => PathBuf::from(home).join(rest),
+        _ => PathBuf::from(path),
+    }
+}
+
+/// Run `program` with `payload` on stdin and return its stdout.
+fn run(program: &str, args: &[&str], payload: &[u8]) -> Result<String> {
+    let spawn_failed = |source| GitError::SpawnProgram {
+        program: program.to_string(),
+        source,
+    };
+
+    let mut child = Command::new(program)
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(spawn_failed)?;
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin.write_all(payload).map_err(spawn_failed)?;
+    }
+    let output = child.wait_with_output().map_err(spawn_failed)?;
+
+    let signature = String::from_utf8(output.stdout).map_err(|_| GitError::InvalidUtf8)?;
+    if !output.status.success() || signature.trim().is_empty() {
+        return Err(GitError::CommandFailed {
+            command: format!("{program} {}", args.join(" ")),
+            exit_code: output.status.code(),
+            stderr: String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        });
+    }
+    Ok(signature)
+}
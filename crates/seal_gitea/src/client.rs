@@ -0,0 +1,827 @@
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use hyper::Method;
+use seal_forge::{
+    ForgeService, PullRequest, PullRequestOptions, PullRequestReference, Release, RestClient,
+    RestResponse,
+};
+use serde::Deserialize;
+use thiserror::Error;
+
+/// The default maximum page size of Gitea and Forgejo instances.
+const PAGE_LIMIT: &str = "50";
+/// Gitea marks pull requests as work in progress with a title prefix.
+const DRAFT_PREFIX: &str = "WIP: ";
+
+#[derive(Debug, Error)]
+pub enum GiteaError {
+    #[error("No releases found for {owner}/{repo}")]
+    NoReleasesFound { owner: String, repo: String },
+    #[error("Gitea authentication is required; set GITEA_TOKEN")]
+    AuthenticationRequired,
+}
+
+#[derive(Debug)]
+pub struct GiteaClient {
+    rest: RestClient,
+    base_url: String,
+    owner: String,
+    repo: String,
+    authenticated: bool,
+    /// The branch release pull requests are merged into, looked up as the repository's default
+    /// branch unless set with [`GiteaClient::with_base`].
+    base: tokio::sync::OnceCell<String>,
+    /// Whether to list the files each merged pull request changes, which takes a request per
+    /// pull request.
+    changed_files: bool,
+}
+
+#[derive(Debug, Deserialize)]
+struct ApiRepository {
+    default_branch: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ApiRelease {
+    name: Option<String>,
+    tag_name: String,
+    created_at: DateTime<Utc>,
+    #[serde(default)]
+    draft: bool,
+}
+
+#[derive(Debug, Deserialize)]
+struct ApiLabel {
+    name: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct ApiUser {
+    login: String,
+    /// Only returned by recent Gitea and Forgejo versions.
+    html_url: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ApiBranch {
+    #[serde(rename = "ref")]
+    name: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct ApiChangedFile {
+    filename: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct ApiPullRequest {
+    number: u64,
+    title: String,
+    html_url: String,
+    #[serde(default)]
+    labels: Vec<ApiLabel>,
+    user: Option<ApiUser>,
+    body: Option<String>,
+    merged_at: Option<DateTime<Utc>>,
+    updated_at: DateTime<Utc>,
+    head: ApiBranch,
+    base: ApiBranch,
+}
+
+impl GiteaClient {
+    /// Create a client for `owner/repo` on the Gitea or Forgejo instance at `base_url`
+    /// (e.g. `https://codeberg.org`).
+    pub fn new(base_url: &str, owner: &str, repo: &str) -> Result<Self> {
+        let token = std::env::var("GITEA_TOKEN")
+            .ok()
+            .filter(|token| !token.trim().is_empty());
+
+        Self::with_token(base_url, owner, repo, token)
+    }
+
+    fn with_token(base_url: &str, owner: &str, repo: &str, token: Option<String>) -> Result<Self> {
+        let base_url = base_url.trim_end_matches('/');
+        let mut rest = RestClient::new(&format!("{base_url}/api/v1"))?;
+        let authenticated = token.is_some();
+        if let Some(token) = token {
+            rest = rest.with_header("authorization", &format!("token {token}"))?;
+        }
+
+        Ok(Self {
+            rest,
+            base_url: base_url.to_string(),
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            authenticated,
+            base: tokio::sync::OnceCell::new(),
+            changed_files: false,
+        })
+    }
+
+    /// Only list pull requests merged into `base`, instead of the repository's default branch.
+    #[must_use]
+    pub fn with_base(self, base: Option<String>) -> Self {
+        Self {
+            base: tokio::sync::OnceCell::new_with(base),
+            ..self
+        }
+    }
+
+    /// List the files each merged pull request changes, for changelog areas with `paths`.
+    #[must_use]
+    pub fn with_changed_files(self, changed_files: bool) -> Self {
+        Self {
+            changed_files,
+            ..self
+        }
+    }
+
+    /// The branch whose merged pull requests are listed.
+    async fn base(&self) -> Result<&str> {
+        let base =
+            self.base
+                .get_or_try_init(|| async {
+                    let repository: RestResponse<ApiRepository> =
+                        self.rest.get(&self.repo_path(""), &[]).await.context(
+                            "Failed to look up the default branch of the Gitea repository",
+                        )?;
+                    repository
+                        .body
+                        .default_branch
+                        .context("Gitea did not report a default branch for the repository")
+                })
+                .await?;
+        Ok(base)
+    }
+
+    fn repo_path(&self, path: &str) -> String {
+        format!("/repos/{}/{}{path}", self.owner, self.repo)
+    }
+
+    /// Fetch every page of a paginated list, following the `Link` header.
+    async fn get_pages<T: serde::de::DeserializeOwned>(
+        &self,
+        path: &str,
+        query: &[(&str, &str)],
+        mut keep_going: impl FnMut(&[T]) -> bool,
+    ) -> Result<Vec<T>> {
+        let mut items = Vec::new();
+        let mut page = 1u32;
+
+        loop {
+            let page_number = page.to_string();
+            let mut page_query = query.to_vec();
+            page_query.extend([("limit", PAGE_LIMIT), ("page", page_number.as_str())]);
+            let response: RestResponse<Vec<T>> = self.rest.get(path, &page_query).await?;
+
+            let has_next = response.header("link").is_some_and(has_next_page);
+            let page_items = response.body;
+            let more = !page_items.is_empty() && keep_going(&page_items);
+            items.extend(page_items);
+
+            if !(has_next && more) {
+                break;
+            }
+            page += 1;
+        }
+
+        Ok(items)
+    }
+
+    fn to_pull_request(&self, pull_request: ApiPullRequest) -> Option<PullRequest> {
+        let merged_at = pull_request.merged_at?;
+        let (author, author_url) = match pull_request.user {
+            Some(user) => {
+                let url = user
+                    .html_url
+                    .unwrap_or_else(|| format!("{}/{}", self.base_url, user.login));
+                (Some(user.login), Some(url))
+            }
+            None => (None, None),
+        };
+
+        Some(PullRequest {
+            title: pull_request.title,
+            number: pull_request.number,
+            url: pull_request.html_url,
+            labels: pull_request
+                .labels
+                .into_iter()
+                .map(|label| label.name)
+                .collect(),
+            author,
+            author_url,
+            body: pull_request.body.filter(|body| !body.trim().is_empty()),
+            closed_issues: Vec::new(),
+            changed_files: Vec::new(),
+            merged_at,
+        })
+    }
+}
+
+/// Whether a `Link` header has a `rel="next"` entry.
+fn has_next_page(link: &str) -> bool {
+    link.split(',').any(|entry| {
+        entry
+            .split(';')
+            .any(|param| param.trim() == r#"rel="next""#)
+    })
+}
+
+fn pull_request_title(options: &PullRequestOptions) -> String {
+    if options.draft {
+        format!("{DRAFT_PREFIX}{}", options.title)
+    } else {
+        options.title.clone()
+    }
+}
+
+fn to_release(release: ApiRelease) -> Release {
+    Release {
+        created_at: release.created_at,
+        name: release
+            .name
+            .filter(|name| !name.is_empty())
+            .or(Some(release.tag_name)),
+    }
+}
+
+impl ForgeService for GiteaClient {
+    fn ensure_authenticated(&self) -> Result<()> {
+        if !self.authenticated {
+            return Err(GiteaError::AuthenticationRequired.into());
+        }
+
+        Ok(())
+    }
+
+    fn compare_url(&self, base: &str, head: &str) -> Option<String> {
+        Some(format!(
+            "{}/{}/{}/compare/{base}...{head}",
+            self.base_url, self.owner, self.repo
+        ))
+    }
+
+    fn pull_request_url(&self, number: u64) -> Option<String> {
+        Some(format!(
+            "{}/{}/{}/pulls/{number}",
+            self.base_url, self.owner, self.repo
+        ))
+    }
+
+    fn get_latest_release(
+        &self,
+    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<Release>> + Send + '_>> {
+        Box::pin(async move {
+            let releases: RestResponse<Vec<ApiRelease>> = self
+                .rest
+                .get(
+                    &self.repo_path("/releases"),
+                    &[("draft", "false"), ("limit", "1")],
+                )
+                .await?;
+
+            releases
+                .body
+                .into_iter()
+                .find(|release| !release.draft)
+                .map(to_release)
+                .ok_or_else(|| {
+                    GiteaError::NoReleasesFound {
+                        owner: self.owner.clone(),
+                        repo: self.repo.clone(),
+                    }
+                    .into()
+                })
+        })
+    }
+
+    fn get_all_releases(
+        &self,
+    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<Vec<Release>>> + Send + '_>>
+    {
+        Box::pin(async move {
+            let releases: Vec<ApiRelease> = self
+                .get_pages(&self.repo_path("/releases"), &[("draft", "false")], |_| {
+                    true
+                })
+                .await?;
+
+            let mut releases: Vec<_> = releases
+                .into_iter()
+                .filter(|release| !release.draft)
+                .map(to_release)
+                .collect();
+            releases.sort_by_key(|release| release.created_at);
+
+            Ok(releases)
+        })
+    }
+
+    fn get_prs_between(
+        &self,
+        since: Option<&DateTime<Utc>>,
+        until: Option<&DateTime<Utc>>,
+    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<Vec<PullRequest>>> + Send + '_>>
+    {
+        let since = since.copied();
+        let until = until.copied();
+        Box::pin(async move {
+            let base = self.base().await?;
+            // A pull request is always updated when it is merged, so stop at the first page that
+            // reaches pull requests last updated before `since`.
+            let pull_requests: Vec<ApiPullRequest> = self
+                .get_pages(
+                    &self.repo_path("/pulls"),
+                    &[("state", "closed"), ("sort", "recentupdate")],
+                    |page: &[ApiPullRequest]| {
+                        since.is_none_or(|since| page.iter().all(|pr| pr.updated_at > since))
+                    },
+                )
+                .await?;
+
+            // The API cannot filter by base branch.
+            let mut pull_requests: Vec<_> = pull_requests
+                .into_iter()
+                .filter(|pr| pr.base.name == base)
+                .filter_map(|pr| self.to_pull_request(pr))
+                .filter(|pr| since.is_none_or(|since| pr.merged_at > since))
+                .filter(|pr| until.is_none_or(|until| pr.merged_at <= until))
+                .collect();
+
+            if self.changed_files {
+                for pr in &mut pull_requests {
+                    let files: Vec<ApiChangedFile> = self
+                        .get_pages(
+                            &self.repo_path(&format!("/pulls/{}/files", pr.number)),
+                            &[],
+                            |_| true,
+                        )
+                        .await
+                        .with_context(|| {
+                            format!(
+                                "Failed to list the files changed by Gitea pull request #{}",
+                                pr.number
+                            )
+                        })?;
+                    pr.changed_files = files.into_iter().map(|file| file.filename).collect();
+                }
+            }
+
+            Ok(pull_requests)
+        })
+    }
+
+    fn create_or_update_pull_request(
+        &self,
+        options: PullRequestOptions,
+    ) -> std::pin::Pin<
+        Box<dyn std::future::Future<Output = Result<PullRequestReference>> + Send + '_>,
+    > {
+        Box::pin(async move {
+            self.ensure_authenticated()?;
+
+            let pulls_path = self.repo_path("/pulls");
+            let is_release_pr =
+                |pr: &ApiPullRequest| pr.head.name == options.head && pr.base.name == options.base;
+            let open: Vec<ApiPullRequest> = self
+                .get_pages(&pulls_path, &[("state", "open")], |page| {
+                    !page.iter().any(is_release_pr)
+                })
+                .await
+                .context("Failed to find an existing Gitea pull request")?;
+
+            let title = pull_request_title(&options);
+            let pull_request: ApiPullRequest =
+                if let Some(existing) = open.iter().find(|pr| is_release_pr(pr)) {
+                    self.rest
+                        .send(
+                            Method::PATCH,
+                            &format!("{pulls_path}/{}", existing.number),
+                            &serde_json::json!({
+                                "title": title,
+                                "body": options.body,
+                            }),
+                        )
+                        .await
+                        .context("Failed to update Gitea pull request")?
+                } else {
+                    self.rest
+                        .send(
+                            Method::POST,
+                            &pulls_path,
+                            &serde_json::json!({
+                                "head": options.head,
+                                "base": options.base,
+                                "title": title,
+                                "body": options.body,
+                            }),
+                        )
+                        .await
+                        .context("Failed to create Gitea pull request")?
+                };
+
+            Ok(PullRequestReference {
+                url: pull_request.html_url,
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use chrono::{DateTime, Utc};
+    use seal_forge::{ForgeService, PullRequestOptions};
+    use serde_json::{Value, json};
+    use wiremock::{
+        Mock, MockServer, ResponseTemplate,
+        matchers::{body_json, header, method, path, query_param},
+    };
+
+    use super::{GiteaClient, GiteaError, has_next_page};
+
+    const REPO_API_PATH: &str = "/api/v1/repos/owner/repo";
+    const HEAD: &str = "release/v1.2.4";
+    const BASE: &str = "main";
+    const TOKEN: &str = "gitea-test";
+
+    fn test_client(server: &MockServer, authenticated: bool) -> Result<GiteaClient> {
+        Ok(GiteaClient::with_token(
+            &server.uri(),
+            "owner",
+            "repo",
+            authenticated.then(|| TOKEN.to_string()),
+        )?
+        .with_base(Some(BASE.to_string())))
+    }
+
+    fn options(draft: bool) -> PullRequestOptions {
+        PullRequestOptions {
+            title: "Release v1.2.4".to_string(),
+            body: "Release notes".to_string(),
+            head: HEAD.to_string(),
+            base: BASE.to_string(),
+            draft,
+        }
+    }
+
+    fn pull_request(number: u64, merged_at: Option<&str>, updated_at: &str) -> Value {
+        pull_request_into(BASE, number, merged_at, updated_at)
+    }
+
+    fn pull_request_into(
+        base: &str,
+        number: u64,
+        merged_at: Option<&str>,
+        updated_at: &str,
+    ) -> Value {
+        json!({
+            "number": number,
+            "title": format!("Pull request {number}"),
+            "html_url": format!("https://git.example.com/owner/repo/pulls/{number}"),
+            "labels": [{ "name": "bug" }],
+            "user": { "login": "alice" },
+            "merged_at": merged_at,
+            "updated_at": updated_at,
+            "head": { "ref": HEAD },
+            "base": { "ref": base },
+        })
+    }
+
+    fn release(name: &str, tag_name: &str, created_at: &str) -> Value {
+        json!({ "name": name, "tag_name": tag_name, "created_at": created_at, "draft": false })
+    }
+
+    async fn mount_page(server: &MockServer, route: &str, page: u32, next: bool, body: Value) {
+        let mut response = ResponseTemplate::new(200).set_body_json(body);
+        if next {
+            response = response.insert_header(
+                "link",
+                format!(
+                    r#"<{}{REPO_API_PATH}{route}?page={}>; rel="next""#,
+                    server.uri(),
+                    page + 1
+                ),
+            );
+        }
+        Mock::given(method("GET"))
+            .and(path(format!("{REPO_API_PATH}{route}")))
+            .and(query_param("limit", "50"))
+            .and(query_param("page", page.to_string()))
+            .respond_with(response)
+            .expect(1)
+            .mount(server)
+            .await;
+    }
+
+    #[test]
+    fn detects_next_page_links() {
+        assert!(has_next_page(
+            r#"<https://x/api/v1/a?page=2>; rel="next",<https://x/api/v1/a?page=3>; rel="last""#
+        ));
+        assert!(!has_next_page(
+            r#"<https://x/api/v1/a?page=1>; rel="first""#
+        ));
+    }
+
+    #[test]
+    fn builds_web_urls() -> Result<()> {
+        let client = GiteaClient::with_token("https://codeberg.org/", "owner", "repo", None)?;
+
+        assert_eq!(
+            client.compare_url("v1.0.0", "v1.1.0").as_deref(),
+            Some("https://codeberg.org/owner/repo/compare/v1.0.0...v1.1.0")
+        );
+        assert_eq!(
+            client.pull_request_url(7).as_deref(),
+            Some("https://codeberg.org/owner/repo/pulls/7")
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn unauthenticated_write_fails_without_requests() -> Result<()> {
+        let server = MockServer::start().await;
+        let client = test_client(&server, false)?;
+
+        let error = client
+            .create_or_update_pull_request(options(false))
+            .await
+            .expect_err("unauthenticated write should fail");
+
+        assert!(matches!(
+            error.downcast_ref::<GiteaError>(),
+            Some(GiteaError::AuthenticationRequired)
+        ));
+        assert!(server.received_requests().await.unwrap().is_empty());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn gets_all_releases_across_pages() -> Result<()> {
+        let server = MockServer::start().await;
+        mount_page(
+            &server,
+            "/releases",
+            1,
+            true,
+            json!([release("v1.0.0", "v1.0.0", "2025-12-01T00:00:00Z")]),
+        )
+        .await;
+        mount_page(
+            &server,
+            "/releases",
+            2,
+            false,
+            json!([release("", "0.2.0", "2025-11-15T00:00:00Z")]),
+        )
+        .await;
+
+        let releases = test_client(&server, true)?.get_all_releases().await?;
+
+        let names: Vec<_> = releases
+            .iter()
+            .map(|release| release.name.as_deref())
+            .collect();
+        assert_eq!(names, [Some("0.2.0"), Some("v1.0.0")]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn latest_release_requires_a_release() -> Result<()> {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path(format!("{REPO_API_PATH}/releases")))
+            .and(query_param("limit", "1"))
+            .and(header("authorization", "token gitea-test"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([])))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let error = test_client(&server, true)?
+            .get_latest_release()
+            .await
+            .expect_err("missing release should fail");
+
+        assert_eq!(error.to_string(), "No releases found for owner/repo");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn gets_pull_requests_merged_in_range() -> Result<()> {
+        let server = MockServer::start().await;
+        mount_page(
+            &server,
+            "/pulls",
+            1,
+            true,
+            json!([
+                pull_request(3, Some("2026-02-10T00:00:00Z"), "2026-02-10T00:00:00Z"),
+                pull_request(2, Some("2026-01-15T00:00:00Z"), "2026-01-15T00:00:00Z"),
+                pull_request_into(
+                    "feature",
+                    5,
+                    Some("2026-01-14T00:00:00Z"),
+                    "2026-01-14T00:00:00Z",
+                ),
+                pull_request(4, None, "2026-01-12T00:00:00Z"),
+                pull_request(1, Some("2025-12-25T00:00:00Z"), "2025-12-25T00:00:00Z"),
+            ]),
+        )
+        .await;
+
+        let since = "2026-01-01T00:00:00Z".parse::<DateTime<Utc>>()?;
+        let until = "2026-02-01T00:00:00Z".parse::<DateTime<Utc>>()?;
+        let pull_requests = test_client(&server, true)?
+            .get_prs_between(Some(&since), Some(&until))
+            .await?;
+
+        assert_eq!(pull_requests.len(), 1);
+        let pull_request = &pull_requests[0];
+        assert_eq!(pull_request.number, 2);
+        assert_eq!(pull_request.labels, ["bug"]);
+        assert_eq!(pull_request.author.as_deref(), Some("alice"));
+        assert_eq!(
+            pull_request.author_url,
+            Some(format!("{}/alice", server.uri()))
+        );
+        assert_eq!(
+            pull_request.url,
+            "https://git.example.com/owner/repo/pulls/2"
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn lists_the_files_changed_by_merged_pull_requests() -> Result<()> {
+        let server = MockServer::start().await;
+        mount_page(
+            &server,
+            "/pulls",
+            1,
+            false,
+            json!([pull_request(
+                2,
+                Some("2026-01-15T00:00:00Z"),
+                "2026-01-15T00:00:00Z"
+            )]),
+        )
+        .await;
+        mount_page(
+            &server,
+            "/pulls/2/files",
+            1,
+            true,
+            json!([{ "filename": "src/lib.rs" }]),
+        )
+        .await;
+        mount_page(
+            &server,
+            "/pulls/2/files",
+            2,
+            false,
+            json!([{ "filename": "README.md" }]),
+        )
+        .await;
+
+        let pull_requests = test_client(&server, true)?
+            .with_changed_files(true)
+            .get_prs_between(None, None)
+            .await?;
+
+        assert_eq!(pull_requests[0].changed_files, ["src/lib.rs", "README.md"]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn lists_pull_requests_into_the_default_branch_without_a_configured_base() -> Result<()> {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path(REPO_API_PATH))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(json!({ "default_branch": "develop" })),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path(format!("{REPO_API_PATH}/pulls")))
+            .and(query_param("state", "closed"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+                pull_request_into(
+                    "develop",
+                    2,
+                    Some("2026-01-15T00:00:00Z"),
+                    "2026-01-15T00:00:00Z"
+                ),
+                pull_request(1, Some("2026-01-14T00:00:00Z"), "2026-01-14T00:00:00Z"),
+            ])))
+            .expect(2)
+            .mount(&server)
+            .await;
+
+        let client = test_client(&server, true)?.with_base(None);
+        for _ in 0..2 {
+            let pull_requests = client.get_prs_between(None, None).await?;
+            let numbers: Vec<_> = pull_requests.iter().map(|pr| pr.number).collect();
+            assert_eq!(numbers, [2]);
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn creates_draft_pull_request_when_none_exists() -> Result<()> {
+        let server = MockServer::start().await;
+        mount_page(&server, "/pulls", 1, false, json!([])).await;
+        Mock::given(method("POST"))
+            .and(path(format!("{REPO_API_PATH}/pulls")))
+            .and(body_json(json!({
+                "head": HEAD,
+                "base": BASE,
+                "title": "WIP: Release v1.2.4",
+                "body": "Release notes",
+            })))
+            .respond_with(ResponseTemplate::new(201).set_body_json(pull_request(
+                9,
+                None,
+                "2026-01-15T00:00:00Z",
+            )))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let pull_request = test_client(&server, true)?
+            .create_or_update_pull_request(options(true))
+            .await?;
+
+        assert_eq!(
+            pull_request.url,
+            "https://git.example.com/owner/repo/pulls/9"
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn updates_existing_pull_request() -> Result<()> {
+        let server = MockServer::start().await;
+        mount_page(
+            &server,
+            "/pulls",
+            1,
+            false,
+            json!([pull_request(8, None, "2026-01-15T00:00:00Z")]),
+        )
+        .await;
+        Mock::given(method("PATCH"))
+            .and(path(format!("{REPO_API_PATH}/pulls/8")))
+            .and(body_json(json!({
+                "title": "Release v1.2.4",
+                "body": "Release notes",
+            })))
+            .respond_with(ResponseTemplate::new(201).set_body_json(pull_request(
+                8,
+                None,
+                "2026-01-15T00:00:00Z",
+            )))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let pull_request = test_client(&server, true)?
+            .create_or_update_pull_request(options(false))
+            .await?;
+
+        assert_eq!(
+            pull_request.url,
+            "https://git.example.com/owner/repo/pulls/8"
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn create_failure_has_stage_specific_context() -> Result<()> {
+        let server = MockServer::start().await;
+        mount_page(&server, "/pulls", 1, false, json!([])).await;
+        Mock::given(method("POST"))
+            .and(path(format!("{REPO_API_PATH}/pulls")))
+            .respond_with(
+                ResponseTemplate::new(409)
+                    .set_body_json(json!({ "message": "pull request already exists" })),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let error = test_client(&server, true)?
+            .create_or_update_pull_request(options(false))
+            .await
+            .expect_err("failed create request should return an error");
+        let message = format!("{error:#}");
+
+        assert!(message.contains("Failed to create Gitea pull request"));
+        assert!(message.contains("pull request already exists"));
+        Ok(())
+    }
+}
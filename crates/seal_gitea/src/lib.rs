@@ -0,0 +1,5 @@
+//! Gitea and Forgejo implementation of [`seal_forge::ForgeService`].
+
+mod client;
+
+pub use client::{GiteaClient, GiteaError};
@@ -1,56 +1,33 @@
-use anyhow::Result;
-use chrono::{DateTime, Utc};
 use thiserror::Error;
 
+mod auth;
+mod cache;
 mod client;
 mod mock;
+mod retry;
 
-pub use client::GitHubClient;
+pub use auth::{Credentials, GitHubToken, TokenSource, discover_token, token_env_vars};
+pub use client::{GitHubClient, TokenInfo};
 pub use mock::MockGithubClient;
-
-pub trait GitHubService: Send + Sync {
-    fn ensure_authenticated(&self) -> Result<()>;
-
-    fn get_latest_release(
-        &self,
-    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<GitHubRelease>> + Send + '_>>;
-
-    /// Get all releases for a repository.
-    ///
-    /// Sorted by creation date in ascending order.
-    fn get_all_releases(
-        &self,
-    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<Vec<GitHubRelease>>> + Send + '_>>;
-
-    fn get_prs_between(
-        &self,
-        since: Option<&DateTime<Utc>>,
-        until: Option<&DateTime<Utc>>,
-    ) -> std::pin::Pin<
-        Box<dyn std::future::Future<Output = Result<Vec<GitHubPullRequest>>> + Send + '_>,
-    >;
-
-    fn get_prs(
-        &self,
-        max: Option<usize>,
-    ) -> std::pin::Pin<
-        Box<dyn std::future::Future<Output = Result<Vec<GitHubPullRequest>>> + Send + '_>,
-    >;
-
-    fn create_or_update_pull_request(
-        &self,
-        options: GitHubPullRequestOptions,
-    ) -> std::pin::Pin<
-        Box<dyn std::future::Future<Output = Result<GitHubPullRequestReference>> + Send + '_>,
-    >;
-}
+pub use retry::RetryConfig;
 
 #[derive(Debug, Error)]
 pub enum GitHubError {
     #[error("No releases found for {owner}/{repo}")]
     NoReleasesFound { owner: String, repo: String },
-    #[error("GitHub authentication is required; set GITHUB_TOKEN or GH_TOKEN")]
+    #[error(
+        "GitHub authentication is required; set GITHUB_TOKEN or GH_TOKEN (GH_ENTERPRISE_TOKEN on \
+         GitHub Enterprise Server), log in with `gh auth login`, or set `github.token-command`"
+    )]
     AuthenticationRequired,
+    #[error("`github.token-command` (`{command}`) failed with {status}{details}")]
+    TokenCommandFailed {
+        command: String,
+        status: String,
+        details: String,
+    },
+    #[error("`github.token-command` (`{command}`) did not print a token")]
+    TokenCommandEmpty { command: String },
     #[error("GitHub did not return a browser URL for pull request #{number}")]
     MissingPullRequestUrl { number: u64 },
     #[error(
@@ -62,59 +39,32 @@ pub enum GitHubError {
         action: &'static str,
         errors: String,
     },
+    #[error(
+        "GitHub API rate limit exceeded with {}; retrying requires waiting {wait_secs}s, more than \
+         the maximum of {max_wait_secs}s set by `github.max-retry-wait`",
+        quota(*.remaining, *.limit)
+    )]
+    RateLimitWaitTooLong {
+        remaining: Option<u64>,
+        limit: Option<u64>,
+        wait_secs: u64,
+        max_wait_secs: u64,
+    },
+    #[error(
+        "GitHub API rate limit still exceeded after {retries} retries, with {}",
+        quota(*.remaining, *.limit)
+    )]
+    RateLimitRetriesExhausted {
+        remaining: Option<u64>,
+        limit: Option<u64>,
+        retries: u32,
+    },
 }
 
-#[derive(Debug, Clone)]
-pub struct GitHubRelease {
-    pub created_at: DateTime<Utc>,
-    pub name: Option<String>,
-}
-
-#[derive(Debug, Clone)]
-pub struct GitHubPullRequest {
-    pub title: String,
-    pub number: u64,
-    pub url: String,
-    pub labels: Vec<String>,
-    pub author: Option<String>,
-    pub merged_at: DateTime<Utc>,
-}
-
-#[derive(Debug, Clone)]
-pub struct GitHubPullRequestOptions {
-    pub title: String,
-    pub body: String,
-    pub head: String,
-    pub base: String,
-    pub draft: bool,
-}
-
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub struct GitHubPullRequestReference {
-    pub url: String,
-}
-
-pub fn filter_prs_by_date_range(
-    prs: &[GitHubPullRequest],
-    since: Option<&DateTime<Utc>>,
-    until: Option<&DateTime<Utc>>,
-) -> Vec<GitHubPullRequest> {
-    prs.iter()
-        .filter(|pr| {
-            let after_since = if let Some(since_date) = since {
-                pr.merged_at > *since_date
-            } else {
-                true
-            };
-
-            let before_until = if let Some(until_date) = until {
-                pr.merged_at <= *until_date
-            } else {
-                true
-            };
-
-            after_since && before_until
-        })
-        .cloned()
-        .collect()
+fn quota(remaining: Option<u64>, limit: Option<u64>) -> String {
+    match (remaining, limit) {
+        (Some(remaining), Some(limit)) => format!("{remaining} of {limit} requests remaining"),
+        (Some(remaining), None) => format!("{remaining} requests remaining"),
+        (None, _) => "an unknown number of requests remaining".to_string(),
+    }
 }
@@ -0,0 +1,431 @@
+use std::fmt;
+use std::io::Write as _;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+use anyhow::{Context, Result};
+
+use crate::github::GitHubError;
+
+/// The environment variables a token for `github.com` is read from, in order of precedence.
+const TOKEN_ENV_VARS: [&str; 2] = ["GITHUB_TOKEN", "GH_TOKEN"];
+/// The environment variables a token for GitHub Enterprise Server is read from, in order of
+/// precedence.
+const ENTERPRISE_TOKEN_ENV_VARS: [&str; 2] = ["GH_ENTERPRISE_TOKEN", "GITHUB_ENTERPRISE_TOKEN"];
+
+/// The environment variables a token for `host` is read from, in order of precedence.
+///
+/// Like the GitHub CLI, `GITHUB_TOKEN` and `GH_TOKEN` are only used for `github.com` and
+/// GitHub Enterprise Cloud's `ghe.com` subdomains, so that they are never sent to a GitHub
+/// Enterprise Server host.
+pub fn token_env_vars(host: &str) -> &'static [&'static str] {
+    let host = host.to_ascii_lowercase();
+    if host == "github.com" || host.ends_with(".ghe.com") {
+        &TOKEN_ENV_VARS
+    } else {
+        &ENTERPRISE_TOKEN_ENV_VARS
+    }
+}
+
+/// A GitHub token and where it was found.
+#[derive(Clone)]
+pub struct GitHubToken {
+    secret: String,
+    source: TokenSource,
+}
+
+impl GitHubToken {
+    pub fn secret(&self) -> &str {
+        &self.secret
+    }
+
+    pub fn source(&self) -> &TokenSource {
+        &self.source
+    }
+}
+
+impl fmt::Debug for GitHubToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("GitHubToken")
+            .field("secret", &"[REDACTED]")
+            .field("source", &self.source)
+            .finish()
+    }
+}
+
+/// How a client authenticates its requests.
+#[derive(Clone)]
+pub enum Credentials {
+    /// Send requests without a token.
+    Anonymous,
+    /// Authenticate with a known token.
+    Token(String),
+    /// Find a token with [`discover_token`] when the first request is sent, since looking one up
+    /// may read the GitHub CLI's config or run `git credential fill`.
+    Discover {
+        host: String,
+        token_command: Option<String>,
+    },
+}
+
+impl Credentials {
+    /// The token to authenticate with, if any.
+    pub(crate) fn resolve(&self) -> Result<Option<String>> {
+        match self {
+            Self::Anonymous => Ok(None),
+            Self::Token(secret) => Ok(Some(secret.clone())),
+            Self::Discover {
+                host,
+                token_command,
+            } => {
+                let token = discover_token(host, token_command.as_deref())?;
+                if let Some(token) = &token {
+                    tracing::debug!("Using the GitHub token from {}", token.source());
+                }
+                Ok(token.map(|token| token.secret))
+            }
+        }
+    }
+}
+
+impl fmt::Debug for Credentials {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Anonymous => f.write_str("Anonymous"),
+            Self::Token(_) => f.debug_tuple("Token").field(&"[REDACTED]").finish(),
+            Self::Discover {
+                host,
+                token_command,
+            } => f
+                .debug_struct("Discover")
+                .field("host", host)
+                .field("token_command", token_command)
+                .finish(),
+        }
+    }
+}
+
+/// Where a GitHub token was found.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TokenSource {
+    /// An environment variable, such as `GITHUB_TOKEN`.
+    Env(&'static str),
+    /// The `github.token-command` setting.
+    TokenCommand(String),
+    /// The GitHub CLI's `hosts.yml`, written by `gh auth login`.
+    GhConfig(PathBuf),
+    /// `git credential fill`, which asks the configured credential helpers.
+    GitCredential,
+}
+
+impl fmt::Display for TokenSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Env(name) => write!(f, "the `{name}` environment variable"),
+            Self::TokenCommand(command) => write!(f, "`github.token-command` (`{command}`)"),
+            Self::GhConfig(path) => write!(f, "the GitHub CLI config at `{}`", path.display()),
+            Self::GitCredential => write!(f, "a Git credential helper"),
+        }
+    }
+}
+
+/// Find a token for the GitHub instance at `host`.
+///
+/// Tokens are read from the environment variables for the host (see [`token_env_vars`]), then
+/// from the output of `token_command`, then from the GitHub CLI's login for the host, and finally
+/// from `git credential fill`. A failing `token_command` is an error, since it was configured explicitly; the other sources are
+/// skipped when they have no token.
+pub fn discover_token(host: &str, token_command: Option<&str>) -> Result<Option<GitHubToken>> {
+    for &name in token_env_vars(host) {
+        if let Some(secret) = std::env::var(name).ok().as_deref().and_then(non_empty) {
+            return Ok(Some(GitHubToken {
+                secret,
+                source: TokenSource::Env(name),
+            }));
+        }
+    }
+
+    if let Some(command) = token_command {
+        return run_token_command(command).map(|secret| {
+            Some(GitHubToken {
+                secret,
+                source: TokenSource::TokenCommand(command.to_string()),
+            })
+        });
+    }
+
+    if let Some(path) = gh_hosts_path()
+        && let Some(secret) = read_gh_hosts(&path, host)
+    {
+        return Ok(Some(GitHubToken {
+            secret,
+            source: TokenSource::GhConfig(path),
+        }));
+    }
+
+    if let Some(secret) = git_credential(host) {
+        return Ok(Some(GitHubToken {
+            secret,
+            source: TokenSource::GitCredential,
+        }));
+    }
+
+    Ok(None)
+}
+
+/// Run `github.token-command` and read the token from its output.
+fn run_token_command(command: &str) -> Result<String> {
+    let args = seal_command::split_command(command)?;
+    let Some((program, args)) = args.split_first() else {
+        anyhow::bail!("`github.token-command` is empty");
+    };
+
+    let output = Command::new(program)
+        .args(args)
+        .stdin(Stdio::null())
+        .output()
+        .with_context(|| format!("Failed to run `github.token-command` (`{command}`)"))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        let stderr = stderr.trim();
+        return Err(GitHubError::TokenCommandFailed {
+            command: command.to_string(),
+            status: output.status.code().map_or_else(
+                || "no exit code".to_string(),
+                |code| format!("exit code {code}"),
+            ),
+            details: if stderr.is_empty() {
+                String::new()
+            } else {
+                format!(": {stderr}")
+            },
+        }
+        .into());
+    }
+
+    String::from_utf8(output.stdout)
+        .ok()
+        .as_deref()
+        .and_then(non_empty)
+        .ok_or_else(|| {
+            GitHubError::TokenCommandEmpty {
+                command: command.to_string(),
+            }
+            .into()
+        })
+}
+
+/// The GitHub CLI's `hosts.yml`, following its config directory resolution.
+fn gh_hosts_path() -> Option<PathBuf> {
+    let env_dir = |name: &str| {
+        std::env::var_os(name)
+            .filter(|value| !value.is_empty())
+            .map(PathBuf::from)
+    };
+
+    let config_dir = if let Some(dir) = env_dir("GH_CONFIG_DIR") {
+        dir
+    } else if let Some(dir) = env_dir("XDG_CONFIG_HOME") {
+        dir.join("gh")
+    } else if cfg!(windows) {
+        env_dir("APPDATA")?.join("GitHub CLI")
+    } else {
+        env_dir("HOME")?.join(".config").join("gh")
+    };
+
+    Some(config_dir.join("hosts.yml"))
+}
+
+fn read_gh_hosts(path: &Path, host: &str) -> Option<String> {
+    let contents = fs_err::read_to_string(path).ok()?;
+    let token = parse_gh_hosts(&contents, host);
+    if token.is_none() {
+        tracing::debug!(
+            "No token for `{host}` in `{}`; the GitHub CLI may keep it in the system keyring",
+            path.display()
+        );
+    }
+    token
+}
+
+/// Read the `oauth_token` of `host` from the GitHub CLI's `hosts.yml`.
+///
+/// The file maps each host to its settings:
+///
+/// ```yaml
+/// github.com:
+///     user: octocat
+///     oauth_token: gho_...
+///     git_protocol: https
+/// ```
+fn parse_gh_hosts(contents: &str, host: &str) -> Option<String> {
+    let mut in_host = false;
+    let mut indent = None;
+
+    for line in contents.lines() {
+        let trimmed = line.trim_start();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+
+        let line_indent = line.len() - trimmed.len();
+        if line_indent == 0 {
+            in_host = trimmed.strip_suffix(':').map(unquote) == Some(host);
+            indent = None;
+            continue;
+        }
+        if !in_host {
+            continue;
+        }
+
+        // Only read the host's own settings, not those of nested maps such as `users`.
+        let indent = *indent.get_or_insert(line_indent);
+        if line_indent != indent {
+            continue;
+        }
+        if let Some(value) = trimmed.strip_prefix("oauth_token:") {
+            return non_empty(unquote(value.trim()));
+        }
+    }
+
+    None
+}
+
+fn unquote(value: &str) -> &str {
+    value
+        .strip_prefix('"')
+        .and_then(|value| value.strip_suffix('"'))
+        .or_else(|| {
+            value
+                .strip_prefix('\'')
+                .and_then(|value| value.strip_suffix('\''))
+        })
+        .unwrap_or(value)
+}
+
+/// Ask the configured Git credential helpers for the password of `https://<host>`, without
+/// prompting.
+fn git_credential(host: &str) -> Option<String> {
+    let mut child = Command::new("git")
+        .args(["credential", "fill"])
+        .env("GIT_TERMINAL_PROMPT", "0")
+        .env("GCM_INTERACTIVE", "never")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .inspect_err(|err| tracing::debug!("Failed to run `git credential fill`: {err}"))
+        .ok()?;
+
+    if let Some(mut stdin) = child.stdin.take() {
+        write!(stdin, "protocol=https\nhost={host}\n\n").ok()?;
+    }
+
+    let output = child.wait_with_output().ok()?;
+    if !output.status.success() {
+        tracing::debug!("`git credential fill` found no credentials for `{host}`");
+        return None;
+    }
+
+    parse_git_credential(&String::from_utf8_lossy(&output.stdout))
+}
+
+/// Read the password from the output of `git credential fill`.
+fn parse_git_credential(output: &str) -> Option<String> {
+    output
+        .lines()
+        .find_map(|line| line.strip_prefix("password="))
+        .and_then(non_empty)
+}
+
+fn non_empty(value: &str) -> Option<String> {
+    let value = value.trim();
+    (!value.is_empty()).then(|| value.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_gh_hosts, parse_git_credential, run_token_command, token_env_vars};
+
+    const HOSTS: &str = "\
+github.com:
+    users:
+        octocat:
+            oauth_token: gho_nested
+    oauth_token: gho_github
+    user: octocat
+    git_protocol: https
+\"github.example.com\":
+    oauth_token: 'ghe_token'
+keyring.example.com:
+    user: octocat
+";
+
+    #[test]
+    fn reads_the_token_for_the_host_from_gh_hosts() {
+        assert_eq!(
+            parse_gh_hosts(HOSTS, "github.com").as_deref(),
+            Some("gho_github")
+        );
+        assert_eq!(
+            parse_gh_hosts(HOSTS, "github.example.com").as_deref(),
+            Some("ghe_token")
+        );
+        assert_eq!(parse_gh_hosts(HOSTS, "keyring.example.com"), None);
+        assert_eq!(parse_gh_hosts(HOSTS, "gitlab.com"), None);
+    }
+
+    #[test]
+    fn only_reads_github_com_tokens_for_github_com() {
+        assert_eq!(token_env_vars("github.com"), ["GITHUB_TOKEN", "GH_TOKEN"]);
+        assert_eq!(token_env_vars("octo.ghe.com"), ["GITHUB_TOKEN", "GH_TOKEN"]);
+        assert_eq!(
+            token_env_vars("github.example.com"),
+            ["GH_ENTERPRISE_TOKEN", "GITHUB_ENTERPRISE_TOKEN"]
+        );
+    }
+
+    #[test]
+    fn reads_the_password_from_git_credential_output() {
+        let output = "protocol=https\nhost=github.com\nusername=octocat\npassword=ghp_secret\n";
+
+        assert_eq!(parse_git_credential(output).as_deref(), Some("ghp_secret"));
+        assert_eq!(
+            parse_git_credential("protocol=https\nhost=github.com\n"),
+            None
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn token_command_output_is_trimmed() {
+        assert_eq!(
+            run_token_command("printf ' ghp_secret\\n'").unwrap(),
+            "ghp_secret"
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn failing_token_command_reports_stderr() {
+        let error = run_token_command("sh -c 'echo not logged in >&2; exit 3'").unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "`github.token-command` (`sh -c 'echo not logged in >&2; exit 3'`) failed with exit \
+             code 3: not logged in"
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn empty_token_command_output_is_an_error() {
+        let error = run_token_command("true").unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "`github.token-command` (`true`) did not print a token"
+        );
+    }
+}
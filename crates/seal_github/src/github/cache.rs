@@ -0,0 +1,163 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use bytes::Bytes;
+use http_body_util::{BodyExt, Full};
+use hyper::body::Body;
+use hyper::header::{
+    CONTENT_TYPE, ETAG, HeaderName, HeaderValue, IF_MODIFIED_SINCE, IF_NONE_MATCH, LAST_MODIFIED,
+    LINK,
+};
+use hyper::{Method, Request, Response, StatusCode};
+use seal_cache::{Cache, CacheBucket};
+use serde::{Deserialize, Serialize};
+use tower::{Layer, Service};
+
+type BoxError = Box<dyn std::error::Error + Send + Sync>;
+
+/// The response headers that are replayed with a cached body.
+const CACHED_HEADERS: [HeaderName; 2] = [CONTENT_TYPE, LINK];
+
+/// A successful `GET` response, with the validator GitHub sent for it.
+#[derive(Debug, Serialize, Deserialize)]
+struct CachedResponse {
+    etag: Option<String>,
+    last_modified: Option<String>,
+    headers: Vec<(String, String)>,
+    body: String,
+}
+
+/// Caches `GET` responses on disk and revalidates them with conditional requests.
+///
+/// GitHub answers a conditional request for an unchanged resource with `304 Not Modified`, which
+/// does not count against the rate limit, and the cached body is returned in its place. Other
+/// requests pass through, with their response bodies buffered.
+#[derive(Debug, Clone)]
+pub(crate) struct HttpCacheLayer {
+    cache: Option<Cache>,
+}
+
+impl HttpCacheLayer {
+    pub(crate) fn new(cache: Option<Cache>) -> Self {
+        Self { cache }
+    }
+}
+
+impl<S> Layer<S> for HttpCacheLayer {
+    type Service = HttpCache<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        HttpCache {
+            inner,
+            cache: self.cache.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct HttpCache<S> {
+    inner: S,
+    cache: Option<Cache>,
+}
+
+impl<S, ReqBody, ResBody> Service<Request<ReqBody>> for HttpCache<S>
+where
+    S: Service<Request<ReqBody>, Response = Response<ResBody>>,
+    S::Future: Send + 'static,
+    S::Error: Into<BoxError>,
+    ResBody: Body<Data = Bytes> + Send + 'static,
+    ResBody::Error: Into<BoxError>,
+{
+    type Response = Response<Full<Bytes>>;
+    type Error = BoxError;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx).map_err(Into::into)
+    }
+
+    fn call(&mut self, mut request: Request<ReqBody>) -> Self::Future {
+        let cache = self
+            .cache
+            .clone()
+            .filter(|_| request.method() == Method::GET);
+        let key = request.uri().to_string();
+
+        let cached = cache.as_ref().and_then(|cache| {
+            let contents = cache.read(CacheBucket::GitHub, &key)?;
+            serde_json::from_slice::<CachedResponse>(&contents).ok()
+        });
+        if let Some(cached) = &cached {
+            let headers = request.headers_mut();
+            if let Some(etag) = cached.etag.as_deref().and_then(to_header_value) {
+                headers.insert(IF_NONE_MATCH, etag);
+            } else if let Some(date) = cached.last_modified.as_deref().and_then(to_header_value) {
+                headers.insert(IF_MODIFIED_SINCE, date);
+            }
+        }
+
+        let response = self.inner.call(request);
+
+        Box::pin(async move {
+            let (mut parts, body) = response.await.map_err(Into::into)?.into_parts();
+
+            if parts.status == StatusCode::NOT_MODIFIED
+                && let Some(cached) = cached
+            {
+                tracing::debug!("Using the cached response for {key}");
+                parts.status = StatusCode::OK;
+                for (name, value) in &cached.headers {
+                    if let (Ok(name), Some(value)) =
+                        (HeaderName::try_from(name.as_str()), to_header_value(value))
+                    {
+                        parts.headers.insert(name, value);
+                    }
+                }
+                return Ok(Response::from_parts(parts, Full::new(cached.body.into())));
+            }
+
+            let body = body.collect().await.map_err(Into::into)?.to_bytes();
+
+            if parts.status == StatusCode::OK
+                && let Some(cache) = cache
+            {
+                let header = |name| {
+                    parts
+                        .headers
+                        .get(name)
+                        .and_then(|value| value.to_str().ok())
+                        .map(ToString::to_string)
+                };
+                let etag = header(ETAG);
+                let last_modified = header(LAST_MODIFIED);
+
+                if (etag.is_some() || last_modified.is_some())
+                    && let Ok(text) = std::str::from_utf8(&body)
+                {
+                    let cached = CachedResponse {
+                        etag,
+                        last_modified,
+                        headers: CACHED_HEADERS
+                            .iter()
+                            .filter_map(|name| Some((name.to_string(), header(name.clone())?)))
+                            .collect(),
+                        body: text.to_string(),
+                    };
+                    if let Err(err) = serde_json::to_vec(&cached)
+                        .map_err(std::io::Error::other)
+                        .and_then(|contents| cache.write(CacheBucket::GitHub, &key, &contents))
+                    {
+                        tracing::warn!("Failed to cache the response for {key}: {err}");
+                    }
+                }
+            }
+
+            Ok(Response::from_parts(parts, Full::new(body)))
+        })
+    }
+}
+
+fn to_header_value(value: &str) -> Option<HeaderValue> {
+    HeaderValue::from_str(value).ok()
+}
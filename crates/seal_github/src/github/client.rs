@@ -1,16 +1,32 @@
+use std::str::FromStr;
+use std::sync::{Arc, OnceLock};
+
 use anyhow::{Context, Result};
 use chrono::{DateTime, Utc};
+use hyper::Uri;
+use hyper::header::{AUTHORIZATION, HeaderValue, USER_AGENT};
+use hyper_rustls::HttpsConnectorBuilder;
+use hyper_util::client::legacy::Client;
+use hyper_util::rt::TokioExecutor;
+use octocrab::service::middleware::base_uri::BaseUriLayer;
+use octocrab::service::middleware::extra_headers::ExtraHeadersLayer;
 use octocrab::{
-    Octocrab,
-    models::pulls::PullRequest,
+    AuthState, Octocrab, OctocrabBuilder,
+    models::pulls::PullRequest as ApiPullRequest,
     params::{Direction, pulls::Sort},
 };
+use seal_cache::{Cache, CacheBucket};
+use serde::Deserialize;
 
-use crate::github::{
-    GitHubError, GitHubPullRequest, GitHubPullRequestOptions, GitHubPullRequestReference,
-    GitHubRelease, GitHubService,
+use seal_forge::{
+    ForgeService, IssueReference, PullRequest, PullRequestOptions, PullRequestReference, Release,
 };
 
+use crate::github::cache::HttpCacheLayer;
+use crate::github::retry::{RetryConfig, RetryLayer};
+use crate::github::{Credentials, GitHubError};
+use crate::helpers::{github_web_url, graphql_base_url};
+
 const CONVERT_PULL_REQUEST_TO_DRAFT: &str = "mutation ConvertPullRequestToDraft($pullRequestId: ID!) {\
         convertPullRequestToDraft(input: { pullRequestId: $pullRequestId }) {\
             pullRequest { isDraft }\
@@ -21,37 +37,189 @@ const MARK_PULL_REQUEST_READY_FOR_REVIEW: &str = "mutation MarkPullRequestReadyF
             pullRequest { isDraft }\
         }\
     }";
+const SEARCH_MERGED_PULL_REQUESTS: &str = "query SearchMergedPullRequests($query: String!, $cursor: String) {\
+        search(query: $query, type: ISSUE, first: 100, after: $cursor) {\
+            issueCount \
+            pageInfo { hasNextPage endCursor } \
+            nodes { ... on PullRequest { \
+                number title url body mergedAt \
+                author { __typename login url } \
+                labels(first: 100) { nodes { name } } \
+                closingIssuesReferences(first: 100) { nodes { number url } } \
+                files(first: 100) { pageInfo { hasNextPage endCursor } nodes { path } } \
+            } }\
+        }\
+    }";
+const PULL_REQUEST_FILES: &str = "query PullRequestFiles($owner: String!, $repo: String!, $number: Int!, $cursor: String) {\
+        repository(owner: $owner, name: $repo) {\
+            pullRequest(number: $number) {\
+                files(first: 100, after: $cursor) { pageInfo { hasNextPage endCursor } nodes { path } }\
+            }\
+        }\
+    }";
+
+/// The maximum number of results GitHub search returns for a single query.
+const SEARCH_RESULT_LIMIT: u64 = 1000;
+
+/// A range of merge dates, exclusive of the start and inclusive of the end.
+type MergeWindow = (Option<DateTime<Utc>>, Option<DateTime<Utc>>);
+
+/// The user a token belongs to, and its OAuth scopes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TokenInfo {
+    pub login: String,
+    /// The token's OAuth scopes, or `None` for tokens without scopes, such as fine-grained
+    /// personal access tokens.
+    pub scopes: Option<Vec<String>>,
+}
 
 #[derive(Debug)]
 pub struct GitHubClient {
-    octocrab: Octocrab,
+    /// The API clients, created with the token from `credentials` when first used.
+    connection: OnceLock<Connection>,
+    credentials: Credentials,
+    retry: RetryConfig,
+    api_url: String,
     owner: String,
     repo: String,
+    /// Stores search results for merge windows that have already closed.
+    cache: Option<Cache>,
+    /// The branch release pull requests are merged into, looked up as the repository's default
+    /// branch unless set with [`GitHubClient::with_base`].
+    base: tokio::sync::OnceCell<String>,
+}
+
+/// The API clients of a [`GitHubClient`].
+#[derive(Debug)]
+struct Connection {
+    octocrab: Octocrab,
+    /// Sends GraphQL requests, which GitHub Enterprise Server serves outside the REST API root.
+    graphql: Octocrab,
     authenticated: bool,
 }
 
 impl GitHubClient {
-    pub fn new(owner: String, repo: String) -> Result<Self> {
-        let github_token = ["GITHUB_TOKEN", "GH_TOKEN"].into_iter().find_map(|name| {
-            std::env::var(name)
-                .ok()
-                .filter(|token| !token.trim().is_empty())
-        });
-        let authenticated = github_token.is_some();
+    /// Create a client for `owner/repo` using the REST API at `api_url`, e.g.
+    /// `https://api.github.com` or `https://github.example.com/api/v3`.
+    ///
+    /// Requests are authenticated according to `credentials`, which are only resolved when the
+    /// first request is sent. Rate-limited and transiently failing requests are retried according
+    /// to `retry`, and responses are stored in `cache`, if any.
+    pub fn new(
+        api_url: &str,
+        owner: String,
+        repo: String,
+        credentials: Credentials,
+        retry: RetryConfig,
+        cache: Option<Cache>,
+    ) -> Self {
+        Self {
+            connection: OnceLock::new(),
+            credentials,
+            retry,
+            api_url: api_url.to_string(),
+            owner,
+            repo,
+            cache,
+            base: tokio::sync::OnceCell::new(),
+        }
+    }
 
-        let mut octocrab = Octocrab::builder();
+    /// The API clients, resolving the credentials on first use.
+    fn connection(&self) -> Result<&Connection> {
+        if let Some(connection) = self.connection.get() {
+            return Ok(connection);
+        }
+
+        let token = self.credentials.resolve()?;
+        let token = token.as_deref();
+        let connection = Connection {
+            octocrab: build_octocrab(&self.api_url, token, &self.retry, self.cache.clone())?,
+            graphql: build_octocrab(
+                graphql_base_url(&self.api_url),
+                token,
+                &self.retry,
+                self.cache.clone(),
+            )?,
+            authenticated: token.is_some(),
+        };
+        Ok(self.connection.get_or_init(|| connection))
+    }
 
-        if let Some(token) = github_token {
-            octocrab = octocrab.personal_token(token);
+    /// Only list pull requests merged into `base`, instead of the repository's default branch.
+    #[must_use]
+    pub fn with_base(self, base: Option<String>) -> Self {
+        Self {
+            base: tokio::sync::OnceCell::new_with(base),
+            ..self
         }
+    }
 
-        let octocrab = octocrab.build()?;
+    /// The branch whose merged pull requests are listed.
+    async fn base(&self) -> Result<&str> {
+        let base = self
+            .base
+            .get_or_try_init(|| async {
+                let repository = self
+                    .connection()?
+                    .octocrab
+                    .repos(&self.owner, &self.repo)
+                    .get()
+                    .await
+                    .map_err(api_error)
+                    .context("Failed to look up the default branch of the GitHub repository")?;
+                repository
+                    .default_branch
+                    .context("GitHub did not report a default branch for the repository")
+            })
+            .await?;
+        Ok(base)
+    }
 
-        Ok(Self {
-            octocrab,
-            owner,
-            repo,
-            authenticated,
+    /// Look up the user the token belongs to and the OAuth scopes GitHub reports for it.
+    pub async fn token_info(&self) -> Result<TokenInfo> {
+        let action = "look up the authenticated GitHub user";
+        self.ensure_authenticated()?;
+
+        let response = self
+            .connection()?
+            .octocrab
+            ._get("/user")
+            .await
+            .map_err(api_error)
+            .with_context(|| format!("Failed to {action}"))?;
+        let response = octocrab::map_github_error(response)
+            .await
+            .map_err(api_error)
+            .with_context(|| format!("Failed to {action}"))?;
+
+        // Fine-grained tokens and GitHub App tokens have permissions instead of scopes.
+        let scopes = response
+            .headers()
+            .get("x-oauth-scopes")
+            .and_then(|value| value.to_str().ok())
+            .map(|scopes| {
+                scopes
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|scope| !scope.is_empty())
+                    .map(ToString::to_string)
+                    .collect()
+            });
+
+        let body = self
+            .connection()?
+            .octocrab
+            .body_to_string(response)
+            .await
+            .map_err(api_error)
+            .with_context(|| format!("Failed to {action}"))?;
+        let user: ApiUser = serde_json::from_str(&body)
+            .with_context(|| format!("Failed to {action}: unexpected response"))?;
+
+        Ok(TokenInfo {
+            login: user.login,
+            scopes,
         })
     }
 
@@ -69,56 +237,371 @@ impl GitHubClient {
         };
 
         let response: serde_json::Value = self
-            .octocrab
+            .connection()?
+            .graphql
             .graphql(&serde_json::json!({
                 "query": query,
                 "variables": { "pullRequestId": node_id },
             }))
             .await
+            .map_err(api_error)
+            .with_context(|| format!("Failed to {action}"))?;
+
+        check_graphql_errors(action, &response)
+    }
+
+    /// Search for the pull requests merged in a window.
+    ///
+    /// Returns `None` if the window matches more pull requests than GitHub search returns, unless
+    /// `truncate` is set, in which case only the first results are kept.
+    async fn search_merged_prs(
+        &self,
+        (since, until): MergeWindow,
+        truncate: bool,
+    ) -> Result<Option<Vec<PullRequest>>> {
+        let query = search_query(&self.owner, &self.repo, self.base().await?, since, until);
+        // GraphQL responses carry no validators, but no more pull requests can be merged into a
+        // window that has closed, so its results are stored as they are.
+        let cacheable = until.is_some_and(|until| until < Utc::now());
+        let mut cursor: Option<String> = None;
+        let mut prs = Vec::new();
+
+        loop {
+            let search = self
+                .search_page(&query, cursor.as_deref(), cacheable)
+                .await?;
+
+            if cursor.is_none() && search.issue_count > SEARCH_RESULT_LIMIT && !truncate {
+                return Ok(None);
+            }
+
+            for node in search.nodes.into_iter().flatten() {
+                // Search returns the first 100 changed files; the rest are paged separately.
+                let files_cursor = node
+                    .files
+                    .as_ref()
+                    .filter(|files| files.page_info.has_next_page)
+                    .and_then(|files| files.page_info.end_cursor.clone());
+                let Some(mut pr) = node.into_pull_request() else {
+                    continue;
+                };
+                if let Some(files_cursor) = files_cursor {
+                    pr.changed_files
+                        .extend(self.remaining_files(pr.number, files_cursor).await?);
+                }
+                prs.push(pr);
+            }
+
+            match search.page_info.end_cursor {
+                Some(end_cursor) if search.page_info.has_next_page => cursor = Some(end_cursor),
+                _ => break,
+            }
+        }
+
+        // Search only matches whole seconds, and both ends of a `merged:` range are inclusive.
+        prs.retain(|pr| {
+            since.is_none_or(|since| pr.merged_at > since)
+                && until.is_none_or(|until| pr.merged_at <= until)
+        });
+
+        Ok(Some(prs))
+    }
+
+    /// List the files a pull request changes after `cursor`.
+    async fn remaining_files(&self, number: u64, cursor: String) -> Result<Vec<String>> {
+        let action = "list the files changed by a GitHub pull request";
+        let mut cursor = cursor;
+        let mut paths = Vec::new();
+
+        loop {
+            let response: serde_json::Value = self
+                .connection()?
+                .graphql
+                .graphql(&serde_json::json!({
+                    "query": PULL_REQUEST_FILES,
+                    "variables": {
+                        "owner": self.owner,
+                        "repo": self.repo,
+                        "number": number,
+                        "cursor": cursor,
+                    },
+                }))
+                .await
+                .map_err(api_error)
+                .with_context(|| format!("Failed to {action} (#{number})"))?;
+            check_graphql_errors(action, &response)?;
+
+            let files: ApiFiles = serde_json::from_value(
+                response["data"]["repository"]["pullRequest"]["files"].clone(),
+            )
+            .with_context(|| format!("Failed to {action} (#{number}): unexpected response"))?;
+            paths.extend(files.nodes.into_iter().map(|file| file.path));
+
+            match files.page_info.end_cursor {
+                Some(end_cursor) if files.page_info.has_next_page => cursor = end_cursor,
+                _ => return Ok(paths),
+            }
+        }
+    }
+
+    /// Fetch a page of search results, reading and writing the cache if `cacheable` is set.
+    async fn search_page(
+        &self,
+        query: &str,
+        cursor: Option<&str>,
+        cacheable: bool,
+    ) -> Result<ApiSearch> {
+        let action = "search GitHub pull requests";
+        let cache = self.cache.as_ref().filter(|_| cacheable);
+        // The key includes the GraphQL document, so results stored before a field was added to
+        // it are not reused.
+        let key = format!(
+            "{}/graphql?document={SEARCH_MERGED_PULL_REQUESTS}&query={query}&cursor={}",
+            self.api_url,
+            cursor.unwrap_or_default()
+        );
+
+        if let Some(search) = cache
+            .and_then(|cache| cache.read(CacheBucket::GitHub, &key))
+            .and_then(|contents| serde_json::from_slice(&contents).ok())
+        {
+            tracing::debug!("Using cached search results for `{query}`");
+            return Ok(search);
+        }
+
+        let response: serde_json::Value = self
+            .connection()?
+            .graphql
+            .graphql(&serde_json::json!({
+                "query": SEARCH_MERGED_PULL_REQUESTS,
+                "variables": { "query": query, "cursor": cursor },
+            }))
+            .await
+            .map_err(api_error)
             .with_context(|| format!("Failed to {action}"))?;
+        check_graphql_errors(action, &response)?;
 
-        if let Some(errors) = response.get("errors").and_then(serde_json::Value::as_array)
-            && !errors.is_empty()
+        let search = &response["data"]["search"];
+        let parsed: ApiSearch = serde_json::from_value(search.clone())
+            .with_context(|| format!("Failed to {action}: unexpected response"))?;
+
+        if let Some(cache) = cache
+            && let Err(err) = cache.write(CacheBucket::GitHub, &key, search.to_string().as_bytes())
         {
-            return Err(GitHubError::GraphQlErrors {
-                action,
-                errors: serde_json::Value::Array(errors.clone()).to_string(),
+            tracing::warn!("Failed to cache search results for `{query}`: {err}");
+        }
+
+        Ok(parsed)
+    }
+
+    /// List the pull requests merged in a window through the REST API.
+    ///
+    /// GraphQL requires authentication, so unauthenticated clients walk the closed pull requests
+    /// instead, most recently updated first.
+    async fn list_merged_prs(&self, (since, until): MergeWindow) -> Result<Vec<PullRequest>> {
+        let base = self.base().await?;
+        let mut page = 1u32;
+        let mut all_prs = Vec::new();
+
+        loop {
+            let response = self
+                .connection()?
+                .octocrab
+                .pulls(&self.owner, &self.repo)
+                .list()
+                .state(octocrab::params::State::Closed)
+                .base(base)
+                .sort(Sort::Updated)
+                .direction(Direction::Descending)
+                .per_page(100)
+                .page(page)
+                .send()
+                .await
+                .map_err(api_error)?;
+
+            if response.items.is_empty() {
+                break;
+            }
+
+            for pr in response {
+                if let Some(since) = since
+                    && let Some(updated_at) = pr.updated_at.as_ref()
+                    && *updated_at <= since
+                {
+                    return Ok(all_prs);
+                }
+
+                let Some(pr) = gh_pr_to_github_pull_request(pr) else {
+                    continue;
+                };
+
+                if let Some(since) = since
+                    && pr.merged_at <= since
+                {
+                    continue;
+                }
+
+                if let Some(until) = until
+                    && pr.merged_at > until
+                {
+                    continue;
+                }
+
+                all_prs.push(pr);
             }
-            .into());
+
+            page += 1;
         }
 
-        Ok(())
+        Ok(all_prs)
+    }
+}
+
+/// Build an Octocrab client for the API rooted at `base_uri` whose requests are retried and
+/// whose responses are cached.
+///
+/// Octocrab's own retry layer resends failed requests immediately, so the service stack is
+/// assembled here instead.
+fn build_octocrab(
+    base_uri: &str,
+    token: Option<&str>,
+    retry: &RetryConfig,
+    cache: Option<Cache>,
+) -> Result<Octocrab> {
+    let invalid_url = || format!("Invalid GitHub API URL `{base_uri}`");
+    let uri = Uri::from_str(base_uri).with_context(invalid_url)?;
+    if uri.scheme().is_none() || uri.authority().is_none() {
+        anyhow::bail!(invalid_url());
+    }
+
+    let connector = HttpsConnectorBuilder::new()
+        .with_native_roots()
+        .context("Failed to load the system's root certificates")?
+        .https_or_http()
+        .enable_http1()
+        .build();
+    let client = Client::builder(TokioExecutor::new()).build(connector);
+
+    let mut headers = vec![(USER_AGENT, HeaderValue::from_static("seal"))];
+    if let Some(token) = token {
+        let mut value =
+            HeaderValue::from_str(&format!("Bearer {token}")).context("Invalid GitHub token")?;
+        value.set_sensitive(true);
+        headers.push((AUTHORIZATION, value));
+    }
+
+    Ok(OctocrabBuilder::new_empty()
+        .with_service(client)
+        .with_layer(&RetryLayer::new(*retry))
+        .with_layer(&HttpCacheLayer::new(cache))
+        .with_layer(&ExtraHeadersLayer::new(Arc::new(headers)))
+        .with_layer(&BaseUriLayer::new(uri))
+        .with_auth(AuthState::None)
+        .build()?)
+}
+
+/// Surface the error from a retry that gave up, which Octocrab wraps as a service error.
+fn api_error(error: octocrab::Error) -> anyhow::Error {
+    match error {
+        octocrab::Error::Service { source, backtrace } => match source.downcast::<GitHubError>() {
+            Ok(error) => (*error).into(),
+            Err(source) => octocrab::Error::Service { source, backtrace }.into(),
+        },
+        error => error.into(),
+    }
+}
+
+fn check_graphql_errors(action: &'static str, response: &serde_json::Value) -> Result<()> {
+    if let Some(errors) = response.get("errors").and_then(serde_json::Value::as_array)
+        && !errors.is_empty()
+    {
+        return Err(GitHubError::GraphQlErrors {
+            action,
+            errors: serde_json::Value::Array(errors.clone()).to_string(),
+        }
+        .into());
+    }
+
+    Ok(())
+}
+
+/// The search query for the pull requests merged into `owner/repo` in a window.
+fn search_query(
+    owner: &str,
+    repo: &str,
+    base: &str,
+    since: Option<DateTime<Utc>>,
+    until: Option<DateTime<Utc>>,
+) -> String {
+    let format = |date: DateTime<Utc>| date.format("%Y-%m-%dT%H:%M:%SZ").to_string();
+    let merged = match (since, until) {
+        (Some(since), Some(until)) => format!(" merged:{}..{}", format(since), format(until)),
+        (Some(since), None) => format!(" merged:>={}", format(since)),
+        (None, Some(until)) => format!(" merged:<={}", format(until)),
+        (None, None) => String::new(),
+    };
+    format!("repo:{owner}/{repo} is:pr is:merged base:{base}{merged}")
+}
+
+/// Split a window in two at its midpoint, unless it spans a single second.
+fn split_window((since, until): MergeWindow) -> Option<(MergeWindow, MergeWindow)> {
+    let start = since.unwrap_or(DateTime::UNIX_EPOCH);
+    let end = until.unwrap_or_else(Utc::now);
+    if end - start <= chrono::TimeDelta::seconds(1) {
+        return None;
     }
+
+    let middle = start + (end - start) / 2;
+    Some(((since, Some(middle)), (Some(middle), until)))
 }
 
-impl GitHubService for GitHubClient {
+impl ForgeService for GitHubClient {
     fn ensure_authenticated(&self) -> Result<()> {
-        if !self.authenticated {
+        if !self.connection()?.authenticated {
             return Err(GitHubError::AuthenticationRequired.into());
         }
 
         Ok(())
     }
 
+    fn compare_url(&self, base: &str, head: &str) -> Option<String> {
+        Some(format!(
+            "{}/{}/{}/compare/{base}...{head}",
+            github_web_url(&self.api_url),
+            self.owner,
+            self.repo
+        ))
+    }
+
+    fn pull_request_url(&self, number: u64) -> Option<String> {
+        Some(format!(
+            "{}/{}/{}/pull/{number}",
+            github_web_url(&self.api_url),
+            self.owner,
+            self.repo
+        ))
+    }
+
     fn get_latest_release(
         &self,
-    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<GitHubRelease>> + Send + '_>>
-    {
+    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<Release>> + Send + '_>> {
         Box::pin(async move {
             let releases = self
+                .connection()?
                 .octocrab
                 .repos(&self.owner, &self.repo)
                 .releases()
                 .list()
                 .per_page(1)
                 .send()
-                .await?;
+                .await
+                .map_err(api_error)?;
 
             Ok(releases
                 .items
                 .first()
                 .and_then(|r| {
-                    r.created_at.map(|dt| GitHubRelease {
+                    r.created_at.map(|dt| Release {
                         created_at: dt,
                         name: r.name.clone(),
                     })
@@ -132,7 +615,7 @@ impl GitHubService for GitHubClient {
 
     fn get_all_releases(
         &self,
-    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<Vec<GitHubRelease>>> + Send + '_>>
+    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<Vec<Release>>> + Send + '_>>
     {
         Box::pin(async move {
             let mut page = 1u32;
@@ -140,6 +623,7 @@ impl GitHubService for GitHubClient {
 
             loop {
                 let releases = self
+                    .connection()?
                     .octocrab
                     .repos(&self.owner, &self.repo)
                     .releases()
@@ -147,7 +631,8 @@ impl GitHubService for GitHubClient {
                     .per_page(100)
                     .page(page)
                     .send()
-                    .await?;
+                    .await
+                    .map_err(api_error)?;
 
                 if releases.items.is_empty() {
                     break;
@@ -155,7 +640,7 @@ impl GitHubService for GitHubClient {
 
                 for release in releases.items {
                     if let Some(created_at) = release.created_at {
-                        all_releases.push(GitHubRelease {
+                        all_releases.push(Release {
                             created_at,
                             name: release.name.clone(),
                         });
@@ -175,120 +660,47 @@ impl GitHubService for GitHubClient {
         &self,
         since: Option<&DateTime<Utc>>,
         until: Option<&DateTime<Utc>>,
-    ) -> std::pin::Pin<
-        Box<dyn std::future::Future<Output = Result<Vec<GitHubPullRequest>>> + Send + '_>,
-    > {
+    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<Vec<PullRequest>>> + Send + '_>>
+    {
         let since = since.copied();
         let until = until.copied();
         Box::pin(async move {
-            let mut page = 1u32;
-            let mut all_prs = Vec::new();
-
-            loop {
-                let response = self
-                    .octocrab
-                    .pulls(&self.owner, &self.repo)
-                    .list()
-                    .state(octocrab::params::State::Closed)
-                    .sort(Sort::Updated)
-                    .direction(Direction::Descending)
-                    .per_page(100)
-                    .page(page)
-                    .send()
-                    .await?;
-
-                if response.items.is_empty() {
-                    break;
-                }
-
-                for pr in response {
-                    if let Some(since) = since
-                        && let Some(updated_at) = pr.updated_at.as_ref()
-                        && *updated_at <= since
-                    {
-                        return Ok(all_prs);
-                    }
-
-                    let Some(pr) = gh_pr_to_github_pull_request(pr) else {
-                        continue;
-                    };
-
-                    if let Some(since) = since
-                        && pr.merged_at <= since
-                    {
-                        continue;
-                    }
-
-                    if let Some(until) = until
-                        && pr.merged_at > until
-                    {
-                        continue;
-                    }
-
-                    all_prs.push(pr);
-                }
-
-                page += 1;
+            if !self.connection()?.authenticated {
+                return self.list_merged_prs((since, until)).await;
             }
 
-            Ok(all_prs)
-        })
-    }
-
-    fn get_prs(
-        &self,
-        max: Option<usize>,
-    ) -> std::pin::Pin<
-        Box<dyn std::future::Future<Output = Result<Vec<GitHubPullRequest>>> + Send + '_>,
-    > {
-        Box::pin(async move {
             let mut all_prs = Vec::new();
-            let per_page = 100u8;
-            let max_prs = max.unwrap_or(usize::MAX);
-            let mut page = 1u32;
-
-            loop {
-                let response = self
-                    .octocrab
-                    .pulls(&self.owner, &self.repo)
-                    .list()
-                    .state(octocrab::params::State::Closed)
-                    .per_page(per_page)
-                    .page(page)
-                    .send()
-                    .await?;
-
-                let merged_prs: Vec<_> = response
-                    .into_iter()
-                    .filter_map(gh_pr_to_github_pull_request)
-                    .collect();
-
-                let is_empty = merged_prs.is_empty();
-                all_prs.extend(merged_prs);
-
-                // Stop if we've hit our max or if the page was empty
-                if all_prs.len() >= max_prs || is_empty {
-                    break;
+            let mut windows = vec![(since, until)];
+
+            // Search returns at most 1000 results, so split busy windows until each one fits.
+            while let Some(window) = windows.pop() {
+                let halves = split_window(window);
+                match self.search_merged_prs(window, halves.is_none()).await? {
+                    Some(prs) => all_prs.extend(prs),
+                    None => windows.extend(halves.into_iter().flat_map(<[MergeWindow; 2]>::from)),
                 }
-
-                page += 1;
             }
 
-            all_prs.truncate(max_prs);
+            all_prs.sort_by(|a, b| {
+                b.merged_at
+                    .cmp(&a.merged_at)
+                    .then_with(|| b.number.cmp(&a.number))
+            });
+
             Ok(all_prs)
         })
     }
 
     fn create_or_update_pull_request(
         &self,
-        options: GitHubPullRequestOptions,
+        options: PullRequestOptions,
     ) -> std::pin::Pin<
-        Box<dyn std::future::Future<Output = Result<GitHubPullRequestReference>> + Send + '_>,
+        Box<dyn std::future::Future<Output = Result<PullRequestReference>> + Send + '_>,
     > {
         Box::pin(async move {
             self.ensure_authenticated()?;
 
-            let pull_requests = self.octocrab.pulls(&self.owner, &self.repo);
+            let pull_requests = self.connection()?.octocrab.pulls(&self.owner, &self.repo);
             let existing = pull_requests
                 .list()
                 .state(octocrab::params::State::Open)
@@ -297,6 +709,7 @@ impl GitHubService for GitHubClient {
                 .per_page(1)
                 .send()
                 .await
+                .map_err(api_error)
                 .context("Failed to find an existing GitHub pull request")?
                 .items
                 .into_iter()
@@ -313,6 +726,7 @@ impl GitHubService for GitHubClient {
                     .body(options.body.as_str())
                     .send()
                     .await
+                    .map_err(api_error)
                     .context("Failed to update GitHub pull request")?;
 
                 if draft_state_changed {
@@ -334,6 +748,7 @@ impl GitHubService for GitHubClient {
                     .draft(options.draft)
                     .send()
                     .await
+                    .map_err(api_error)
                     .context("Failed to create GitHub pull request")?
             };
 
@@ -342,16 +757,16 @@ impl GitHubService for GitHubClient {
                 .html_url
                 .ok_or(GitHubError::MissingPullRequestUrl { number })?;
 
-            Ok(GitHubPullRequestReference {
+            Ok(PullRequestReference {
                 url: url.to_string(),
             })
         })
     }
 }
 
-fn gh_pr_to_github_pull_request(pr: PullRequest) -> Option<GitHubPullRequest> {
+fn gh_pr_to_github_pull_request(pr: ApiPullRequest) -> Option<PullRequest> {
     pr.merged_at.and_then(|merged_at| {
-        pr.html_url.map(|url| GitHubPullRequest {
+        pr.html_url.map(|url| PullRequest {
             title: pr.title.unwrap_or_default(),
             number: pr.number,
             url: url.to_string(),
@@ -359,25 +774,160 @@ fn gh_pr_to_github_pull_request(pr: PullRequest) -> Option<GitHubPullRequest> {
                 .labels
                 .map(|labels| labels.iter().map(|l| l.name.clone()).collect())
                 .unwrap_or_default(),
+            author_url: pr.user.as_ref().map(|u| u.html_url.to_string()),
             author: pr.user.map(|u| u.login),
+            body: pr.body.filter(|body| !body.trim().is_empty()),
+            closed_issues: Vec::new(),
+            changed_files: Vec::new(),
             merged_at,
         })
     })
 }
 
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ApiSearch {
+    issue_count: u64,
+    page_info: ApiPageInfo,
+    nodes: Vec<Option<ApiSearchPullRequest>>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ApiPageInfo {
+    has_next_page: bool,
+    end_cursor: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ApiSearchPullRequest {
+    number: u64,
+    title: String,
+    url: String,
+    #[serde(default)]
+    body: String,
+    merged_at: Option<DateTime<Utc>>,
+    author: Option<ApiActor>,
+    labels: Option<ApiLabels>,
+    closing_issues_references: Option<ApiIssues>,
+    files: Option<ApiFiles>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ApiActor {
+    #[serde(rename = "__typename")]
+    typename: String,
+    login: String,
+    url: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct ApiLabels {
+    nodes: Vec<ApiLabel>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ApiLabel {
+    name: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct ApiIssues {
+    nodes: Vec<ApiIssue>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ApiIssue {
+    number: u64,
+    url: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ApiFiles {
+    page_info: ApiPageInfo,
+    nodes: Vec<ApiFile>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ApiFile {
+    path: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct ApiUser {
+    login: String,
+}
+
+impl ApiSearchPullRequest {
+    fn into_pull_request(self) -> Option<PullRequest> {
+        let merged_at = self.merged_at?;
+        let (author, author_url) = self.author.map_or((None, None), |author| {
+            // The REST API, and so `ignore-contributors`, spells bot logins with a `[bot]` suffix.
+            let login = if author.typename == "Bot" {
+                format!("{}[bot]", author.login)
+            } else {
+                author.login
+            };
+            (Some(login), Some(author.url))
+        });
+
+        Some(PullRequest {
+            title: self.title,
+            number: self.number,
+            url: self.url,
+            labels: self
+                .labels
+                .map(|labels| labels.nodes.into_iter().map(|label| label.name).collect())
+                .unwrap_or_default(),
+            author,
+            author_url,
+            body: Some(self.body).filter(|body| !body.trim().is_empty()),
+            closed_issues: self
+                .closing_issues_references
+                .map(|issues| {
+                    issues
+                        .nodes
+                        .into_iter()
+                        .map(|issue| IssueReference {
+                            number: issue.number,
+                            url: issue.url,
+                        })
+                        .collect()
+                })
+                .unwrap_or_default(),
+            changed_files: self
+                .files
+                .map(|files| files.nodes.into_iter().map(|file| file.path).collect())
+                .unwrap_or_default(),
+            merged_at,
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use anyhow::{Context as _, Result};
+    use std::sync::OnceLock;
+    use std::time::Duration;
+
     use chrono::{DateTime, Utc};
-    use octocrab::Octocrab;
     use serde_json::{Value, json};
     use wiremock::{
         Mock, MockServer, ResponseTemplate,
-        matchers::{body_json, method, path, query_param},
+        matchers::{body_json, header, method, path, query_param},
     };
 
-    use super::{CONVERT_PULL_REQUEST_TO_DRAFT, GitHubClient, MARK_PULL_REQUEST_READY_FOR_REVIEW};
-    use crate::github::{GitHubError, GitHubPullRequestOptions, GitHubService};
+    use super::{
+        CONVERT_PULL_REQUEST_TO_DRAFT, Connection, GitHubClient,
+        MARK_PULL_REQUEST_READY_FOR_REVIEW, PULL_REQUEST_FILES, SEARCH_MERGED_PULL_REQUESTS,
+        build_octocrab, search_query,
+    };
+    use seal_cache::Cache;
+    use seal_forge::{ForgeService, IssueReference, PullRequestOptions};
+
+    use crate::github::{Credentials, GitHubError, RetryConfig};
 
     const OWNER: &str = "owner";
     const REPO: &str = "repo";
@@ -388,19 +938,45 @@ mod tests {
     const PULL_NUMBER: u64 = 8;
     const NODE_ID: &str = "PR_8";
 
+    /// Retries without waiting, unless GitHub asks for a wait.
+    fn test_retry() -> RetryConfig {
+        RetryConfig {
+            max_retries: 2,
+            max_wait: Duration::from_secs(60),
+            initial_backoff: Duration::ZERO,
+        }
+    }
+
     fn test_client(server: &MockServer, authenticated: bool) -> Result<GitHubClient> {
-        let octocrab = Octocrab::builder().base_uri(server.uri())?.build()?;
+        cached_test_client(server, authenticated, None)
+    }
 
-        Ok(GitHubClient {
+    fn cached_test_client(
+        server: &MockServer,
+        authenticated: bool,
+        cache: Option<Cache>,
+    ) -> Result<GitHubClient> {
+        let octocrab = build_octocrab(&server.uri(), None, &test_retry(), cache.clone())?;
+        let connection = Connection {
+            graphql: octocrab.clone(),
             octocrab,
+            authenticated,
+        };
+
+        Ok(GitHubClient {
+            connection: OnceLock::from(connection),
+            credentials: Credentials::Anonymous,
+            retry: test_retry(),
+            api_url: server.uri(),
             owner: OWNER.to_string(),
             repo: REPO.to_string(),
-            authenticated,
+            cache,
+            base: tokio::sync::OnceCell::new_with(Some(BASE.to_string())),
         })
     }
 
-    fn options(draft: bool) -> GitHubPullRequestOptions {
-        GitHubPullRequestOptions {
+    fn options(draft: bool) -> PullRequestOptions {
+        PullRequestOptions {
             title: TITLE.to_string(),
             body: BODY.to_string(),
             head: HEAD.to_string(),
@@ -448,6 +1024,7 @@ mod tests {
         Mock::given(method("GET"))
             .and(path(format!("/repos/{OWNER}/{REPO}/pulls")))
             .and(query_param("state", "closed"))
+            .and(query_param("base", BASE))
             .and(query_param("sort", "updated"))
             .and(query_param("direction", "desc"))
             .and(query_param("per_page", "100"))
@@ -502,26 +1079,86 @@ mod tests {
             .await;
     }
 
-    async fn assert_received_paths(server: &MockServer, expected: &[&str]) -> Result<()> {
-        let actual = server
-            .received_requests()
-            .await
-            .context("Wiremock request recording is disabled")?
-            .iter()
-            .map(|request| request.url.path().to_string())
-            .collect::<Vec<_>>();
-        let expected = expected.iter().map(ToString::to_string).collect::<Vec<_>>();
-        assert_eq!(actual, expected);
-        Ok(())
+    fn search_pull_request(number: u64, merged_at: &str, typename: &str, login: &str) -> Value {
+        json!({
+            "number": number,
+            "title": format!("Pull request {number}"),
+            "url": format!("https://github.com/{OWNER}/{REPO}/pull/{number}"),
+            "mergedAt": merged_at,
+            "author": {
+                "__typename": typename,
+                "login": login,
+                "url": format!("https://github.com/{login}"),
+            },
+            "labels": { "nodes": [{ "name": "bug" }] },
+            "closingIssuesReferences": {
+                "nodes": [{
+                    "number": number + 100,
+                    "url": format!("https://github.com/{OWNER}/{REPO}/issues/{}", number + 100),
+                }],
+            },
+            "files": files_page(None, &["src/lib.rs"]),
+        })
     }
 
-    #[tokio::test]
-    async fn unauthenticated_preflight_and_write_fail_without_requests() -> Result<()> {
-        let server = MockServer::start().await;
-        let client = test_client(&server, false)?;
-
-        let preflight_error = client
-            .ensure_authenticated()
+    fn files_page(end_cursor: Option<&str>, paths: &[&str]) -> Value {
+        json!({
+            "pageInfo": {
+                "hasNextPage": end_cursor.is_some(),
+                "endCursor": end_cursor,
+            },
+            "nodes": paths.iter().map(|path| json!({ "path": path })).collect::<Vec<_>>(),
+        })
+    }
+
+    fn search_page(issue_count: u64, end_cursor: Option<&str>, nodes: &[Value]) -> Value {
+        json!({
+            "data": {
+                "search": {
+                    "issueCount": issue_count,
+                    "pageInfo": {
+                        "hasNextPage": end_cursor.is_some(),
+                        "endCursor": end_cursor,
+                    },
+                    "nodes": nodes,
+                }
+            }
+        })
+    }
+
+    async fn mount_search(server: &MockServer, query: &str, cursor: Option<&str>, response: Value) {
+        Mock::given(method("POST"))
+            .and(path("/graphql"))
+            .and(body_json(json!({
+                "query": SEARCH_MERGED_PULL_REQUESTS,
+                "variables": { "query": query, "cursor": cursor },
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(response))
+            .expect(1)
+            .mount(server)
+            .await;
+    }
+
+    async fn assert_received_paths(server: &MockServer, expected: &[&str]) -> Result<()> {
+        let actual = server
+            .received_requests()
+            .await
+            .context("Wiremock request recording is disabled")?
+            .iter()
+            .map(|request| request.url.path().to_string())
+            .collect::<Vec<_>>();
+        let expected = expected.iter().map(ToString::to_string).collect::<Vec<_>>();
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn unauthenticated_preflight_and_write_fail_without_requests() -> Result<()> {
+        let server = MockServer::start().await;
+        let client = test_client(&server, false)?;
+
+        let preflight_error = client
+            .ensure_authenticated()
             .expect_err("authentication preflight should fail");
         assert!(matches!(
             preflight_error.downcast_ref::<GitHubError>(),
@@ -542,7 +1179,7 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn gets_pr_created_before_release_when_merged_after_release() -> Result<()> {
+    async fn unauthenticated_client_lists_pull_requests_merged_after_release() -> Result<()> {
         let server = MockServer::start().await;
         mount_closed_pull_requests_page(
             &server,
@@ -577,7 +1214,7 @@ mod tests {
         .await;
 
         let since = "2026-01-01T00:00:00Z".parse::<DateTime<Utc>>()?;
-        let pull_requests = test_client(&server, true)?
+        let pull_requests = test_client(&server, false)?
             .get_prs_between(Some(&since), None)
             .await?;
 
@@ -592,6 +1229,258 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn searches_pull_requests_merged_between_releases() -> Result<()> {
+        let server = MockServer::start().await;
+        let query = "repo:owner/repo is:pr is:merged base:main merged:2026-01-01T00:00:00Z..2026-02-01T00:00:00Z";
+        mount_search(
+            &server,
+            query,
+            None,
+            search_page(
+                2,
+                Some("cursor-1"),
+                &[
+                    search_pull_request(1, "2026-01-01T00:00:00Z", "User", "alice"),
+                    search_pull_request(2, "2026-01-10T00:00:00Z", "Bot", "dependabot"),
+                ],
+            ),
+        )
+        .await;
+        mount_search(
+            &server,
+            query,
+            Some("cursor-1"),
+            search_page(
+                1,
+                None,
+                &[search_pull_request(
+                    3,
+                    "2026-01-20T00:00:00Z",
+                    "User",
+                    "bob",
+                )],
+            ),
+        )
+        .await;
+
+        let since = "2026-01-01T00:00:00Z".parse::<DateTime<Utc>>()?;
+        let until = "2026-02-01T00:00:00Z".parse::<DateTime<Utc>>()?;
+        let pull_requests = test_client(&server, true)?
+            .get_prs_between(Some(&since), Some(&until))
+            .await?;
+
+        assert_eq!(
+            pull_requests
+                .iter()
+                .map(|pull_request| pull_request.number)
+                .collect::<Vec<_>>(),
+            vec![3, 2]
+        );
+        let bot = &pull_requests[1];
+        assert_eq!(bot.author.as_deref(), Some("dependabot[bot]"));
+        assert_eq!(
+            bot.author_url.as_deref(),
+            Some("https://github.com/dependabot")
+        );
+        assert_eq!(bot.labels, ["bug"]);
+        assert_eq!(bot.url, "https://github.com/owner/repo/pull/2");
+        assert_eq!(
+            bot.closed_issues,
+            [IssueReference {
+                number: 102,
+                url: "https://github.com/owner/repo/issues/102".to_string(),
+            }]
+        );
+        assert_eq!(bot.changed_files, ["src/lib.rs"]);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn pages_the_files_of_pull_requests_with_more_than_a_page_of_them() -> Result<()> {
+        let server = MockServer::start().await;
+        let query = "repo:owner/repo is:pr is:merged base:main merged:2026-01-01T00:00:00Z..2026-02-01T00:00:00Z";
+        let mut pull_request = search_pull_request(1, "2026-01-10T00:00:00Z", "User", "alice");
+        pull_request["files"] = files_page(Some("files-1"), &["src/lib.rs"]);
+        mount_search(&server, query, None, search_page(1, None, &[pull_request])).await;
+        for (cursor, page) in [
+            ("files-1", files_page(Some("files-2"), &["src/main.rs"])),
+            ("files-2", files_page(None, &["README.md"])),
+        ] {
+            Mock::given(method("POST"))
+                .and(path("/graphql"))
+                .and(body_json(json!({
+                    "query": PULL_REQUEST_FILES,
+                    "variables": { "owner": OWNER, "repo": REPO, "number": 1, "cursor": cursor },
+                })))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                    "data": { "repository": { "pullRequest": { "files": page } } },
+                })))
+                .expect(1)
+                .mount(&server)
+                .await;
+        }
+
+        let since = "2026-01-01T00:00:00Z".parse::<DateTime<Utc>>()?;
+        let until = "2026-02-01T00:00:00Z".parse::<DateTime<Utc>>()?;
+        let pull_requests = test_client(&server, true)?
+            .get_prs_between(Some(&since), Some(&until))
+            .await?;
+
+        assert_eq!(
+            pull_requests[0].changed_files,
+            ["src/lib.rs", "src/main.rs", "README.md"]
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn splits_searches_with_more_results_than_search_returns() -> Result<()> {
+        let server = MockServer::start().await;
+        mount_search(
+            &server,
+            "repo:owner/repo is:pr is:merged base:main merged:2026-01-01T00:00:00Z..2026-01-03T00:00:00Z",
+            None,
+            search_page(1001, Some("cursor-1"), &[]),
+        )
+        .await;
+        // A pull request merged at the midpoint belongs to the first half only.
+        let middle = search_pull_request(2, "2026-01-02T00:00:00Z", "User", "alice");
+        mount_search(
+            &server,
+            "repo:owner/repo is:pr is:merged base:main merged:2026-01-01T00:00:00Z..2026-01-02T00:00:00Z",
+            None,
+            search_page(
+                2,
+                None,
+                &[
+                    search_pull_request(1, "2026-01-01T12:00:00Z", "User", "alice"),
+                    middle.clone(),
+                ],
+            ),
+        )
+        .await;
+        mount_search(
+            &server,
+            "repo:owner/repo is:pr is:merged base:main merged:2026-01-02T00:00:00Z..2026-01-03T00:00:00Z",
+            None,
+            search_page(
+                2,
+                None,
+                &[
+                    middle,
+                    search_pull_request(3, "2026-01-02T12:00:00Z", "User", "bob"),
+                ],
+            ),
+        )
+        .await;
+
+        let since = "2026-01-01T00:00:00Z".parse::<DateTime<Utc>>()?;
+        let until = "2026-01-03T00:00:00Z".parse::<DateTime<Utc>>()?;
+        let pull_requests = test_client(&server, true)?
+            .get_prs_between(Some(&since), Some(&until))
+            .await?;
+
+        assert_eq!(
+            pull_requests
+                .iter()
+                .map(|pull_request| pull_request.number)
+                .collect::<Vec<_>>(),
+            vec![3, 2, 1]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn search_query_only_matches_pull_requests_merged_into_the_base() -> Result<()> {
+        let since = "2026-01-01T00:00:00Z".parse::<DateTime<Utc>>()?;
+        let until = "2026-02-01T00:00:00Z".parse::<DateTime<Utc>>()?;
+
+        assert_eq!(
+            search_query(OWNER, REPO, "release/1.x", Some(since), Some(until)),
+            "repo:owner/repo is:pr is:merged base:release/1.x \
+             merged:2026-01-01T00:00:00Z..2026-02-01T00:00:00Z"
+        );
+        assert_eq!(
+            search_query(OWNER, REPO, BASE, None, None),
+            "repo:owner/repo is:pr is:merged base:main"
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn searches_the_default_branch_without_a_configured_base() -> Result<()> {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path(format!("/repos/{OWNER}/{REPO}")))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "id": 1,
+                "name": REPO,
+                "url": format!("https://api.github.com/repos/{OWNER}/{REPO}"),
+                "default_branch": "develop",
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        for (since, until) in [
+            ("2026-01-01T00:00:00Z", "2026-02-01T00:00:00Z"),
+            ("2026-02-01T00:00:00Z", "2026-03-01T00:00:00Z"),
+        ] {
+            mount_search(
+                &server,
+                &format!("repo:owner/repo is:pr is:merged base:develop merged:{since}..{until}"),
+                None,
+                search_page(0, None, &[]),
+            )
+            .await;
+        }
+
+        let client = test_client(&server, true)?.with_base(None);
+        for (since, until) in [
+            ("2026-01-01T00:00:00Z", "2026-02-01T00:00:00Z"),
+            ("2026-02-01T00:00:00Z", "2026-03-01T00:00:00Z"),
+        ] {
+            let since = since.parse::<DateTime<Utc>>()?;
+            let until = until.parse::<DateTime<Utc>>()?;
+            assert!(
+                client
+                    .get_prs_between(Some(&since), Some(&until))
+                    .await?
+                    .is_empty()
+            );
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn graphql_errors_include_search_context() -> Result<()> {
+        let server = MockServer::start().await;
+        mount_search(
+            &server,
+            "repo:owner/repo is:pr is:merged base:main merged:>=2026-01-01T00:00:00Z",
+            None,
+            json!({ "data": null, "errors": [{ "message": "API rate limit exceeded" }] }),
+        )
+        .await;
+
+        let since = "2026-01-01T00:00:00Z".parse::<DateTime<Utc>>()?;
+        let error = test_client(&server, true)?
+            .get_prs_between(Some(&since), None)
+            .await
+            .expect_err("GraphQL errors should fail the search");
+        let message = error.to_string();
+
+        assert!(message.contains("Failed to search GitHub pull requests"));
+        assert!(message.contains("API rate limit exceeded"));
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn creates_pull_request_when_none_exists() -> Result<()> {
         let server = MockServer::start().await;
@@ -814,4 +1703,432 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn resolves_credentials_on_the_first_request() -> Result<()> {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path(format!("/repos/{OWNER}/{REPO}/releases")))
+            .and(header("authorization", "Bearer token"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([release()])))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = GitHubClient::new(
+            &server.uri(),
+            OWNER.to_string(),
+            REPO.to_string(),
+            Credentials::Token("token".to_string()),
+            test_retry(),
+            None,
+        );
+        assert!(client.compare_url("v1.0.0", "v1.1.0").is_some());
+        assert!(client.pull_request_url(1).is_some());
+        assert!(client.connection.get().is_none());
+
+        client.get_latest_release().await?;
+        assert!(client.connection.get().is_some_and(|c| c.authenticated));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn enterprise_routes_rest_and_graphql_requests() -> Result<()> {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/api/v3/repos/owner/repo/releases"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([])))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/api/graphql"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "data": {} })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = GitHubClient::new(
+            &format!("{}/api/v3", server.uri()),
+            OWNER.to_string(),
+            REPO.to_string(),
+            Credentials::Token("token".to_string()),
+            test_retry(),
+            None,
+        );
+        client
+            .get_latest_release()
+            .await
+            .expect_err("repository without releases should fail");
+        client
+            .update_pull_request_draft_state(NODE_ID, true)
+            .await?;
+
+        assert_received_paths(
+            &server,
+            &["/api/v3/repos/owner/repo/releases", "/api/graphql"],
+        )
+        .await
+    }
+
+    fn release() -> Value {
+        json!({
+            "url": "https://api.github.com/repos/owner/repo/releases/1",
+            "html_url": "https://github.com/owner/repo/releases/tag/v1.0.0",
+            "assets_url": "https://api.github.com/repos/owner/repo/releases/1/assets",
+            "upload_url": "https://uploads.github.com/repos/owner/repo/releases/1/assets",
+            "id": 1,
+            "node_id": "RE_1",
+            "tag_name": "v1.0.0",
+            "target_commitish": "main",
+            "name": "v1.0.0",
+            "draft": false,
+            "prerelease": false,
+            "created_at": "2026-01-01T00:00:00Z",
+            "published_at": "2026-01-01T00:00:00Z",
+            "assets": [],
+        })
+    }
+
+    async fn mount_releases_failure(server: &MockServer, response: ResponseTemplate, times: u64) {
+        Mock::given(method("GET"))
+            .and(path(format!("/repos/{OWNER}/{REPO}/releases")))
+            .respond_with(response)
+            .up_to_n_times(times)
+            .expect(times)
+            .mount(server)
+            .await;
+    }
+
+    async fn mount_releases(server: &MockServer) {
+        Mock::given(method("GET"))
+            .and(path(format!("/repos/{OWNER}/{REPO}/releases")))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([release()])))
+            .expect(1)
+            .mount(server)
+            .await;
+    }
+
+    #[tokio::test]
+    async fn retries_transient_server_errors() -> Result<()> {
+        let server = MockServer::start().await;
+        mount_releases_failure(&server, ResponseTemplate::new(502), 2).await;
+        mount_releases(&server).await;
+
+        let release = test_client(&server, true)?.get_latest_release().await?;
+
+        assert_eq!(release.name.as_deref(), Some("v1.0.0"));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn does_not_retry_writes_after_transient_server_errors() -> Result<()> {
+        let server = MockServer::start().await;
+        mount_lookup(&server, Vec::new()).await;
+        Mock::given(method("POST"))
+            .and(path(format!("/repos/{OWNER}/{REPO}/pulls")))
+            .respond_with(ResponseTemplate::new(502))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let error = test_client(&server, true)?
+            .create_or_update_pull_request(options(false))
+            .await
+            .expect_err("a failed create request should not be retried");
+
+        assert!(format!("{error:#}").contains("Failed to create GitHub pull request"));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn retries_secondary_rate_limits_after_retry_after() -> Result<()> {
+        let server = MockServer::start().await;
+        mount_releases_failure(
+            &server,
+            ResponseTemplate::new(403)
+                .insert_header("retry-after", "0")
+                .set_body_json(json!({ "message": "You have exceeded a secondary rate limit" })),
+            1,
+        )
+        .await;
+        mount_releases(&server).await;
+
+        let release = test_client(&server, true)?.get_latest_release().await?;
+
+        assert_eq!(release.name.as_deref(), Some("v1.0.0"));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn resends_graphql_requests_after_rate_limits() -> Result<()> {
+        let server = MockServer::start().await;
+        let query = "repo:owner/repo is:pr is:merged base:main merged:>=2026-01-01T00:00:00Z";
+        Mock::given(method("POST"))
+            .and(path("/graphql"))
+            .respond_with(ResponseTemplate::new(429).insert_header("retry-after", "0"))
+            .up_to_n_times(1)
+            .expect(1)
+            .mount(&server)
+            .await;
+        mount_search(
+            &server,
+            query,
+            None,
+            search_page(
+                1,
+                None,
+                &[search_pull_request(
+                    1,
+                    "2026-01-02T00:00:00Z",
+                    "User",
+                    "alice",
+                )],
+            ),
+        )
+        .await;
+
+        let since = "2026-01-01T00:00:00Z".parse::<DateTime<Utc>>()?;
+        let pull_requests = test_client(&server, true)?
+            .get_prs_between(Some(&since), None)
+            .await?;
+
+        assert_eq!(pull_requests.len(), 1);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn gives_up_when_the_rate_limit_resets_after_the_max_wait() -> Result<()> {
+        let server = MockServer::start().await;
+        let reset = (Utc::now().timestamp() + 3600).to_string();
+        mount_releases_failure(
+            &server,
+            ResponseTemplate::new(403)
+                .insert_header("x-ratelimit-limit", "5000")
+                .insert_header("x-ratelimit-remaining", "0")
+                .insert_header("x-ratelimit-reset", reset.as_str())
+                .set_body_json(json!({ "message": "API rate limit exceeded" })),
+            1,
+        )
+        .await;
+
+        let error = test_client(&server, true)?
+            .get_latest_release()
+            .await
+            .expect_err("a distant rate limit reset should fail");
+        assert!(matches!(
+            error.downcast_ref::<GitHubError>(),
+            Some(GitHubError::RateLimitWaitTooLong {
+                remaining: Some(0),
+                limit: Some(5000),
+                ..
+            })
+        ));
+        let message = format!("{error:#}");
+
+        assert!(
+            message.contains("GitHub API rate limit exceeded with 0 of 5000 requests remaining"),
+            "{message}"
+        );
+        assert!(
+            message.contains("more than the maximum of 60s"),
+            "{message}"
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn gives_up_after_the_max_retries() -> Result<()> {
+        let server = MockServer::start().await;
+        mount_releases_failure(
+            &server,
+            ResponseTemplate::new(429)
+                .insert_header("retry-after", "0")
+                .insert_header("x-ratelimit-remaining", "12"),
+            3,
+        )
+        .await;
+
+        let error = test_client(&server, true)?
+            .get_latest_release()
+            .await
+            .expect_err("a persistent rate limit should fail");
+
+        assert!(
+            format!("{error:#}").contains(
+                "GitHub API rate limit still exceeded after 2 retries, with 12 requests remaining"
+            ),
+            "{error:#}"
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn reuses_cached_responses_that_have_not_been_modified() -> Result<()> {
+        let server = MockServer::start().await;
+        let dir = tempfile::tempdir()?;
+        let cache = Cache::from_path(dir.path());
+        Mock::given(method("GET"))
+            .and(path(format!("/repos/{OWNER}/{REPO}/releases")))
+            .and(header("if-none-match", "\"v1\""))
+            .respond_with(ResponseTemplate::new(304))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path(format!("/repos/{OWNER}/{REPO}/releases")))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("etag", "\"v1\"")
+                    .set_body_json(json!([release()])),
+            )
+            .up_to_n_times(1)
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = cached_test_client(&server, true, Some(cache))?;
+        let first = client.get_latest_release().await?;
+        let second = client.get_latest_release().await?;
+
+        assert_eq!(first.name.as_deref(), Some("v1.0.0"));
+        assert_eq!(second.name.as_deref(), Some("v1.0.0"));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn refresh_skips_conditional_requests() -> Result<()> {
+        let server = MockServer::start().await;
+        let dir = tempfile::tempdir()?;
+        let cache = Cache::from_path(dir.path());
+        Mock::given(method("GET"))
+            .and(path(format!("/repos/{OWNER}/{REPO}/releases")))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("etag", "\"v1\"")
+                    .set_body_json(json!([release()])),
+            )
+            .expect(2)
+            .mount(&server)
+            .await;
+
+        cached_test_client(&server, true, Some(cache.clone()))?
+            .get_latest_release()
+            .await?;
+        cached_test_client(&server, true, Some(cache.with_refresh(true)))?
+            .get_latest_release()
+            .await?;
+
+        let requests = server
+            .received_requests()
+            .await
+            .context("Wiremock request recording is disabled")?;
+        assert!(
+            requests
+                .iter()
+                .all(|request| !request.headers.contains_key("if-none-match"))
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn caches_searches_for_closed_merge_windows() -> Result<()> {
+        let server = MockServer::start().await;
+        let dir = tempfile::tempdir()?;
+        let cache = Cache::from_path(dir.path());
+        let query = "repo:owner/repo is:pr is:merged base:main merged:2026-01-01T00:00:00Z..2026-02-01T00:00:00Z";
+        mount_search(
+            &server,
+            query,
+            None,
+            search_page(
+                1,
+                None,
+                &[search_pull_request(
+                    1,
+                    "2026-01-10T00:00:00Z",
+                    "User",
+                    "alice",
+                )],
+            ),
+        )
+        .await;
+
+        let since = "2026-01-01T00:00:00Z".parse::<DateTime<Utc>>()?;
+        let until = "2026-02-01T00:00:00Z".parse::<DateTime<Utc>>()?;
+        let client = cached_test_client(&server, true, Some(cache))?;
+        let first = client.get_prs_between(Some(&since), Some(&until)).await?;
+        let second = client.get_prs_between(Some(&since), Some(&until)).await?;
+
+        assert_eq!(first.len(), 1);
+        assert_eq!(second.len(), 1);
+        assert_eq!(second[0].author.as_deref(), Some("alice"));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn reports_the_user_and_scopes_of_the_token() -> Result<()> {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/user"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("x-oauth-scopes", "repo, read:org")
+                    .set_body_json(json!({ "login": "octocat" })),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let info = test_client(&server, true)?.token_info().await?;
+
+        assert_eq!(info.login, "octocat");
+        assert_eq!(
+            info.scopes,
+            Some(vec!["repo".to_string(), "read:org".to_string()])
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn fine_grained_tokens_have_no_scopes() -> Result<()> {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/user"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "login": "octocat" })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let info = test_client(&server, true)?.token_info().await?;
+
+        assert_eq!(info.scopes, None);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn rejected_tokens_fail_to_report_the_user() -> Result<()> {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/user"))
+            .respond_with(
+                ResponseTemplate::new(401).set_body_json(json!({ "message": "Bad credentials" })),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let error = test_client(&server, true)?
+            .token_info()
+            .await
+            .expect_err("rejected token should fail");
+
+        assert_eq!(
+            error.to_string(),
+            "Failed to look up the authenticated GitHub user"
+        );
+        assert!(format!("{error:#}").contains("Bad credentials"));
+        Ok(())
+    }
 }
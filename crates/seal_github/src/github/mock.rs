@@ -1,73 +1,116 @@
 use anyhow::Result;
 use chrono::{DateTime, TimeZone, Utc};
 
-use crate::github::{
-    GitHubError, GitHubPullRequest, GitHubPullRequestOptions, GitHubPullRequestReference,
-    GitHubRelease, GitHubService, filter_prs_by_date_range,
+use seal_forge::{
+    ForgeService, IssueReference, PullRequest, PullRequestOptions, PullRequestReference, Release,
+    filter_prs_by_date_range,
 };
 
+use crate::github::GitHubError;
+
 #[derive(Default, Clone)]
 pub struct MockGithubClient {
-    prs: Vec<GitHubPullRequest>,
+    prs: Vec<PullRequest>,
 }
 
 impl MockGithubClient {
     pub fn new() -> Self {
         let prs = vec![
-            GitHubPullRequest {
+            PullRequest {
                 title: "Add new feature X".to_string(),
                 number: 7,
                 url: "https://github.com/owner/repo/pull/7".to_string(),
                 labels: vec!["feature".to_string(), "enhancement".to_string()],
                 author: Some("ignored".to_string()),
+                author_url: Some("https://github.com/ignored".to_string()),
+                body: None,
+                closed_issues: Vec::new(),
+                changed_files: Vec::new(),
                 merged_at: Utc.with_ymd_and_hms(2025, 12, 8, 10, 0, 0).unwrap(),
             },
-            GitHubPullRequest {
+            PullRequest {
                 title: "Add new feature X".to_string(),
                 number: 6,
                 url: "https://github.com/owner/repo/pull/6".to_string(),
                 labels: vec!["feature".to_string(), "enhancement".to_string()],
                 author: Some("alice".to_string()),
+                author_url: Some("https://github.com/alice".to_string()),
+                body: Some(
+                    "Adds feature X.\n\nBREAKING CHANGE: `--x` is now `--feature-x`.".to_string(),
+                ),
+                closed_issues: Vec::new(),
+                changed_files: Vec::new(),
                 merged_at: Utc.with_ymd_and_hms(2025, 12, 8, 10, 0, 0).unwrap(),
             },
-            GitHubPullRequest {
+            PullRequest {
                 title: "Fix critical bug in module Y".to_string(),
                 number: 5,
                 url: "https://github.com/owner/repo/pull/5".to_string(),
                 labels: vec!["bug".to_string()],
                 author: Some("bob".to_string()),
+                author_url: Some("https://github.com/bob".to_string()),
+                body: Some(
+                    "```release-notes\nModule Y no longer panics on empty input.\n```".to_string(),
+                ),
+                closed_issues: vec![
+                    IssueReference {
+                        number: 2,
+                        url: "https://github.com/owner/repo/issues/2".to_string(),
+                    },
+                    IssueReference {
+                        number: 3,
+                        url: "https://github.com/owner/repo/issues/3".to_string(),
+                    },
+                ],
+                changed_files: vec!["src/module_y.rs".to_string()],
                 merged_at: Utc.with_ymd_and_hms(2025, 12, 5, 0, 0, 0).unwrap(),
             },
-            GitHubPullRequest {
+            PullRequest {
                 title: "Update documentation".to_string(),
                 number: 4,
                 url: "https://github.com/owner/repo/pull/4".to_string(),
                 labels: vec!["documentation".to_string()],
                 author: Some("joe".to_string()),
+                author_url: Some("https://github.com/joe".to_string()),
+                body: None,
+                closed_issues: Vec::new(),
+                changed_files: Vec::new(),
                 merged_at: Utc.with_ymd_and_hms(2025, 12, 3, 0, 0, 0).unwrap(),
             },
-            GitHubPullRequest {
+            PullRequest {
                 title: "Update documentation".to_string(),
                 number: 3,
                 url: "https://github.com/owner/repo/pull/3".to_string(),
                 labels: vec!["documentation".to_string()],
                 author: Some("alice".to_string()),
+                author_url: Some("https://github.com/alice".to_string()),
+                body: None,
+                closed_issues: Vec::new(),
+                changed_files: Vec::new(),
                 merged_at: Utc.with_ymd_and_hms(2025, 11, 25, 0, 0, 0).unwrap(),
             },
-            GitHubPullRequest {
+            PullRequest {
                 title: "Update documentation".to_string(),
                 number: 2,
                 url: "https://github.com/owner/repo/pull/2".to_string(),
                 labels: vec!["documentation".to_string()],
                 author: Some("alice".to_string()),
+                author_url: Some("https://github.com/alice".to_string()),
+                body: None,
+                closed_issues: Vec::new(),
+                changed_files: Vec::new(),
                 merged_at: Utc.with_ymd_and_hms(2025, 11, 10, 0, 0, 0).unwrap(),
             },
-            GitHubPullRequest {
+            PullRequest {
                 title: "Update documentation".to_string(),
                 number: 1,
                 url: "https://github.com/owner/repo/pull/1".to_string(),
                 labels: vec!["documentation".to_string()],
                 author: Some("alice".to_string()),
+                author_url: Some("https://github.com/alice".to_string()),
+                body: None,
+                closed_issues: Vec::new(),
+                changed_files: Vec::new(),
                 merged_at: Utc.with_ymd_and_hms(2025, 11, 3, 0, 0, 0).unwrap(),
             },
         ];
@@ -75,7 +118,7 @@ impl MockGithubClient {
     }
 }
 
-impl GitHubService for MockGithubClient {
+impl ForgeService for MockGithubClient {
     fn ensure_authenticated(&self) -> Result<()> {
         if std::env::var_os("SEAL_TEST_GITHUB_AUTHENTICATION_REQUIRED").is_some() {
             return Err(GitHubError::AuthenticationRequired.into());
@@ -84,14 +127,23 @@ impl GitHubService for MockGithubClient {
         Ok(())
     }
 
+    fn compare_url(&self, base: &str, head: &str) -> Option<String> {
+        Some(format!(
+            "https://github.com/owner/repo/compare/{base}...{head}"
+        ))
+    }
+
+    fn pull_request_url(&self, number: u64) -> Option<String> {
+        Some(format!("https://github.com/owner/repo/pull/{number}"))
+    }
+
     fn get_latest_release(
         &self,
-    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<GitHubRelease>> + Send + '_>>
-    {
+    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<Release>> + Send + '_>> {
         Box::pin(async {
             use chrono::TimeZone;
 
-            Ok(GitHubRelease {
+            Ok(Release {
                 created_at: Utc.with_ymd_and_hms(2025, 12, 1, 0, 0, 0).unwrap(),
                 name: Some("v1.0.0".to_string()),
             })
@@ -100,21 +152,21 @@ impl GitHubService for MockGithubClient {
 
     fn get_all_releases(
         &self,
-    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<Vec<GitHubRelease>>> + Send + '_>>
+    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<Vec<Release>>> + Send + '_>>
     {
         Box::pin(async {
             use chrono::TimeZone;
 
             Ok(vec![
-                GitHubRelease {
+                Release {
                     created_at: Utc.with_ymd_and_hms(2025, 11, 5, 0, 0, 0).unwrap(),
                     name: None,
                 },
-                GitHubRelease {
+                Release {
                     created_at: Utc.with_ymd_and_hms(2025, 11, 15, 0, 0, 0).unwrap(),
                     name: Some("0.2.0".to_string()),
                 },
-                GitHubRelease {
+                Release {
                     created_at: Utc.with_ymd_and_hms(2025, 12, 1, 0, 0, 0).unwrap(),
                     name: Some("v1.0.0".to_string()),
                 },
@@ -126,9 +178,8 @@ impl GitHubService for MockGithubClient {
         &self,
         since: Option<&DateTime<Utc>>,
         until: Option<&DateTime<Utc>>,
-    ) -> std::pin::Pin<
-        Box<dyn std::future::Future<Output = Result<Vec<GitHubPullRequest>>> + Send + '_>,
-    > {
+    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<Vec<PullRequest>>> + Send + '_>>
+    {
         let since = since.copied();
         let until = until.copied();
         Box::pin(async move {
@@ -138,26 +189,11 @@ impl GitHubService for MockGithubClient {
         })
     }
 
-    fn get_prs(
-        &self,
-        max: Option<usize>,
-    ) -> std::pin::Pin<
-        Box<dyn std::future::Future<Output = Result<Vec<GitHubPullRequest>>> + Send + '_>,
-    > {
-        Box::pin(async move {
-            let mut prs = self.prs.clone();
-            if let Some(max) = max {
-                prs.truncate(max);
-            }
-            Ok(prs)
-        })
-    }
-
     fn create_or_update_pull_request(
         &self,
-        options: GitHubPullRequestOptions,
+        options: PullRequestOptions,
     ) -> std::pin::Pin<
-        Box<dyn std::future::Future<Output = Result<GitHubPullRequestReference>> + Send + '_>,
+        Box<dyn std::future::Future<Output = Result<PullRequestReference>> + Send + '_>,
     > {
         Box::pin(async move {
             let number = if options.title == "Release v1.2.4"
@@ -171,7 +207,7 @@ impl GitHubService for MockGithubClient {
                 9
             };
 
-            Ok(GitHubPullRequestReference {
+            Ok(PullRequestReference {
                 url: format!("https://github.com/owner/repo/pull/{number}"),
             })
         })
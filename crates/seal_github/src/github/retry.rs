@@ -0,0 +1,430 @@
+use std::future::{Future, poll_fn};
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+use bytes::Bytes;
+use chrono::{DateTime, Utc};
+use http_body_util::BodyExt;
+use hyper::body::Body;
+use hyper::{HeaderMap, Method, Request, Response, StatusCode};
+use tower::{Layer, Service};
+
+use crate::github::GitHubError;
+
+type BoxError = Box<dyn std::error::Error + Send + Sync>;
+
+/// How GitHub API requests are retried after rate limits and transient failures.
+#[derive(Debug, Clone, Copy)]
+pub struct RetryConfig {
+    /// The number of times a request is retried before giving up.
+    pub(crate) max_retries: u32,
+    /// The longest single wait before a retry.
+    pub(crate) max_wait: Duration,
+    /// The first backoff delay, doubled after every retry.
+    pub(crate) initial_backoff: Duration,
+}
+
+impl RetryConfig {
+    /// Retry with the default limits, waiting at most `max_wait` before each retry.
+    pub fn new(max_wait: Duration) -> Self {
+        Self {
+            max_wait,
+            ..Self::default()
+        }
+    }
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            max_wait: Duration::from_secs(60),
+            initial_backoff: Duration::from_secs(1),
+        }
+    }
+}
+
+/// What to do with the response to an attempt.
+#[derive(Debug)]
+enum Decision {
+    /// Return the response, or the error, as is.
+    Return,
+    /// Send the request again after a delay.
+    Retry(Duration),
+    /// Fail with an error instead of waiting for the rate limit to reset.
+    GiveUp(GitHubError),
+}
+
+impl RetryConfig {
+    fn backoff(&self, attempt: u32) -> Duration {
+        self.initial_backoff
+            .saturating_mul(2u32.saturating_pow(attempt))
+            .min(self.max_wait)
+    }
+
+    /// Decide whether to retry after `attempt` retries, given the response status and headers,
+    /// or `None` if the request failed without a response.
+    ///
+    /// Transient failures are only retried for `idempotent` requests, since a write may have
+    /// been applied before the failure. Rate-limited requests were rejected, so any is retried.
+    fn decide(
+        &self,
+        attempt: u32,
+        idempotent: bool,
+        response: Option<(StatusCode, &HeaderMap)>,
+        now: DateTime<Utc>,
+    ) -> Decision {
+        let Some((status, headers)) = response else {
+            return if idempotent && attempt < self.max_retries {
+                Decision::Retry(self.backoff(attempt))
+            } else {
+                Decision::Return
+            };
+        };
+
+        if is_rate_limited(status, headers) {
+            let remaining = header_u64(headers, "x-ratelimit-remaining");
+            let limit = header_u64(headers, "x-ratelimit-limit");
+            if attempt >= self.max_retries {
+                return Decision::GiveUp(GitHubError::RateLimitRetriesExhausted {
+                    remaining,
+                    limit,
+                    retries: attempt,
+                });
+            }
+
+            // Secondary rate limits without either header call for an increasing wait.
+            let wait = rate_limit_wait(headers, now).unwrap_or_else(|| self.backoff(attempt));
+            if wait > self.max_wait {
+                return Decision::GiveUp(GitHubError::RateLimitWaitTooLong {
+                    remaining,
+                    limit,
+                    wait_secs: wait.as_secs(),
+                    max_wait_secs: self.max_wait.as_secs(),
+                });
+            }
+            return Decision::Retry(wait);
+        }
+
+        if idempotent && is_transient(status) && attempt < self.max_retries {
+            return Decision::Retry(self.backoff(attempt));
+        }
+
+        Decision::Return
+    }
+}
+
+/// Whether sending `request` again is safe: a `GET` or `HEAD`, or a GraphQL query that is not a
+/// mutation.
+fn is_idempotent(request: &hyper::http::request::Parts, body: &Bytes) -> bool {
+    if matches!(request.method, Method::GET | Method::HEAD) {
+        return true;
+    }
+    if request.method != Method::POST || !request.uri.path().ends_with("/graphql") {
+        return false;
+    }
+
+    let Ok(body) = serde_json::from_slice::<serde_json::Value>(body) else {
+        return false;
+    };
+    body.get("query")
+        .and_then(serde_json::Value::as_str)
+        .is_some_and(|query| {
+            let query = query.trim_start();
+            query.starts_with('{') || query.starts_with("query")
+        })
+}
+
+/// Whether GitHub rejected a request because of a primary or secondary rate limit.
+fn is_rate_limited(status: StatusCode, headers: &HeaderMap) -> bool {
+    status == StatusCode::TOO_MANY_REQUESTS
+        || (status == StatusCode::FORBIDDEN
+            && (headers.contains_key("retry-after")
+                || header_u64(headers, "x-ratelimit-remaining") == Some(0)))
+}
+
+fn is_transient(status: StatusCode) -> bool {
+    matches!(
+        status,
+        StatusCode::INTERNAL_SERVER_ERROR
+            | StatusCode::BAD_GATEWAY
+            | StatusCode::SERVICE_UNAVAILABLE
+            | StatusCode::GATEWAY_TIMEOUT
+    )
+}
+
+/// The wait GitHub asks for, from `Retry-After` or, once the quota is spent, `X-RateLimit-Reset`.
+fn rate_limit_wait(headers: &HeaderMap, now: DateTime<Utc>) -> Option<Duration> {
+    if let Some(seconds) = header_u64(headers, "retry-after") {
+        return Some(Duration::from_secs(seconds));
+    }
+
+    if header_u64(headers, "x-ratelimit-remaining") == Some(0) {
+        let reset = header_u64(headers, "x-ratelimit-reset")?;
+        let reset = DateTime::from_timestamp(i64::try_from(reset).ok()?, 0)?;
+        return Some((reset - now).to_std().unwrap_or_default());
+    }
+
+    None
+}
+
+fn header_u64(headers: &HeaderMap, name: &str) -> Option<u64> {
+    headers.get(name)?.to_str().ok()?.trim().parse().ok()
+}
+
+/// Retries GitHub API requests according to a [`RetryConfig`].
+#[derive(Debug, Clone)]
+pub(crate) struct RetryLayer {
+    config: RetryConfig,
+}
+
+impl RetryLayer {
+    pub(crate) fn new(config: RetryConfig) -> Self {
+        Self { config }
+    }
+}
+
+impl<S> Layer<S> for RetryLayer {
+    type Service = Retry<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        Retry {
+            inner,
+            config: self.config,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct Retry<S> {
+    inner: S,
+    config: RetryConfig,
+}
+
+impl<S, ReqBody, ResBody> Service<Request<ReqBody>> for Retry<S>
+where
+    S: Service<Request<ReqBody>, Response = Response<ResBody>> + Clone + Send + 'static,
+    S::Future: Send,
+    S::Error: Into<BoxError>,
+    ReqBody: Body<Data = Bytes> + From<Bytes> + Send + 'static,
+    ReqBody::Error: Into<BoxError>,
+    ResBody: Send + 'static,
+{
+    type Response = Response<ResBody>;
+    type Error = BoxError;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx).map_err(Into::into)
+    }
+
+    fn call(&mut self, request: Request<ReqBody>) -> Self::Future {
+        // Keep the service that was polled ready for the first attempt.
+        let clone = self.inner.clone();
+        let mut inner = std::mem::replace(&mut self.inner, clone);
+        let config = self.config;
+
+        Box::pin(async move {
+            // Buffer the body so that it can be sent again.
+            let (parts, body) = request.into_parts();
+            let body = body.collect().await.map_err(Into::into)?.to_bytes();
+            let idempotent = is_idempotent(&parts, &body);
+
+            let mut attempt = 0;
+            loop {
+                if attempt > 0 {
+                    poll_fn(|cx| inner.poll_ready(cx))
+                        .await
+                        .map_err(Into::into)?;
+                }
+                let request = Request::from_parts(parts.clone(), ReqBody::from(body.clone()));
+                let result: Result<_, BoxError> = inner.call(request).await.map_err(Into::into);
+
+                let response = result
+                    .as_ref()
+                    .ok()
+                    .map(|response| (response.status(), response.headers()));
+                let delay = match config.decide(attempt, idempotent, response, Utc::now()) {
+                    Decision::Return => return result,
+                    Decision::Retry(delay) => delay,
+                    Decision::GiveUp(error) => return Err(error.into()),
+                };
+                drop(result);
+
+                tracing::warn!(
+                    "GitHub request to {} failed; retrying in {}s",
+                    parts.uri,
+                    delay.as_secs()
+                );
+                tokio::time::sleep(delay).await;
+                attempt += 1;
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bytes::Bytes;
+    use chrono::{DateTime, Utc};
+    use hyper::{HeaderMap, StatusCode};
+
+    use super::{Decision, RetryConfig, is_idempotent};
+    use crate::github::GitHubError;
+
+    fn headers(pairs: &[(&'static str, &str)]) -> HeaderMap {
+        pairs
+            .iter()
+            .map(|(name, value)| (*name, value.parse().unwrap()))
+            .map(|(name, value)| (hyper::header::HeaderName::from_static(name), value))
+            .collect()
+    }
+
+    fn now() -> DateTime<Utc> {
+        "2026-01-01T00:00:00Z".parse().unwrap()
+    }
+
+    #[test]
+    fn waits_until_the_primary_rate_limit_resets() {
+        let reset = (now().timestamp() + 30).to_string();
+        let headers = headers(&[
+            ("x-ratelimit-limit", "5000"),
+            ("x-ratelimit-remaining", "0"),
+            ("x-ratelimit-reset", &reset),
+        ]);
+
+        let decision =
+            RetryConfig::default().decide(0, true, Some((StatusCode::FORBIDDEN, &headers)), now());
+
+        assert!(matches!(decision, Decision::Retry(wait) if wait == Duration::from_secs(30)));
+    }
+
+    #[test]
+    fn backs_off_exponentially_up_to_the_max_wait() {
+        let config = RetryConfig {
+            max_retries: 10,
+            max_wait: Duration::from_secs(5),
+            initial_backoff: Duration::from_secs(1),
+        };
+        let waits = (0..4)
+            .map(|attempt| {
+                match config.decide(
+                    attempt,
+                    true,
+                    Some((StatusCode::BAD_GATEWAY, &HeaderMap::new())),
+                    now(),
+                ) {
+                    Decision::Retry(wait) => wait.as_secs(),
+                    decision => panic!("expected a retry, got {decision:?}"),
+                }
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(waits, [1, 2, 4, 5]);
+    }
+
+    #[test]
+    fn returns_client_errors_without_retrying() {
+        let decision = RetryConfig::default().decide(
+            0,
+            true,
+            Some((StatusCode::FORBIDDEN, &HeaderMap::new())),
+            now(),
+        );
+
+        assert!(matches!(decision, Decision::Return));
+    }
+
+    #[test]
+    fn retries_only_rate_limits_for_writes() {
+        let config = RetryConfig::default();
+        let rate_limited = headers(&[("retry-after", "1")]);
+
+        let transient = config.decide(
+            0,
+            false,
+            Some((StatusCode::BAD_GATEWAY, &HeaderMap::new())),
+            now(),
+        );
+        let disconnected = config.decide(0, false, None, now());
+        let rate_limit = config.decide(
+            0,
+            false,
+            Some((StatusCode::FORBIDDEN, &rate_limited)),
+            now(),
+        );
+
+        assert!(matches!(transient, Decision::Return));
+        assert!(matches!(disconnected, Decision::Return));
+        assert!(matches!(rate_limit, Decision::Retry(wait) if wait == Duration::from_secs(1)));
+    }
+
+    #[test]
+    fn graphql_queries_are_idempotent_but_mutations_are_not() {
+        let request = |method: &str, uri: &str| {
+            hyper::Request::builder()
+                .method(method)
+                .uri(uri)
+                .body(())
+                .unwrap()
+                .into_parts()
+                .0
+        };
+        let body = |query: &str| Bytes::from(serde_json::json!({ "query": query }).to_string());
+        let graphql = request("POST", "https://api.github.com/graphql");
+
+        assert!(is_idempotent(
+            &request("GET", "https://api.github.com/repos/o/r"),
+            &Bytes::new()
+        ));
+        assert!(is_idempotent(
+            &graphql,
+            &body("query Search { search { issueCount } }")
+        ));
+        assert!(is_idempotent(&graphql, &body("{ viewer { login } }")));
+        assert!(!is_idempotent(
+            &graphql,
+            &body("mutation Ready { ready { isDraft } }")
+        ));
+        assert!(!is_idempotent(
+            &request("POST", "https://api.github.com/repos/o/r/pulls"),
+            &Bytes::new()
+        ));
+        assert!(!is_idempotent(
+            &request("PATCH", "https://api.github.com/repos/o/r/pulls/1"),
+            &Bytes::new()
+        ));
+    }
+
+    #[test]
+    fn gives_up_when_the_reset_is_beyond_the_max_wait() {
+        let reset = (now().timestamp() + 3600).to_string();
+        let headers = headers(&[
+            ("x-ratelimit-limit", "5000"),
+            ("x-ratelimit-remaining", "0"),
+            ("x-ratelimit-reset", &reset),
+        ]);
+
+        let decision =
+            RetryConfig::default().decide(0, true, Some((StatusCode::FORBIDDEN, &headers)), now());
+
+        let Decision::GiveUp(error) = decision else {
+            panic!("expected to give up, got {decision:?}");
+        };
+        assert!(matches!(
+            error,
+            GitHubError::RateLimitWaitTooLong {
+                wait_secs: 3600,
+                ..
+            }
+        ));
+        assert_eq!(
+            error.to_string(),
+            "GitHub API rate limit exceeded with 0 of 5000 requests remaining; retrying requires \
+             waiting 3600s, more than the maximum of 60s set by `github.max-retry-wait`"
+        );
+    }
+}
@@ -1,137 +1,114 @@
-use anyhow::{Context, Result};
-use std::{path::Path, process::Command};
-
-pub fn get_git_remote_url<P: AsRef<Path>>(current_directory: P) -> Result<String> {
-    let output = Command::new("git")
-        .args(["config", "--get", "remote.origin.url"])
-        .current_dir(current_directory)
-        .output()
-        .context("Failed to execute git config")?;
+/// The environment variable that overrides the GitHub API URL, as set by GitHub Actions.
+const GITHUB_API_URL_ENV: &str = "GITHUB_API_URL";
+
+const GITHUB_HOST: &str = "github.com";
+const GITHUB_API_URL: &str = "https://api.github.com";
+
+/// The REST API URL for a repository on `host`.
+///
+/// Uses `configured` when set, then the `GITHUB_API_URL` environment variable, and otherwise
+/// `https://api.github.com` for `github.com` or the GitHub Enterprise Server default of
+/// `https://<host>/api/v3`.
+pub fn github_api_url(host: &str, configured: Option<&str>) -> String {
+    resolve_api_url(host, configured, github_api_url_from_env().as_deref())
+}
 
-    let url = String::from_utf8(output.stdout)
-        .context("Git remote URL is not valid UTF-8")?
-        .trim()
-        .to_string();
+/// The GitHub API URL set in the `GITHUB_API_URL` environment variable, if any.
+pub fn github_api_url_from_env() -> Option<String> {
+    std::env::var(GITHUB_API_URL_ENV)
+        .ok()
+        .filter(|url| !url.trim().is_empty())
+}
 
-    if url.is_empty() {
-        anyhow::bail!("No remote URL found");
+fn resolve_api_url(host: &str, configured: Option<&str>, env_url: Option<&str>) -> String {
+    if let Some(url) = configured.or(env_url) {
+        return url.trim_end_matches('/').to_string();
     }
 
-    Ok(url)
+    if host == GITHUB_HOST {
+        GITHUB_API_URL.to_string()
+    } else {
+        format!("https://{host}/api/v3")
+    }
 }
 
-pub fn parse_github_repo(repo_url: &str) -> Result<(String, String)> {
-    let url = repo_url
-        .trim_end_matches('/')
-        .trim_end_matches(".git")
-        .to_string();
-
-    let parts: Vec<&str> = if url.starts_with("https://github.com/") {
-        url.trim_start_matches("https://github.com/")
-            .split('/')
-            .collect()
-    } else if url.starts_with("git@github.com:") {
-        url.trim_start_matches("git@github.com:")
-            .split('/')
-            .collect()
-    } else {
-        anyhow::bail!("Invalid GitHub repository URL: {repo_url}");
-    };
+/// The base URL that GraphQL requests are sent relative to, for the REST API at `api_url`.
+///
+/// GitHub Enterprise Server serves REST under `/api/v3` and GraphQL at `/api/graphql`, while
+/// `api.github.com` serves both from the root.
+pub(crate) fn graphql_base_url(api_url: &str) -> &str {
+    let api_url = api_url.trim_end_matches('/');
+    api_url.strip_suffix("/v3").unwrap_or(api_url)
+}
 
-    if parts.len() != 2 {
-        anyhow::bail!("Invalid GitHub repository URL: {repo_url}");
+/// The web URL of the GitHub instance serving the REST API at `api_url`.
+///
+/// `api.github.com` is served by `github.com`, and GitHub Enterprise Server serves its API under
+/// `/api/v3` of the web host.
+pub(crate) fn github_web_url(api_url: &str) -> String {
+    let api_url = api_url.trim_end_matches('/');
+    if api_url == GITHUB_API_URL {
+        return format!("https://{GITHUB_HOST}");
     }
-
-    Ok((parts[0].to_string(), parts[1].to_string()))
+    api_url
+        .strip_suffix("/api/v3")
+        .unwrap_or(api_url)
+        .to_string()
 }
 
 #[cfg(test)]
 mod tests {
-    use tempfile::TempDir;
-
     use super::*;
 
     #[test]
-    fn test_parse_github_repo_https() {
-        let (owner, repo) = parse_github_repo("https://github.com/owner/repo").unwrap();
-        assert_eq!(owner, "owner");
-        assert_eq!(repo, "repo");
-    }
-
-    #[test]
-    fn test_parse_github_repo_https_with_git() {
-        let (owner, repo) = parse_github_repo("https://github.com/owner/repo.git").unwrap();
-        assert_eq!(owner, "owner");
-        assert_eq!(repo, "repo");
-    }
-
-    #[test]
-    fn test_parse_github_repo_ssh() {
-        let (owner, repo) = parse_github_repo("git@github.com:owner/repo").unwrap();
-        assert_eq!(owner, "owner");
-        assert_eq!(repo, "repo");
+    fn test_resolve_api_url() {
+        assert_eq!(
+            resolve_api_url("github.com", None, None),
+            "https://api.github.com"
+        );
+        assert_eq!(
+            resolve_api_url("github.example.com", None, None),
+            "https://github.example.com/api/v3"
+        );
+        assert_eq!(
+            resolve_api_url(
+                "github.example.com",
+                None,
+                Some("https://ghe.example.com/api/v3")
+            ),
+            "https://ghe.example.com/api/v3"
+        );
+        assert_eq!(
+            resolve_api_url(
+                "github.example.com",
+                Some("https://api.example.com/"),
+                Some("https://ghe.example.com/api/v3")
+            ),
+            "https://api.example.com"
+        );
     }
 
     #[test]
-    fn test_parse_github_repo_ssh_with_git() {
-        let (owner, repo) = parse_github_repo("git@github.com:owner/repo.git").unwrap();
-        assert_eq!(owner, "owner");
-        assert_eq!(repo, "repo");
+    fn test_graphql_base_url() {
+        assert_eq!(
+            graphql_base_url("https://api.github.com"),
+            "https://api.github.com"
+        );
+        assert_eq!(
+            graphql_base_url("https://github.example.com/api/v3/"),
+            "https://github.example.com/api"
+        );
     }
 
     #[test]
-    fn test_parse_github_repo_invalid() {
-        assert!(parse_github_repo("https://example.com/owner/repo").is_err());
-        assert!(parse_github_repo("https://github.com/owner/repo/other.git").is_err());
-        assert!(parse_github_repo("not-a-url").is_err());
-    }
-
-    #[test]
-    fn test_get_git_remote_url() -> Result<()> {
-        let temp_dir = TempDir::new()?;
-        let repo_path = temp_dir.path();
-
-        Command::new("git")
-            .args(["init"])
-            .current_dir(repo_path)
-            .output()?;
-
-        Command::new("git")
-            .args([
-                "remote",
-                "add",
-                "origin",
-                "https://github.com/user/repo.git",
-            ])
-            .current_dir(repo_path)
-            .output()?;
-
-        let url = get_git_remote_url(repo_path)?;
-        assert_eq!(url, "https://github.com/user/repo.git");
-
-        Ok(())
-    }
-
-    #[test]
-    fn test_get_git_remote_url_no_remote() {
-        let temp_dir = TempDir::new().unwrap();
-        let repo_path = temp_dir.path();
-
-        Command::new("git")
-            .args(["init"])
-            .current_dir(repo_path)
-            .output()
-            .unwrap();
-
-        let result = get_git_remote_url(repo_path);
-        assert!(result.is_err());
-    }
-
-    #[test]
-    fn test_get_git_remote_url_not_a_repo() {
-        let temp_dir = TempDir::new().unwrap();
-
-        let result = get_git_remote_url(temp_dir.path());
-        assert!(result.is_err());
+    fn test_github_web_url() {
+        assert_eq!(
+            github_web_url("https://api.github.com"),
+            "https://github.com"
+        );
+        assert_eq!(
+            github_web_url("https://github.example.com/api/v3/"),
+            "https://github.example.com"
+        );
     }
 }
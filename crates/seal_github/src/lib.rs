@@ -2,10 +2,9 @@ mod github;
 
 mod helpers;
 
-pub use helpers::{get_git_remote_url, parse_github_repo};
+pub use helpers::{github_api_url, github_api_url_from_env};
 
 pub use github::{
-    GitHubClient, GitHubError, GitHubPullRequest, GitHubPullRequestOptions,
-    GitHubPullRequestReference, GitHubRelease, GitHubService, MockGithubClient,
-    filter_prs_by_date_range,
+    Credentials, GitHubClient, GitHubError, GitHubToken, MockGithubClient, RetryConfig, TokenInfo,
+    TokenSource, discover_token, token_env_vars,
 };
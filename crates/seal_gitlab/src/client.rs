@@ -0,0 +1,744 @@
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use hyper::Method;
+use seal_forge::{
+    ForgeService, PullRequest, PullRequestOptions, PullRequestReference, Release, RestClient,
+    RestResponse,
+};
+use serde::Deserialize;
+use thiserror::Error;
+
+const PER_PAGE: &str = "100";
+/// GitLab marks merge requests as drafts with a title prefix.
+const DRAFT_PREFIX: &str = "Draft: ";
+
+#[derive(Debug, Error)]
+pub enum GitLabError {
+    #[error("No releases found for {project}")]
+    NoReleasesFound { project: String },
+    #[error("GitLab authentication is required; set GITLAB_TOKEN")]
+    AuthenticationRequired,
+}
+
+#[derive(Debug)]
+pub struct GitLabClient {
+    rest: RestClient,
+    project: String,
+    /// The project's web page, e.g. `https://gitlab.com/group/project`.
+    web_url: String,
+    authenticated: bool,
+    /// The branch release merge requests target, looked up as the project's default branch
+    /// unless set with [`GitLabClient::with_base`].
+    base: tokio::sync::OnceCell<String>,
+    /// Whether to list the files each merged merge request changes, which takes a request per
+    /// merge request.
+    changed_files: bool,
+}
+
+#[derive(Debug, Deserialize)]
+struct ApiProject {
+    default_branch: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ApiRelease {
+    name: Option<String>,
+    tag_name: String,
+    created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ApiUser {
+    username: String,
+    web_url: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ApiDiff {
+    new_path: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct ApiMergeRequest {
+    iid: u64,
+    title: String,
+    web_url: String,
+    #[serde(default)]
+    labels: Vec<String>,
+    author: Option<ApiUser>,
+    description: Option<String>,
+    merged_at: Option<DateTime<Utc>>,
+}
+
+impl GitLabClient {
+    /// Create a client for the project at `project_path` (e.g. `group/project`) on the GitLab
+    /// instance at `base_url` (e.g. `https://gitlab.com`).
+    pub fn new(base_url: &str, project_path: &str) -> Result<Self> {
+        let token = std::env::var("GITLAB_TOKEN")
+            .ok()
+            .filter(|token| !token.trim().is_empty());
+
+        let api_url = format!("{}/api/v4", base_url.trim_end_matches('/'));
+        Self::with_api_url(&api_url, project_path, token)
+    }
+
+    fn with_api_url(api_url: &str, project_path: &str, token: Option<String>) -> Result<Self> {
+        let mut rest = RestClient::new(api_url)?;
+        let authenticated = token.is_some();
+        if let Some(token) = token {
+            rest = rest.with_header("private-token", &token)?;
+        }
+
+        Ok(Self {
+            rest,
+            // The API addresses projects by their URL-encoded path.
+            project: project_path.replace('/', "%2F"),
+            web_url: {
+                let api_url = api_url.trim_end_matches('/');
+                let base_url = api_url.strip_suffix("/api/v4").unwrap_or(api_url);
+                format!("{base_url}/{project_path}")
+            },
+            authenticated,
+            base: tokio::sync::OnceCell::new(),
+            changed_files: false,
+        })
+    }
+
+    /// Only list merge requests merged into `base`, instead of the project's default branch.
+    #[must_use]
+    pub fn with_base(self, base: Option<String>) -> Self {
+        Self {
+            base: tokio::sync::OnceCell::new_with(base),
+            ..self
+        }
+    }
+
+    /// List the files each merged merge request changes, for changelog areas with `paths`.
+    #[must_use]
+    pub fn with_changed_files(self, changed_files: bool) -> Self {
+        Self {
+            changed_files,
+            ..self
+        }
+    }
+
+    /// The branch whose merged merge requests are listed.
+    async fn base(&self) -> Result<&str> {
+        let base =
+            self.base
+                .get_or_try_init(|| async {
+                    let project: RestResponse<ApiProject> =
+                        self.rest.get(&self.project_path(""), &[]).await.context(
+                            "Failed to look up the default branch of the GitLab project",
+                        )?;
+                    project
+                        .body
+                        .default_branch
+                        .context("GitLab did not report a default branch for the project")
+                })
+                .await?;
+        Ok(base)
+    }
+
+    fn project_path(&self, path: &str) -> String {
+        format!("/projects/{}{path}", self.project)
+    }
+
+    /// Fetch every page of a paginated list, following the `x-next-page` header.
+    async fn get_pages<T: serde::de::DeserializeOwned>(
+        &self,
+        path: &str,
+        query: &[(&str, &str)],
+    ) -> Result<Vec<T>> {
+        let mut items = Vec::new();
+        let mut page = "1".to_string();
+
+        loop {
+            let mut page_query = query.to_vec();
+            page_query.extend([("per_page", PER_PAGE), ("page", page.as_str())]);
+            let response: RestResponse<Vec<T>> = self.rest.get(path, &page_query).await?;
+
+            let next_page = response.header("x-next-page").map(ToString::to_string);
+            items.extend(response.body);
+
+            match next_page {
+                Some(next_page) => page = next_page,
+                None => break,
+            }
+        }
+
+        Ok(items)
+    }
+}
+
+fn to_pull_request(merge_request: ApiMergeRequest) -> Option<PullRequest> {
+    let merged_at = merge_request.merged_at?;
+    Some(PullRequest {
+        title: merge_request.title,
+        number: merge_request.iid,
+        url: merge_request.web_url,
+        labels: merge_request.labels,
+        author_url: merge_request
+            .author
+            .as_ref()
+            .and_then(|author| author.web_url.clone()),
+        author: merge_request.author.map(|author| author.username),
+        body: merge_request
+            .description
+            .filter(|description| !description.trim().is_empty()),
+        closed_issues: Vec::new(),
+        changed_files: Vec::new(),
+        merged_at,
+    })
+}
+
+fn merge_request_title(options: &PullRequestOptions) -> String {
+    if options.draft {
+        format!("{DRAFT_PREFIX}{}", options.title)
+    } else {
+        options.title.clone()
+    }
+}
+
+impl ForgeService for GitLabClient {
+    fn ensure_authenticated(&self) -> Result<()> {
+        if !self.authenticated {
+            return Err(GitLabError::AuthenticationRequired.into());
+        }
+
+        Ok(())
+    }
+
+    fn compare_url(&self, base: &str, head: &str) -> Option<String> {
+        Some(format!("{}/-/compare/{base}...{head}", self.web_url))
+    }
+
+    fn pull_request_url(&self, number: u64) -> Option<String> {
+        Some(format!("{}/-/merge_requests/{number}", self.web_url))
+    }
+
+    fn get_latest_release(
+        &self,
+    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<Release>> + Send + '_>> {
+        Box::pin(async move {
+            let releases: RestResponse<Vec<ApiRelease>> = self
+                .rest
+                .get(&self.project_path("/releases"), &[("per_page", "1")])
+                .await?;
+
+            releases
+                .body
+                .into_iter()
+                .next()
+                .map(|release| Release {
+                    created_at: release.created_at,
+                    name: release.name.or(Some(release.tag_name)),
+                })
+                .ok_or_else(|| {
+                    GitLabError::NoReleasesFound {
+                        project: self.project.replace("%2F", "/"),
+                    }
+                    .into()
+                })
+        })
+    }
+
+    fn get_all_releases(
+        &self,
+    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<Vec<Release>>> + Send + '_>>
+    {
+        Box::pin(async move {
+            let releases: Vec<ApiRelease> =
+                self.get_pages(&self.project_path("/releases"), &[]).await?;
+
+            let mut releases: Vec<_> = releases
+                .into_iter()
+                .map(|release| Release {
+                    created_at: release.created_at,
+                    name: release.name.or(Some(release.tag_name)),
+                })
+                .collect();
+            releases.sort_by_key(|release| release.created_at);
+
+            Ok(releases)
+        })
+    }
+
+    fn get_prs_between(
+        &self,
+        since: Option<&DateTime<Utc>>,
+        until: Option<&DateTime<Utc>>,
+    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<Vec<PullRequest>>> + Send + '_>>
+    {
+        let since = since.copied();
+        let until = until.copied();
+        Box::pin(async move {
+            let base = self.base().await?;
+            let updated_after = since.map(|since| since.to_rfc3339());
+            let mut query = vec![
+                ("state", "merged"),
+                ("target_branch", base),
+                ("order_by", "updated_at"),
+                ("sort", "desc"),
+            ];
+            // A merge request is always updated when it is merged.
+            if let Some(updated_after) = &updated_after {
+                query.push(("updated_after", updated_after.as_str()));
+            }
+
+            let merge_requests: Vec<ApiMergeRequest> = self
+                .get_pages(&self.project_path("/merge_requests"), &query)
+                .await?;
+
+            let mut pull_requests: Vec<_> = merge_requests
+                .into_iter()
+                .filter_map(to_pull_request)
+                .filter(|pr| since.is_none_or(|since| pr.merged_at > since))
+                .filter(|pr| until.is_none_or(|until| pr.merged_at <= until))
+                .collect();
+
+            if self.changed_files {
+                for pr in &mut pull_requests {
+                    let diffs: Vec<ApiDiff> = self
+                        .get_pages(
+                            &self.project_path(&format!("/merge_requests/{}/diffs", pr.number)),
+                            &[],
+                        )
+                        .await
+                        .with_context(|| {
+                            format!(
+                                "Failed to list the files changed by GitLab merge request !{}",
+                                pr.number
+                            )
+                        })?;
+                    pr.changed_files = diffs.into_iter().map(|diff| diff.new_path).collect();
+                }
+            }
+
+            Ok(pull_requests)
+        })
+    }
+
+    fn create_or_update_pull_request(
+        &self,
+        options: PullRequestOptions,
+    ) -> std::pin::Pin<
+        Box<dyn std::future::Future<Output = Result<PullRequestReference>> + Send + '_>,
+    > {
+        Box::pin(async move {
+            self.ensure_authenticated()?;
+
+            let merge_requests_path = self.project_path("/merge_requests");
+            let existing: RestResponse<Vec<ApiMergeRequest>> = self
+                .rest
+                .get(
+                    &merge_requests_path,
+                    &[
+                        ("state", "opened"),
+                        ("source_branch", options.head.as_str()),
+                        ("target_branch", options.base.as_str()),
+                        ("per_page", "1"),
+                    ],
+                )
+                .await
+                .context("Failed to find an existing GitLab merge request")?;
+
+            let title = merge_request_title(&options);
+            let merge_request: ApiMergeRequest =
+                if let Some(existing) = existing.body.into_iter().next() {
+                    self.rest
+                        .send(
+                            Method::PUT,
+                            &format!("{merge_requests_path}/{}", existing.iid),
+                            &serde_json::json!({
+                                "title": title,
+                                "description": options.body,
+                            }),
+                        )
+                        .await
+                        .context("Failed to update GitLab merge request")?
+                } else {
+                    self.rest
+                        .send(
+                            Method::POST,
+                            &merge_requests_path,
+                            &serde_json::json!({
+                                "source_branch": options.head,
+                                "target_branch": options.base,
+                                "title": title,
+                                "description": options.body,
+                            }),
+                        )
+                        .await
+                        .context("Failed to create GitLab merge request")?
+                };
+
+            Ok(PullRequestReference {
+                url: merge_request.web_url,
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use chrono::{DateTime, Utc};
+    use seal_forge::{ForgeService, PullRequestOptions};
+    use serde_json::{Value, json};
+    use wiremock::{
+        Mock, MockServer, ResponseTemplate,
+        matchers::{body_json, header, method, path, query_param},
+    };
+
+    use super::{GitLabClient, GitLabError};
+
+    const PROJECT: &str = "group/project";
+    const PROJECT_API_PATH: &str = "/api/v4/projects/group%2Fproject";
+    const HEAD: &str = "release/v1.2.4";
+    const BASE: &str = "main";
+    const TOKEN: &str = "glpat-test";
+
+    fn test_client(server: &MockServer, authenticated: bool) -> Result<GitLabClient> {
+        Ok(GitLabClient::with_api_url(
+            &format!("{}/api/v4", server.uri()),
+            PROJECT,
+            authenticated.then(|| TOKEN.to_string()),
+        )?
+        .with_base(Some(BASE.to_string())))
+    }
+
+    fn options(draft: bool) -> PullRequestOptions {
+        PullRequestOptions {
+            title: "Release v1.2.4".to_string(),
+            body: "Release notes".to_string(),
+            head: HEAD.to_string(),
+            base: BASE.to_string(),
+            draft,
+        }
+    }
+
+    fn merge_request(iid: u64, merged_at: Option<&str>) -> Value {
+        json!({
+            "iid": iid,
+            "title": format!("Merge request {iid}"),
+            "web_url": format!("https://gitlab.com/{PROJECT}/-/merge_requests/{iid}"),
+            "labels": ["bug"],
+            "author": {
+                "username": "alice",
+                "web_url": "https://gitlab.com/alice",
+            },
+            "merged_at": merged_at,
+        })
+    }
+
+    fn release(name: Option<&str>, tag_name: &str, created_at: &str) -> Value {
+        json!({ "name": name, "tag_name": tag_name, "created_at": created_at })
+    }
+
+    async fn mount_page(
+        server: &MockServer,
+        route: &str,
+        page: u32,
+        next: Option<u32>,
+        body: Value,
+    ) {
+        let mut response = ResponseTemplate::new(200).set_body_json(body);
+        if let Some(next) = next {
+            response = response.insert_header("x-next-page", next.to_string());
+        }
+        Mock::given(method("GET"))
+            .and(path(format!("{PROJECT_API_PATH}{route}")))
+            .and(query_param("per_page", "100"))
+            .and(query_param("page", page.to_string()))
+            .respond_with(response)
+            .expect(1)
+            .mount(server)
+            .await;
+    }
+
+    async fn mount_lookup(server: &MockServer, response: Vec<Value>) {
+        Mock::given(method("GET"))
+            .and(path(format!("{PROJECT_API_PATH}/merge_requests")))
+            .and(query_param("state", "opened"))
+            .and(query_param("source_branch", HEAD))
+            .and(query_param("target_branch", BASE))
+            .and(header("private-token", TOKEN))
+            .respond_with(ResponseTemplate::new(200).set_body_json(response))
+            .expect(1)
+            .mount(server)
+            .await;
+    }
+
+    #[test]
+    fn builds_web_urls() -> Result<()> {
+        let client = GitLabClient::with_api_url("https://gitlab.com/api/v4/", PROJECT, None)?;
+
+        assert_eq!(
+            client.compare_url("v1.0.0", "v1.1.0").as_deref(),
+            Some("https://gitlab.com/group/project/-/compare/v1.0.0...v1.1.0")
+        );
+        assert_eq!(
+            client.pull_request_url(7).as_deref(),
+            Some("https://gitlab.com/group/project/-/merge_requests/7")
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn unauthenticated_write_fails_without_requests() -> Result<()> {
+        let server = MockServer::start().await;
+        let client = test_client(&server, false)?;
+
+        let error = client
+            .create_or_update_pull_request(options(false))
+            .await
+            .expect_err("unauthenticated write should fail");
+
+        assert!(matches!(
+            error.downcast_ref::<GitLabError>(),
+            Some(GitLabError::AuthenticationRequired)
+        ));
+        assert!(server.received_requests().await.unwrap().is_empty());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn gets_all_releases_across_pages() -> Result<()> {
+        let server = MockServer::start().await;
+        mount_page(
+            &server,
+            "/releases",
+            1,
+            Some(2),
+            json!([release(Some("v1.0.0"), "v1.0.0", "2025-12-01T00:00:00Z")]),
+        )
+        .await;
+        mount_page(
+            &server,
+            "/releases",
+            2,
+            None,
+            json!([release(None, "0.2.0", "2025-11-15T00:00:00Z")]),
+        )
+        .await;
+
+        let releases = test_client(&server, true)?.get_all_releases().await?;
+
+        let names: Vec<_> = releases
+            .iter()
+            .map(|release| release.name.as_deref())
+            .collect();
+        assert_eq!(names, [Some("0.2.0"), Some("v1.0.0")]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn latest_release_requires_a_release() -> Result<()> {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path(format!("{PROJECT_API_PATH}/releases")))
+            .and(query_param("per_page", "1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([])))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let error = test_client(&server, true)?
+            .get_latest_release()
+            .await
+            .expect_err("missing release should fail");
+
+        assert_eq!(error.to_string(), "No releases found for group/project");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn gets_merge_requests_merged_in_range() -> Result<()> {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path(format!("{PROJECT_API_PATH}/merge_requests")))
+            .and(query_param("state", "merged"))
+            .and(query_param("target_branch", BASE))
+            .and(query_param("order_by", "updated_at"))
+            .and(query_param("updated_after", "2026-01-01T00:00:00+00:00"))
+            .and(query_param("page", "1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+                merge_request(3, Some("2026-02-10T00:00:00Z")),
+                merge_request(2, Some("2026-01-15T00:00:00Z")),
+                merge_request(4, None),
+                merge_request(1, Some("2025-12-25T00:00:00Z")),
+            ])))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let since = "2026-01-01T00:00:00Z".parse::<DateTime<Utc>>()?;
+        let until = "2026-02-01T00:00:00Z".parse::<DateTime<Utc>>()?;
+        let merge_requests = test_client(&server, true)?
+            .get_prs_between(Some(&since), Some(&until))
+            .await?;
+
+        assert_eq!(merge_requests.len(), 1);
+        let merge_request = &merge_requests[0];
+        assert_eq!(merge_request.number, 2);
+        assert_eq!(merge_request.labels, ["bug"]);
+        assert_eq!(merge_request.author.as_deref(), Some("alice"));
+        assert_eq!(
+            merge_request.author_url.as_deref(),
+            Some("https://gitlab.com/alice")
+        );
+        assert_eq!(
+            merge_request.url,
+            "https://gitlab.com/group/project/-/merge_requests/2"
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn lists_the_files_changed_by_merged_merge_requests() -> Result<()> {
+        let server = MockServer::start().await;
+        mount_page(
+            &server,
+            "/merge_requests",
+            1,
+            None,
+            json!([merge_request(2, Some("2026-01-15T00:00:00Z"))]),
+        )
+        .await;
+        mount_page(
+            &server,
+            "/merge_requests/2/diffs",
+            1,
+            Some(2),
+            json!([{ "old_path": "src/lib.rs", "new_path": "src/lib.rs" }]),
+        )
+        .await;
+        mount_page(
+            &server,
+            "/merge_requests/2/diffs",
+            2,
+            None,
+            json!([{ "old_path": "src/old.rs", "new_path": "src/new.rs" }]),
+        )
+        .await;
+
+        let merge_requests = test_client(&server, true)?
+            .with_changed_files(true)
+            .get_prs_between(None, None)
+            .await?;
+
+        assert_eq!(
+            merge_requests[0].changed_files,
+            ["src/lib.rs", "src/new.rs"]
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn lists_merge_requests_into_the_default_branch_without_a_configured_base() -> Result<()>
+    {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path(PROJECT_API_PATH))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(json!({ "default_branch": "develop" })),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path(format!("{PROJECT_API_PATH}/merge_requests")))
+            .and(query_param("state", "merged"))
+            .and(query_param("target_branch", "develop"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([])))
+            .expect(2)
+            .mount(&server)
+            .await;
+
+        let client = test_client(&server, true)?.with_base(None);
+        assert!(client.get_prs_between(None, None).await?.is_empty());
+        assert!(client.get_prs_between(None, None).await?.is_empty());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn creates_draft_merge_request_when_none_exists() -> Result<()> {
+        let server = MockServer::start().await;
+        mount_lookup(&server, Vec::new()).await;
+        Mock::given(method("POST"))
+            .and(path(format!("{PROJECT_API_PATH}/merge_requests")))
+            .and(body_json(json!({
+                "source_branch": HEAD,
+                "target_branch": BASE,
+                "title": "Draft: Release v1.2.4",
+                "description": "Release notes",
+            })))
+            .respond_with(ResponseTemplate::new(201).set_body_json(merge_request(9, None)))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let merge_request = test_client(&server, true)?
+            .create_or_update_pull_request(options(true))
+            .await?;
+
+        assert_eq!(
+            merge_request.url,
+            "https://gitlab.com/group/project/-/merge_requests/9"
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn updates_existing_merge_request_and_marks_it_ready() -> Result<()> {
+        let server = MockServer::start().await;
+        mount_lookup(&server, vec![merge_request(8, None)]).await;
+        Mock::given(method("PUT"))
+            .and(path(format!("{PROJECT_API_PATH}/merge_requests/8")))
+            .and(body_json(json!({
+                "title": "Release v1.2.4",
+                "description": "Release notes",
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(merge_request(8, None)))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let merge_request = test_client(&server, true)?
+            .create_or_update_pull_request(options(false))
+            .await?;
+
+        assert_eq!(
+            merge_request.url,
+            "https://gitlab.com/group/project/-/merge_requests/8"
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn create_failure_has_stage_specific_context() -> Result<()> {
+        let server = MockServer::start().await;
+        mount_lookup(&server, Vec::new()).await;
+        Mock::given(method("POST"))
+            .and(path(format!("{PROJECT_API_PATH}/merge_requests")))
+            .respond_with(
+                ResponseTemplate::new(409).set_body_json(
+                    json!({ "message": ["Another open merge request already exists"] }),
+                ),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let error = test_client(&server, true)?
+            .create_or_update_pull_request(options(false))
+            .await
+            .expect_err("failed create request should return an error");
+        let message = format!("{error:#}");
+
+        assert!(message.contains("Failed to create GitLab merge request"));
+        assert!(message.contains("Another open merge request already exists"));
+        Ok(())
+    }
+}
@@ -0,0 +1,5 @@
+//! GitLab implementation of [`seal_forge::ForgeService`].
+
+mod client;
+
+pub use client::{GitLabClient, GitLabError};
@@ -8,6 +8,8 @@ use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use crate::ProjectName;
 use crate::error::{ConfigValidationError, ProjectError};
 
+const VERSION_PLACEHOLDER: &str = "{version}";
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, OptionsMetadata)]
 #[serde(rename_all = "kebab-case")]
 pub struct Config {
@@ -30,6 +32,16 @@ pub struct Config {
     /// Changelog configuration for release notes generation.
     #[option_group]
     pub changelog: Option<ChangelogConfig>,
+
+    /// The forge that hosts the repository's releases and pull requests.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[option_group]
+    pub forge: Option<ForgeConfig>,
+
+    /// GitHub settings.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[option_group]
+    pub github: Option<GitHubConfig>,
 }
 
 impl Config {
@@ -52,6 +64,15 @@ impl Config {
         if let Some(release) = &self.release {
             release.validate()?;
         }
+        if let Some(changelog) = &self.changelog {
+            changelog.validate()?;
+        }
+        if let Some(forge) = &self.forge {
+            forge.validate()?;
+        }
+        if let Some(github) = &self.github {
+            github.validate()?;
+        }
         Ok(())
     }
 }
@@ -93,7 +114,7 @@ pub enum VersionFileTextFormat {
     Text,
 }
 
-/// Behavior when a pre-commit command fails.
+/// Behavior when a pre-commit command or hook command fails.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum PreCommitFailure {
@@ -104,6 +125,79 @@ pub enum PreCommitFailure {
     Continue,
 }
 
+/// A command run during the release process.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ReleaseCommand {
+    /// A command line, split into arguments using POSIX shell quoting rules.
+    Simple(String),
+    /// A command with explicit arguments, working directory, environment, or timeout.
+    Detailed(ReleaseCommandOptions),
+}
+
+/// Table form of a [`ReleaseCommand`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub struct ReleaseCommandOptions {
+    /// The command line, or the program to run when `args` is set.
+    pub command: String,
+    /// Arguments passed to `command` verbatim, without any splitting or quoting.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub args: Option<Vec<String>>,
+    /// Run `command` through `sh -c`, enabling pipes, `&&`, and variable assignments.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub shell: bool,
+    /// Working directory, relative to the workspace root.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cwd: Option<PathBuf>,
+    /// Extra environment variables.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub env: BTreeMap<String, String>,
+    /// Maximum number of seconds the command may run before it is killed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timeout: Option<u64>,
+}
+
+impl ReleaseCommand {
+    /// The command line as written in the configuration.
+    pub fn command(&self) -> &str {
+        match self {
+            Self::Simple(command) => command,
+            Self::Detailed(options) => &options.command,
+        }
+    }
+
+    fn validate(&self, field: &str) -> Result<(), ConfigValidationError> {
+        let command = self.command();
+        if command.trim().is_empty() {
+            return Err(ConfigValidationError::EmptyCommand {
+                field: field.to_string(),
+            });
+        }
+
+        let (shell, has_args) = match self {
+            Self::Simple(_) => (false, false),
+            Self::Detailed(options) => (options.shell, options.args.is_some()),
+        };
+
+        if shell && has_args {
+            return Err(ConfigValidationError::ShellCommandWithArgs {
+                field: field.to_string(),
+                command: command.to_string(),
+            });
+        }
+
+        if !shell && !has_args && shlex::split(command).is_none() {
+            return Err(ConfigValidationError::InvalidCommandQuoting {
+                field: field.to_string(),
+                command: command.to_string(),
+            });
+        }
+
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, OptionsMetadata)]
 #[serde(rename_all = "kebab-case", deny_unknown_fields)]
 pub struct ReleaseConfig {
@@ -151,6 +245,19 @@ pub struct ReleaseConfig {
     )]
     pub commit_message: Option<CommitMessage>,
 
+    /// How to sign the release commit: `"gpg"`, `"ssh"`, or `false` to leave it unsigned.
+    ///
+    /// Signing uses the key from Git's `user.signingkey` setting.
+    #[serde(default, skip_serializing_if = "CommitSigning::is_disabled")]
+    #[field(
+        default = "false",
+        value_type = r#""gpg" | "ssh" | false"#,
+        example = r#"
+        sign = "ssh"
+    "#
+    )]
+    pub sign: CommitSigning,
+
     /// The branch name to use when creating a new release branch.
     #[field(
         default = "null",
@@ -171,6 +278,19 @@ pub struct ReleaseConfig {
     )]
     pub push: bool,
 
+    /// The Git remote to push the release branch to and to resolve the GitHub repository from.
+    ///
+    /// Use this when `origin` is a personal fork and another remote is the canonical repository.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[field(
+        default = r#""origin""#,
+        value_type = "string",
+        example = r#"
+        remote = "upstream"
+    "#
+    )]
+    pub remote: Option<String>,
+
     /// Whether to confirm the release changes with the user before proceeding.
     #[serde(default = "default_confirm")]
     #[field(
@@ -181,17 +301,86 @@ pub struct ReleaseConfig {
     )]
     pub confirm: bool,
 
+    /// Whether to release from a working tree with uncommitted changes.
+    ///
+    /// By default, a release that creates a branch or commit is refused when `git status`
+    /// reports changes, so unrelated work is not swept into the release commit.
+    #[serde(default, skip_serializing_if = "is_false")]
+    #[field(
+        default = "false",
+        value_type = "boolean",
+        example = r#"
+        allow-dirty = true"#
+    )]
+    pub allow_dirty: bool,
+
+    /// Branches a release may be started from, as glob patterns.
+    ///
+    /// When unset, a release may be started from any branch.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[field(
+        default = "null",
+        value_type = "list",
+        example = r#"
+        allow-branches = ["main", "release/*"]
+    "#
+    )]
+    pub allow_branches: Option<Vec<String>>,
+
+    /// Whether to continue when the release branch already exists locally or on the remote.
+    #[serde(default, skip_serializing_if = "is_false")]
+    #[field(
+        default = "false",
+        value_type = "boolean",
+        example = r#"
+        allow-existing-branch = true"#
+    )]
+    pub allow_existing_branch: bool,
+
+    /// Whether to continue when the tag for the new version, from `changelog.tag-format`, already
+    /// exists.
+    #[serde(default, skip_serializing_if = "is_false")]
+    #[field(
+        default = "false",
+        value_type = "boolean",
+        example = r#"
+        allow-existing-tag = true"#
+    )]
+    pub allow_existing_tag: bool,
+
+    /// Whether to continue when the current branch is behind its upstream branch.
+    ///
+    /// The upstream is the branch's `branch.<name>.remote` and `branch.<name>.merge`
+    /// configuration, as of the last `git fetch`.
+    #[serde(default, skip_serializing_if = "is_false")]
+    #[field(
+        default = "false",
+        value_type = "boolean",
+        example = r#"
+        allow-behind = true"#
+    )]
+    pub allow_behind: bool,
+
     /// Commands to run before committing. These run after `git add -A` and before `git commit`.
     /// A second `git add -A` is run after these commands to stage any changes they make.
+    ///
+    /// A string is split into arguments using POSIX shell quoting rules. Use the table form to
+    /// run the command through `sh -c` with `shell = true`, to pass explicit `args`, or to set a
+    /// working directory (`cwd`), environment variables (`env`), or a `timeout` in seconds.
     #[serde(skip_serializing_if = "Option::is_none")]
     #[field(
         default = "[]",
         value_type = "list",
         example = r#"
-        pre-commit-commands = ["cargo fmt", "npm run lint:fix"]
+        pre-commit-commands = [
+            "cargo fmt",
+            "npm run build -- --mode 'production build'",
+            { command = "cargo test 2>&1 | tee test.log", shell = true },
+            { command = "npm", args = ["run", "lint:fix"], cwd = "web", env = { CI = "1" }, timeout = 300 },
+        ]
     "#
     )]
-    pub pre_commit_commands: Option<Vec<String>>,
+    pub pre_commit_commands: Option<Vec<ReleaseCommand>>,
 
     /// Behavior when a pre-commit command fails.
     #[serde(default, skip_serializing_if = "is_default_pre_commit_failure")]
@@ -208,6 +397,21 @@ pub struct ReleaseConfig {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[option_group]
     pub pull_request: Option<PullRequestConfig>,
+
+    /// Commands to run at each stage of the release.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[option_group]
+    pub hooks: Option<ReleaseHooksConfig>,
+
+    /// Author of the release commit. Defaults to Git's configured identity.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[option_group]
+    pub author: Option<CommitIdentity>,
+
+    /// Committer of the release commit. Defaults to `author`, then to Git's configured identity.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[option_group]
+    pub committer: Option<CommitIdentity>,
 }
 
 #[expect(clippy::trivially_copy_pass_by_ref)]
@@ -215,6 +419,11 @@ fn is_default_pre_commit_failure(value: &PreCommitFailure) -> bool {
     *value == PreCommitFailure::Abort
 }
 
+#[expect(clippy::trivially_copy_pass_by_ref)]
+fn is_false(value: &bool) -> bool {
+    !*value
+}
+
 fn default_push() -> bool {
     false
 }
@@ -223,6 +432,127 @@ fn default_confirm() -> bool {
     true
 }
 
+/// How the release commit is signed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CommitSigning {
+    /// Leave the commit unsigned.
+    #[default]
+    Disabled,
+    /// Sign with a GPG key.
+    Gpg,
+    /// Sign with an SSH key.
+    Ssh,
+}
+
+impl CommitSigning {
+    pub fn is_disabled(&self) -> bool {
+        *self == Self::Disabled
+    }
+
+    /// The value of Git's `gpg.format` setting for this signing method.
+    pub fn git_format(self) -> Option<&'static str> {
+        match self {
+            Self::Disabled => None,
+            Self::Gpg => Some("openpgp"),
+            Self::Ssh => Some("ssh"),
+        }
+    }
+}
+
+impl fmt::Display for CommitSigning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Disabled => write!(f, "false"),
+            Self::Gpg => write!(f, "gpg"),
+            Self::Ssh => write!(f, "ssh"),
+        }
+    }
+}
+
+impl Serialize for CommitSigning {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Self::Disabled => serializer.serialize_bool(false),
+            Self::Gpg => serializer.serialize_str("gpg"),
+            Self::Ssh => serializer.serialize_str("ssh"),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for CommitSigning {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct Visitor;
+
+        impl serde::de::Visitor<'_> for Visitor {
+            type Value = CommitSigning;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, r#""gpg", "ssh", or false"#)
+            }
+
+            fn visit_bool<E>(self, value: bool) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                if value {
+                    Err(E::invalid_value(serde::de::Unexpected::Bool(true), &self))
+                } else {
+                    Ok(CommitSigning::Disabled)
+                }
+            }
+
+            fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                match value {
+                    "gpg" => Ok(CommitSigning::Gpg),
+                    "ssh" => Ok(CommitSigning::Ssh),
+                    _ => Err(E::invalid_value(serde::de::Unexpected::Str(value), &self)),
+                }
+            }
+        }
+
+        deserializer.deserialize_any(Visitor)
+    }
+}
+
+/// A Git identity used for the release commit.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, OptionsMetadata)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub struct CommitIdentity {
+    /// Name recorded on the commit.
+    #[field(
+        default = "null",
+        value_type = "string",
+        example = r#"name = "release-bot""#
+    )]
+    pub name: String,
+
+    /// Email address recorded on the commit.
+    #[field(
+        default = "null",
+        value_type = "string",
+        example = r#"email = "release-bot@example.com""#
+    )]
+    pub email: String,
+}
+
+impl CommitIdentity {
+    fn validate(&self, field: &'static str) -> Result<(), ConfigValidationError> {
+        if self.name.trim().is_empty() || self.email.trim().is_empty() {
+            return Err(ConfigValidationError::EmptyCommitIdentity { field });
+        }
+        Ok(())
+    }
+}
+
 /// Pull request configuration for release bumps.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, OptionsMetadata, Default)]
 #[serde(rename_all = "kebab-case", deny_unknown_fields)]
@@ -246,6 +576,9 @@ pub struct PullRequestConfig {
     pub body: Option<String>,
 
     /// Base branch for the pull request.
+    ///
+    /// Changelogs only include pull requests merged into this branch, or into the repository's
+    /// default branch when it is not set.
     #[serde(skip_serializing_if = "Option::is_none")]
     #[field(
         default = "branch from which the release branch was created",
@@ -260,7 +593,207 @@ pub struct PullRequestConfig {
     pub draft: bool,
 }
 
+/// A stage of the release process at which hook commands run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ReleaseHook {
+    /// Before the new version is calculated.
+    PreBump,
+    /// After version files and the changelog are updated.
+    PostFiles,
+    /// After changes are staged and before the release commit.
+    PreCommit,
+    /// After the release commit is created.
+    PostCommit,
+    /// After the release branch is pushed.
+    PostPush,
+    /// After the release pull request is created or updated.
+    PostPullRequest,
+}
+
+impl ReleaseHook {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::PreBump => "pre-bump",
+            Self::PostFiles => "post-files",
+            Self::PreCommit => "pre-commit",
+            Self::PostCommit => "post-commit",
+            Self::PostPush => "post-push",
+            Self::PostPullRequest => "post-pull-request",
+        }
+    }
+}
+
+impl fmt::Display for ReleaseHook {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Commands to run at each stage of the release.
+///
+/// Hook commands accept the same forms as `pre-commit-commands` and receive the
+/// `SEAL_VERSION`, `SEAL_PREVIOUS_VERSION`, and, when configured, `SEAL_BRANCH` environment
+/// variables. `pre-bump` commands run before the new version is known, so they only receive
+/// `SEAL_PREVIOUS_VERSION`. `post-pull-request` commands also receive `SEAL_PR_URL`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, OptionsMetadata, Default)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub struct ReleaseHooksConfig {
+    /// Commands to run before the new version is calculated and the changes are previewed, such
+    /// as checks that the release can start. They only receive `SEAL_PREVIOUS_VERSION`, and
+    /// `--dry-run` lists them without running them.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[field(
+        default = "[]",
+        value_type = "list",
+        example = r#"pre-bump = ["git diff --exit-code"]"#
+    )]
+    pub pre_bump: Option<Vec<ReleaseCommand>>,
+
+    /// Commands to run after version files and the changelog are updated.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[field(
+        default = "[]",
+        value_type = "list",
+        example = r#"post-files = ["cargo update --workspace"]"#
+    )]
+    pub post_files: Option<Vec<ReleaseCommand>>,
+
+    /// Commands to run after `pre-commit-commands`, before the release commit. Requires
+    /// `release.commit-message`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[field(
+        default = "[]",
+        value_type = "list",
+        example = r#"pre-commit = ["cargo fmt"]"#
+    )]
+    pub pre_commit: Option<Vec<ReleaseCommand>>,
+
+    /// Commands to run after the release commit is created. Requires `release.commit-message`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[field(
+        default = "[]",
+        value_type = "list",
+        example = r#"post-commit = ["git log -1 --stat"]"#
+    )]
+    pub post_commit: Option<Vec<ReleaseCommand>>,
+
+    /// Commands to run after the release branch is pushed. Requires `release.push = true`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[field(
+        default = "[]",
+        value_type = "list",
+        example = r#"post-push = ["./scripts/trigger-ci.sh"]"#
+    )]
+    pub post_push: Option<Vec<ReleaseCommand>>,
+
+    /// Commands to run after the release pull request is created or updated. Requires
+    /// `[release.pull-request]`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[field(
+        default = "[]",
+        value_type = "list",
+        example = r#"post-pull-request = [{ command = "./scripts/notify.sh \"$SEAL_PR_URL\"", shell = true }]"#
+    )]
+    pub post_pull_request: Option<Vec<ReleaseCommand>>,
+
+    /// Behavior when a hook command fails, per hook. Hooks abort the release by default.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[field(
+        default = "{}",
+        value_type = "dict",
+        example = r#"
+        [release.hooks.on-failure]
+        post-push = "continue"
+        post-pull-request = "continue"
+        "#
+    )]
+    pub on_failure: Option<BTreeMap<ReleaseHook, PreCommitFailure>>,
+}
+
+impl ReleaseHooksConfig {
+    /// The commands configured for `hook`.
+    pub fn commands(&self, hook: ReleaseHook) -> &[ReleaseCommand] {
+        let commands = match hook {
+            ReleaseHook::PreBump => &self.pre_bump,
+            ReleaseHook::PostFiles => &self.post_files,
+            ReleaseHook::PreCommit => &self.pre_commit,
+            ReleaseHook::PostCommit => &self.post_commit,
+            ReleaseHook::PostPush => &self.post_push,
+            ReleaseHook::PostPullRequest => &self.post_pull_request,
+        };
+        commands.as_deref().unwrap_or(&[])
+    }
+
+    /// The behavior when a command in `hook` fails.
+    pub fn on_failure(&self, hook: ReleaseHook) -> PreCommitFailure {
+        self.on_failure
+            .as_ref()
+            .and_then(|on_failure| on_failure.get(&hook))
+            .copied()
+            .unwrap_or_default()
+    }
+
+    fn validate(&self, release: &ReleaseConfig) -> Result<(), ConfigValidationError> {
+        const HOOKS: [ReleaseHook; 6] = [
+            ReleaseHook::PreBump,
+            ReleaseHook::PostFiles,
+            ReleaseHook::PreCommit,
+            ReleaseHook::PostCommit,
+            ReleaseHook::PostPush,
+            ReleaseHook::PostPullRequest,
+        ];
+
+        for hook in HOOKS {
+            let commands = self.commands(hook);
+            if commands.is_empty() {
+                continue;
+            }
+
+            let requirement = match hook {
+                ReleaseHook::PreCommit | ReleaseHook::PostCommit
+                    if release.commit_message.is_none() =>
+                {
+                    Some("release.commit-message")
+                }
+                ReleaseHook::PostPush if !release.push => Some("release.push = true"),
+                ReleaseHook::PostPullRequest if release.pull_request.is_none() => {
+                    Some("release.pull-request")
+                }
+                _ => None,
+            };
+            if let Some(requirement) = requirement {
+                return Err(ConfigValidationError::HookMissingPrerequisite { hook, requirement });
+            }
+
+            let field = format!("hooks.{hook}");
+            for command in commands {
+                command.validate(&field)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// The Git remote used when none is configured.
+pub const DEFAULT_REMOTE: &str = "origin";
+
 impl ReleaseConfig {
+    /// The configured Git remote, or `origin`.
+    pub fn remote(&self) -> &str {
+        self.remote.as_deref().unwrap_or(DEFAULT_REMOTE)
+    }
+
+    /// Whether a release may be started from `branch` according to `allow-branches`.
+    pub fn allows_branch(&self, branch: &str) -> bool {
+        self.allow_branches.as_ref().is_none_or(|patterns| {
+            patterns.iter().any(|pattern| {
+                glob::Pattern::new(pattern).is_ok_and(|pattern| pattern.matches(branch))
+            })
+        })
+    }
+
     fn validate(&self) -> Result<(), ConfigValidationError> {
         if let Some(pull_request) = &self.pull_request {
             if self.commit_message.is_none() || self.branch_name.is_none() || !self.push {
@@ -274,6 +807,45 @@ impl ReleaseConfig {
             return Err(ConfigValidationError::PushRequiresBranchName);
         }
 
+        if self
+            .remote
+            .as_ref()
+            .is_some_and(|remote| remote.trim().is_empty())
+        {
+            return Err(ConfigValidationError::EmptyRemote);
+        }
+
+        for pattern in self.allow_branches.iter().flatten() {
+            if let Err(err) = glob::Pattern::new(pattern) {
+                return Err(ConfigValidationError::InvalidBranchPattern {
+                    pattern: pattern.clone(),
+                    reason: err.msg.to_string(),
+                });
+            }
+        }
+
+        for command in self.pre_commit_commands.iter().flatten() {
+            command.validate("pre-commit-commands")?;
+        }
+
+        if let Some(hooks) = &self.hooks {
+            hooks.validate(self)?;
+        }
+
+        if self.commit_message.is_none()
+            && (!self.sign.is_disabled() || self.author.is_some() || self.committer.is_some())
+        {
+            return Err(ConfigValidationError::CommitSettingsRequireCommitMessage);
+        }
+
+        if let Some(author) = &self.author {
+            author.validate("author")?;
+        }
+
+        if let Some(committer) = &self.committer {
+            committer.validate("committer")?;
+        }
+
         Ok(())
     }
 }
@@ -434,13 +1006,50 @@ pub struct ChangelogConfig {
     )]
     pub section_labels: Option<BTreeMap<String, Vec<String>>>,
 
+    /// The order of the sections in each release, by name. Sections that are not listed follow
+    /// in alphabetical order.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[field(
+        default = "[]",
+        value_type = "list",
+        example = r#"
+        section-order = ["Breaking changes", "Enhancements", "Bug fixes"]
+        "#
+    )]
+    pub section_order: Option<Vec<String>>,
+
+    /// Areas of the project to group each release's sections by, in order.
+    ///
+    /// A pull request belongs to the first area that has one of its labels, the scope of its
+    /// conventional commit title (such as `cli` in `fix(cli): ...`), or a file it changed matching
+    /// one of its `paths`. An area without `labels`, `scopes`, or `paths` matches every pull
+    /// request. Pull requests that match no area are grouped under `Other`, last. Changed files
+    /// are read from GitHub, and require a token.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[field(
+        default = "[]",
+        value_type = "list",
+        example = r#"
+        [[changelog.areas]]
+        name = "CLI"
+        labels = ["area:cli"]
+        scopes = ["cli"]
+        paths = ["crates/cli/**"]
+        "#
+    )]
+    pub areas: Option<Vec<ChangelogArea>>,
+
     /// Template for the changelog heading. Must contain {version} placeholder.
+    ///
+    /// May also use `{date}` (the release date, as `YYYY-MM-DD`), `{previous_version}`, `{tag}`
+    /// (the new version's tag), and `{compare_url}` (the forge's comparison of the previous and
+    /// new tags). The last two are empty for the first release.
     #[serde(skip_serializing_if = "Option::is_none")]
     #[field(
         default = r#""{version}""#,
         value_type = "string",
         example = r#"
-        changelog-heading = "{version}"
+        changelog-heading = "[{version}] - {date}"
         "#
     )]
     pub changelog_heading: Option<ChangelogHeading>,
@@ -466,6 +1075,201 @@ pub struct ChangelogConfig {
         "#
     )]
     pub changelog_path: Option<PathBuf>,
+
+    /// Directory of changelog fragments, relative to the workspace root.
+    ///
+    /// When set, `seal bump` builds the new section from fragment files named
+    /// `<pull request number>.<type>.md` instead of merged pull requests, and deletes them. Each
+    /// fragment's type is matched against `section-labels` like a pull request label, and its
+    /// contents become the entry's text.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[field(
+        default = "null",
+        value_type = "string",
+        example = r#"
+        fragments-dir = "changes"
+        "#
+    )]
+    pub fragments_dir: Option<PathBuf>,
+
+    /// A [MiniJinja](https://docs.rs/minijinja) template for each changelog section, given inline
+    /// or as `{ path = "..." }` relative to the workspace root.
+    ///
+    /// The template can use `version`, `previous_version`, `tag`, `heading`, `date`,
+    /// `compare_url`, `sections` (each with a `name` and `prs`), and `contributors` (each with a
+    /// `login` and `url`). Each pull request has a `title`, `number`, `url`, `author`,
+    /// `author_url`, `labels`, `body`, `closed_issues`, `notes`, and `breaking`. With
+    /// `release-notes`, `breaking_changes` lists the breaking changes. Defaults to the built-in
+    /// layout.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[field(
+        default = "null",
+        value_type = "string | { path = string }",
+        example = r#"
+        template = { path = ".github/changelog.md.jinja" }
+        "#
+    )]
+    pub template: Option<ChangelogTemplate>,
+
+    /// The name of each release's tag. Must contain {version} placeholder.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[field(
+        default = r#""v{version}""#,
+        value_type = "string",
+        example = r#"
+        tag-format = "release-{version}"
+        "#
+    )]
+    pub tag_format: Option<String>,
+
+    /// Whether to add a `[version]: compare-url` link reference definition to the bottom of the
+    /// changelog for each new section, as used by Keep a Changelog headings like
+    /// `[{version}] - {date}`.
+    #[serde(default, skip_serializing_if = "is_false")]
+    #[field(
+        default = "false",
+        value_type = "boolean",
+        example = r#"
+        compare-links = true
+        "#
+    )]
+    pub compare_links: bool,
+
+    /// Whether to link the issues each pull request closed in its entry, e.g.
+    /// `- Fix crash (#12, closes #10)`.
+    ///
+    /// Closed issues are read from GitHub, and require a token.
+    #[serde(default, skip_serializing_if = "is_false")]
+    #[field(
+        default = "false",
+        value_type = "boolean",
+        example = r#"
+        link-closed-issues = true
+        "#
+    )]
+    pub link_closed_issues: bool,
+
+    /// Release the hand-written `## [Unreleased]` section instead of inserting a generated one.
+    /// The table itself enables this mode.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[option_group]
+    pub unreleased: Option<UnreleasedConfig>,
+
+    /// Show release notes from pull request descriptions beneath their entries. The table itself
+    /// enables this.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[option_group]
+    pub release_notes: Option<ReleaseNotesConfig>,
+}
+
+/// An area of the project that groups changelog sections.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub struct ChangelogArea {
+    /// The area's heading.
+    pub name: String,
+    /// Labels of the area's pull requests.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub labels: Vec<String>,
+    /// Conventional commit scopes of the area's pull request titles.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub scopes: Vec<String>,
+    /// Glob patterns for the files the area's pull requests change.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub paths: Vec<String>,
+}
+
+/// Configuration for releasing a curated `## [Unreleased]` changelog section.
+///
+/// `seal bump` renames the section to the new version's `changelog-heading` and adds an empty
+/// `Unreleased` section above it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, OptionsMetadata, Default)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub struct UnreleasedConfig {
+    /// Whether to add the generated pull request entries to the curated notes, under matching
+    /// `###` subsections. Entries that are already present are not repeated.
+    #[serde(default, skip_serializing_if = "is_false")]
+    #[field(
+        default = "false",
+        value_type = "boolean",
+        example = "merge-generated = true"
+    )]
+    pub merge_generated: bool,
+
+    /// Whether to fail when the `Unreleased` section has no release notes.
+    #[serde(default, skip_serializing_if = "is_false")]
+    #[field(
+        default = "false",
+        value_type = "boolean",
+        example = "require-notes = true"
+    )]
+    pub require_notes: bool,
+}
+
+/// Configuration for release notes written in pull request descriptions.
+///
+/// The notes are the contents of a fenced code block whose info string is `fence`, or else the
+/// text under a `Release notes` heading, or else the text after `BREAKING CHANGE:`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, OptionsMetadata, Default)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub struct ReleaseNotesConfig {
+    /// The info string of the fenced code block that holds a pull request's release notes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[field(
+        default = r#""release-notes""#,
+        value_type = "string",
+        example = r#"
+        fence = "changelog"
+        "#
+    )]
+    pub fence: Option<String>,
+
+    /// Labels that mark a pull request as a breaking change, in addition to a `BREAKING CHANGE:`
+    /// note in its description.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[field(
+        default = "[]",
+        value_type = "list",
+        example = r#"
+        breaking-labels = ["breaking"]
+        "#
+    )]
+    pub breaking_labels: Option<Vec<String>>,
+
+    /// Whether to list breaking changes and their notes under a `### Breaking changes` heading at
+    /// the top of each release, instead of showing the notes beneath their entries.
+    #[serde(default, skip_serializing_if = "is_false")]
+    #[field(
+        default = "false",
+        value_type = "boolean",
+        example = "breaking-preamble = true"
+    )]
+    pub breaking_preamble: bool,
+
+    /// Labels of pull requests whose descriptions should not be read for release notes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[field(
+        default = "[]",
+        value_type = "list",
+        example = r#"
+        ignore-labels = ["dependencies"]
+        "#
+    )]
+    pub ignore_labels: Option<Vec<String>>,
+}
+
+impl ReleaseNotesConfig {
+    pub fn fence(&self) -> &str {
+        self.fence.as_deref().unwrap_or("release-notes")
+    }
+
+    pub fn breaking_labels(&self) -> &[String] {
+        self.breaking_labels.as_deref().unwrap_or(&[])
+    }
+
+    pub fn ignore_labels(&self) -> &[String] {
+        self.ignore_labels.as_deref().unwrap_or(&[])
+    }
 }
 
 impl ChangelogConfig {
@@ -478,18 +1282,213 @@ impl ChangelogConfig {
         self.section_labels.as_ref().unwrap_or(&EMPTY)
     }
 
-    pub fn changelog_heading(&self) -> &str {
-        self.changelog_heading
-            .as_ref()
-            .map(ChangelogHeading::as_str)
-            .unwrap_or("{version}")
+    pub fn section_order(&self) -> &[String] {
+        self.section_order.as_deref().unwrap_or(&[])
+    }
+
+    pub fn areas(&self) -> &[ChangelogArea] {
+        self.areas.as_deref().unwrap_or(&[])
+    }
+
+    pub fn changelog_heading(&self) -> &str {
+        self.changelog_heading
+            .as_ref()
+            .map(ChangelogHeading::as_str)
+            .unwrap_or("{version}")
+    }
+
+    pub fn include_contributors(&self) -> bool {
+        self.include_contributors.unwrap_or(true)
+    }
+
+    pub fn tag_format(&self) -> &str {
+        self.tag_format.as_deref().unwrap_or("v{version}")
+    }
+
+    /// The tag for `version`.
+    pub fn tag(&self, version: &str) -> String {
+        self.tag_format().replace(VERSION_PLACEHOLDER, version)
+    }
+
+    fn validate(&self) -> Result<(), ConfigValidationError> {
+        if let Some(tag_format) = &self.tag_format {
+            if !tag_format.contains("{version}") {
+                return Err(ConfigValidationError::TagFormatMissingVersionPlaceholder {
+                    value: tag_format.clone(),
+                });
+            }
+        }
+        for area in self.areas() {
+            if area.name.trim().is_empty() {
+                return Err(ConfigValidationError::EmptyAreaName);
+            }
+            for pattern in &area.paths {
+                if let Err(err) = glob::Pattern::new(pattern) {
+                    return Err(ConfigValidationError::InvalidAreaPathPattern {
+                        pattern: pattern.clone(),
+                        reason: err.msg.to_string(),
+                    });
+                }
+            }
+        }
+        if self
+            .release_notes
+            .as_ref()
+            .is_some_and(|release_notes| release_notes.fence().trim().is_empty())
+        {
+            return Err(ConfigValidationError::EmptyReleaseNotesFence);
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, OptionsMetadata, Default)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub struct ForgeConfig {
+    /// The kind of forge. Detected from the release remote's host when unset.
+    ///
+    /// `github.com` is detected as GitHub, `gitlab.com` or any `gitlab.*` host as GitLab, and
+    /// `codeberg.org` or any `gitea.*` or `forgejo.*` host as Gitea.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[field(
+        default = "null",
+        value_type = r#""github" | "gitlab" | "gitea""#,
+        example = r#"
+        kind = "gitea"
+        "#
+    )]
+    pub kind: Option<ForgeKind>,
+
+    /// The base URL of a GitLab or Gitea instance. Defaults to the scheme, host, and port of an
+    /// HTTP release remote, or to `https://` followed by the host of an SSH remote.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[field(
+        default = "null",
+        value_type = "string",
+        example = r#"
+        url = "https://git.example.com/forgejo"
+        "#
+    )]
+    pub url: Option<String>,
+}
+
+impl ForgeConfig {
+    fn validate(&self) -> Result<(), ConfigValidationError> {
+        validate_url("forge.url", self.url.as_deref())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, OptionsMetadata, Default)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub struct GitHubConfig {
+    /// The GitHub REST API URL, for GitHub Enterprise Server.
+    ///
+    /// Defaults to the `GITHUB_API_URL` environment variable, then to `https://api.github.com`
+    /// for `github.com` remotes and `https://<host>/api/v3` for other hosts. GraphQL requests are
+    /// sent to the matching GraphQL endpoint.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[field(
+        default = "null",
+        value_type = "string",
+        example = r#"
+        api-url = "https://github.example.com/api/v3"
+        "#
+    )]
+    pub api_url: Option<String>,
+
+    /// The longest time, in seconds, to wait before retrying a GitHub API request.
+    ///
+    /// Rate-limited requests are retried once the rate limit resets, and reads that fail with a
+    /// server error are retried with exponential backoff. Seal gives up instead of waiting longer
+    /// than this. Defaults to 60.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[field(
+        default = "60",
+        value_type = "integer",
+        example = r#"
+        max-retry-wait = 300
+        "#
+    )]
+    pub max_retry_wait: Option<u64>,
+
+    /// A command that prints a GitHub token, such as a password manager lookup.
+    ///
+    /// Used when the token environment variables for the host are not set (`GITHUB_TOKEN` and
+    /// `GH_TOKEN` for `github.com`, or `GH_ENTERPRISE_TOKEN` and `GITHUB_ENTERPRISE_TOKEN` for GitHub
+    /// Enterprise Server), before the GitHub CLI's login and Git credential helpers. The command
+    /// line is split into arguments like a shell would, but is not run through a shell. Seal fails if the command fails or prints nothing.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[field(
+        default = "null",
+        value_type = "string",
+        example = r#"
+        token-command = "op read op://Private/GitHub/token"
+        "#
+    )]
+    pub token_command: Option<String>,
+}
+
+impl GitHubConfig {
+    fn validate(&self) -> Result<(), ConfigValidationError> {
+        validate_url("github.api-url", self.api_url.as_deref())?;
+
+        if self
+            .token_command
+            .as_deref()
+            .is_some_and(|command| command.trim().is_empty())
+        {
+            return Err(ConfigValidationError::EmptyTokenCommand);
+        }
+
+        Ok(())
+    }
+}
+
+fn validate_url(field: &'static str, url: Option<&str>) -> Result<(), ConfigValidationError> {
+    if let Some(url) = url {
+        if !(url.starts_with("https://") || url.starts_with("http://")) {
+            return Err(ConfigValidationError::InvalidUrl {
+                field,
+                value: url.to_string(),
+            });
+        }
     }
+    Ok(())
+}
 
-    pub fn include_contributors(&self) -> bool {
-        self.include_contributors.unwrap_or(true)
+/// A supported forge.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ForgeKind {
+    GitHub,
+    GitLab,
+    /// Gitea, or its fork Forgejo.
+    Gitea,
+}
+
+impl fmt::Display for ForgeKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::GitHub => write!(f, "GitHub"),
+            Self::GitLab => write!(f, "GitLab"),
+            Self::Gitea => write!(f, "Gitea"),
+        }
     }
 }
 
+/// The template used to render changelog sections.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ChangelogTemplate {
+    /// The template source itself.
+    Inline(String),
+    /// A file containing the template.
+    File {
+        /// Path to the template, relative to the workspace root.
+        path: PathBuf,
+    },
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 #[repr(transparent)]
 pub struct ChangelogHeading(String);
@@ -543,7 +1542,7 @@ impl<'de> Deserialize<'de> for ChangelogHeading {
 
 #[cfg(test)]
 mod tests {
-    use insta::{assert_debug_snapshot, assert_json_snapshot};
+    use insta::{assert_debug_snapshot, assert_json_snapshot, assert_snapshot};
 
     use super::*;
 
@@ -693,7 +1692,7 @@ unknown-field = "value"
         assert_debug_snapshot!(err, @r#"
         ConfigParseError(
             Error {
-                message: "unknown field `unknown-field`, expected one of `current-version`, `version-files`, `commit-message`, `branch-name`, `push`, `confirm`, `pre-commit-commands`, `on-pre-commit-failure`, `pull-request`",
+                message: "unknown field `unknown-field`, expected one of `current-version`, `version-files`, `commit-message`, `sign`, `branch-name`, `push`, `remote`, `confirm`, `allow-dirty`, `allow-branches`, `allow-existing-branch`, `allow-existing-tag`, `allow-behind`, `pre-commit-commands`, `on-pre-commit-failure`, `pull-request`, `hooks`, `author`, `committer`",
                 input: Some(
                     "\n[release]\nunknown-field = \"value\"\n",
                 ),
@@ -928,14 +1927,26 @@ branch-name = ""
                 current_version: "1.2.3".to_string(),
                 version_files: Some(vec![VersionFile::Simple("Cargo.toml".to_string())]),
                 commit_message: Some(CommitMessage::new("Release v{version}".to_string()).unwrap()),
+                sign: CommitSigning::Disabled,
                 branch_name: Some(BranchName::new("release/v{version}".to_string()).unwrap()),
                 push: true,
+                remote: None,
                 confirm: true,
+                allow_dirty: false,
+                allow_branches: None,
+                allow_existing_branch: false,
+                allow_existing_tag: false,
+                allow_behind: false,
                 pre_commit_commands: None,
                 on_pre_commit_failure: PreCommitFailure::default(),
                 pull_request: None,
+                hooks: None,
+                author: None,
+                committer: None,
             }),
             changelog: None,
+            forge: None,
+            github: None,
         };
 
         let toml_str = toml::to_string(&config).unwrap();
@@ -981,15 +1992,27 @@ commit-message = "Release {version} with {version} tag"
                             "Release {version} with {version} tag",
                         ),
                     ),
+                    sign: Disabled,
                     branch_name: None,
                     push: false,
+                    remote: None,
                     confirm: true,
+                    allow_dirty: false,
+                    allow_branches: None,
+                    allow_existing_branch: false,
+                    allow_existing_tag: false,
+                    allow_behind: false,
                     pre_commit_commands: None,
                     on_pre_commit_failure: Abort,
                     pull_request: None,
+                    hooks: None,
+                    author: None,
+                    committer: None,
                 },
             ),
             changelog: None,
+            forge: None,
+            github: None,
         }
         "#);
     }
@@ -1052,15 +2075,27 @@ version-files = ["Cargo.toml", "package.json", "VERSION"]
                         ],
                     ),
                     commit_message: None,
+                    sign: Disabled,
                     branch_name: None,
                     push: false,
+                    remote: None,
                     confirm: true,
+                    allow_dirty: false,
+                    allow_branches: None,
+                    allow_existing_branch: false,
+                    allow_existing_tag: false,
+                    allow_behind: false,
                     pre_commit_commands: None,
                     on_pre_commit_failure: Abort,
                     pull_request: None,
+                    hooks: None,
+                    author: None,
+                    committer: None,
                 },
             ),
             changelog: None,
+            forge: None,
+            github: None,
         }
         "#);
     }
@@ -1084,15 +2119,27 @@ version-files = []
                         [],
                     ),
                     commit_message: None,
+                    sign: Disabled,
                     branch_name: None,
                     push: false,
+                    remote: None,
                     confirm: true,
+                    allow_dirty: false,
+                    allow_branches: None,
+                    allow_existing_branch: false,
+                    allow_existing_tag: false,
+                    allow_behind: false,
                     pre_commit_commands: None,
                     on_pre_commit_failure: Abort,
                     pull_request: None,
+                    hooks: None,
+                    author: None,
+                    committer: None,
                 },
             ),
             changelog: None,
+            forge: None,
+            github: None,
         }
         "#);
     }
@@ -1185,6 +2232,29 @@ push = true
         "#);
     }
 
+    #[test]
+    fn test_validation_remote() {
+        let toml = r#"
+[release]
+current-version = "1.0.0"
+remote = "upstream"
+"#;
+        let release = Config::from_toml_str(toml).unwrap().release.unwrap();
+        assert_eq!(release.remote(), "upstream");
+
+        let toml = r#"
+[release]
+current-version = "1.0.0"
+remote = " "
+"#;
+        let result = Config::from_toml_str(toml);
+        assert_debug_snapshot!(result.unwrap_err(), @r"
+        InvalidConfigurationFile(
+            EmptyRemote,
+        )
+        ");
+    }
+
     #[test]
     fn test_validation_valid_with_branch_and_push() {
         let toml = r#"
@@ -1291,6 +2361,299 @@ base = "   "
         "#);
     }
 
+    #[test]
+    fn test_validation_allow_branches() {
+        let toml = r#"
+[release]
+current-version = "1.0.0"
+allow-branches = ["main", "release/*"]
+"#;
+        let release = Config::from_toml_str(toml).unwrap().release.unwrap();
+        assert!(release.allows_branch("main"));
+        assert!(release.allows_branch("release/1.x"));
+        assert!(!release.allows_branch("feature/login"));
+
+        let toml = r#"
+[release]
+current-version = "1.0.0"
+allow-branches = ["release/[1"]
+"#;
+        let error = Config::from_toml_str(toml).unwrap_err();
+        assert_snapshot!(error.to_string(), @"Invalid configuration file: release.allow-branches contains an invalid pattern 'release/[1': invalid range pattern");
+    }
+
+    #[test]
+    fn test_parse_commit_signing_and_identity() {
+        let toml = r#"
+[release]
+current-version = "1.0.0"
+commit-message = "Release {version}"
+sign = "ssh"
+
+[release.author]
+name = "release-bot"
+email = "release-bot@example.com"
+"#;
+        let release = Config::from_toml_str(toml).unwrap().release.unwrap();
+        assert_eq!(release.sign, CommitSigning::Ssh);
+        assert_eq!(release.author.unwrap().name, "release-bot");
+        assert_eq!(release.committer, None);
+
+        let signs = [r#""gpg""#, "false", "true", r#""x509""#].map(|sign| {
+            let toml = format!(
+                "[release]\ncurrent-version = \"1.0.0\"\ncommit-message = \"Release {{version}}\"\nsign = {sign}\n"
+            );
+            Config::from_toml_str(&toml)
+                .map(|config| config.release.unwrap().sign.to_string())
+                .map_err(|err| err.to_string())
+        });
+        assert_debug_snapshot!(signs, @r#"
+        [
+            Ok(
+                "gpg",
+            ),
+            Ok(
+                "false",
+            ),
+            Err(
+                "TOML parse error at line 4, column 8\n  |\n4 | sign = true\n  |        ^^^^\ninvalid value: boolean `true`, expected \"gpg\", \"ssh\", or false\n",
+            ),
+            Err(
+                "TOML parse error at line 4, column 8\n  |\n4 | sign = \"x509\"\n  |        ^^^^^^\ninvalid value: string \"x509\", expected \"gpg\", \"ssh\", or false\n",
+            ),
+        ]
+        "#);
+    }
+
+    #[test]
+    fn test_validation_commit_settings() {
+        let configs = [
+            r#"
+[release]
+current-version = "1.0.0"
+sign = "gpg"
+"#,
+            r#"
+[release]
+current-version = "1.0.0"
+commit-message = "Release {version}"
+
+[release.committer]
+name = "release-bot"
+email = ""
+"#,
+        ];
+
+        let errors = configs.map(Config::from_toml_str);
+        assert_debug_snapshot!(errors, @r#"
+        [
+            Err(
+                InvalidConfigurationFile(
+                    CommitSettingsRequireCommitMessage,
+                ),
+            ),
+            Err(
+                InvalidConfigurationFile(
+                    EmptyCommitIdentity {
+                        field: "committer",
+                    },
+                ),
+            ),
+        ]
+        "#);
+    }
+
+    #[test]
+    fn test_parse_pre_commit_command_forms() {
+        let toml = r#"
+[release]
+current-version = "1.0.0"
+pre-commit-commands = [
+    "npm run build -- --mode 'production build'",
+    { command = "cargo test | tee test.log", shell = true },
+    { command = "npm", args = ["run", "lint:fix"], cwd = "web", env = { CI = "1" }, timeout = 30 },
+]
+"#;
+
+        let config = Config::from_toml_str(toml).unwrap();
+        assert_debug_snapshot!(config.release.unwrap().pre_commit_commands, @r#"
+        Some(
+            [
+                Simple(
+                    "npm run build -- --mode 'production build'",
+                ),
+                Detailed(
+                    ReleaseCommandOptions {
+                        command: "cargo test | tee test.log",
+                        args: None,
+                        shell: true,
+                        cwd: None,
+                        env: {},
+                        timeout: None,
+                    },
+                ),
+                Detailed(
+                    ReleaseCommandOptions {
+                        command: "npm",
+                        args: Some(
+                            [
+                                "run",
+                                "lint:fix",
+                            ],
+                        ),
+                        shell: false,
+                        cwd: Some(
+                            "web",
+                        ),
+                        env: {
+                            "CI": "1",
+                        },
+                        timeout: Some(
+                            30,
+                        ),
+                    },
+                ),
+            ],
+        )
+        "#);
+    }
+
+    #[test]
+    fn test_validation_pre_commit_commands() {
+        let configs = [
+            r#"
+[release]
+current-version = "1.0.0"
+pre-commit-commands = ["  "]
+"#,
+            r#"
+[release]
+current-version = "1.0.0"
+pre-commit-commands = ["echo 'unterminated"]
+"#,
+            r#"
+[release]
+current-version = "1.0.0"
+pre-commit-commands = [{ command = "echo", args = ["hi"], shell = true }]
+"#,
+        ];
+
+        let errors = configs.map(Config::from_toml_str);
+        assert_debug_snapshot!(errors, @r#"
+        [
+            Err(
+                InvalidConfigurationFile(
+                    EmptyCommand {
+                        field: "pre-commit-commands",
+                    },
+                ),
+            ),
+            Err(
+                InvalidConfigurationFile(
+                    InvalidCommandQuoting {
+                        field: "pre-commit-commands",
+                        command: "echo 'unterminated",
+                    },
+                ),
+            ),
+            Err(
+                InvalidConfigurationFile(
+                    ShellCommandWithArgs {
+                        field: "pre-commit-commands",
+                        command: "echo",
+                    },
+                ),
+            ),
+        ]
+        "#);
+    }
+
+    #[test]
+    fn test_parse_release_hooks() {
+        let toml = r#"
+[release]
+current-version = "1.0.0"
+commit-message = "Release {version}"
+
+[release.hooks]
+pre-bump = ["cargo test"]
+post-commit = [{ command = "echo committed", shell = true }]
+on-failure = { post-commit = "continue" }
+"#;
+
+        let config = Config::from_toml_str(toml).unwrap();
+        let hooks = config.release.unwrap().hooks.unwrap();
+        assert_eq!(hooks.commands(ReleaseHook::PreBump).len(), 1);
+        assert_eq!(hooks.commands(ReleaseHook::PostCommit).len(), 1);
+        assert!(hooks.commands(ReleaseHook::PostPush).is_empty());
+        assert_eq!(
+            hooks.on_failure(ReleaseHook::PostCommit),
+            PreCommitFailure::Continue
+        );
+        assert_eq!(
+            hooks.on_failure(ReleaseHook::PreBump),
+            PreCommitFailure::Abort
+        );
+    }
+
+    #[test]
+    fn test_validation_release_hooks() {
+        let configs = [
+            r#"
+[release]
+current-version = "1.0.0"
+
+[release.hooks]
+pre-commit = ["cargo fmt"]
+"#,
+            r#"
+[release]
+current-version = "1.0.0"
+commit-message = "Release {version}"
+branch-name = "release/{version}"
+
+[release.hooks]
+post-push = ["echo pushed"]
+"#,
+            r#"
+[release]
+current-version = "1.0.0"
+
+[release.hooks]
+post-files = [""]
+"#,
+        ];
+
+        let errors = configs.map(Config::from_toml_str);
+        assert_debug_snapshot!(errors, @r#"
+        [
+            Err(
+                InvalidConfigurationFile(
+                    HookMissingPrerequisite {
+                        hook: PreCommit,
+                        requirement: "release.commit-message",
+                    },
+                ),
+            ),
+            Err(
+                InvalidConfigurationFile(
+                    HookMissingPrerequisite {
+                        hook: PostPush,
+                        requirement: "release.push = true",
+                    },
+                ),
+            ),
+            Err(
+                InvalidConfigurationFile(
+                    EmptyCommand {
+                        field: "hooks.post-files",
+                    },
+                ),
+            ),
+        ]
+        "#);
+    }
+
     #[test]
     fn test_validate_changelog_config() {
         let toml = r#"
@@ -1332,4 +2695,187 @@ changelog-heading = "Release {version}"
         }
         "#);
     }
+
+    #[test]
+    fn test_changelog_tag_format() {
+        let config = Config::from_toml_str(
+            r#"
+[changelog]
+tag-format = "release-{version}"
+compare-links = true
+"#,
+        )
+        .unwrap();
+        let changelog = config.changelog.unwrap();
+        assert_eq!(changelog.tag("1.2.0"), "release-1.2.0");
+        assert!(changelog.compare_links);
+
+        assert_eq!(ChangelogConfig::default().tag("1.2.0"), "v1.2.0");
+
+        let result = Config::from_toml_str(
+            r#"
+[changelog]
+tag-format = "latest"
+"#,
+        );
+        assert_snapshot!(
+            result.unwrap_err(),
+            @"Invalid configuration file: changelog.tag-format must contain '{version}' placeholder, got: 'latest'"
+        );
+    }
+
+    #[test]
+    fn test_changelog_release_notes() {
+        let config = Config::from_toml_str(
+            r#"
+[changelog.release-notes]
+breaking-labels = ["breaking"]
+breaking-preamble = true
+"#,
+        )
+        .unwrap();
+        let release_notes = config.changelog.unwrap().release_notes.unwrap();
+        assert_eq!(release_notes.fence(), "release-notes");
+        assert_eq!(release_notes.breaking_labels(), ["breaking"]);
+        assert!(release_notes.breaking_preamble);
+        assert!(release_notes.ignore_labels().is_empty());
+
+        let result = Config::from_toml_str(
+            r#"
+[changelog.release-notes]
+fence = " "
+"#,
+        );
+        assert_snapshot!(
+            result.unwrap_err(),
+            @"Invalid configuration file: changelog.release-notes.fence cannot be empty"
+        );
+    }
+
+    #[test]
+    fn test_changelog_areas() {
+        let config = Config::from_toml_str(
+            r#"
+[changelog]
+section-order = ["Enhancements", "Bug fixes"]
+
+[[changelog.areas]]
+name = "CLI"
+labels = ["area:cli"]
+scopes = ["cli"]
+
+[[changelog.areas]]
+name = "Docs"
+paths = ["docs/**"]
+"#,
+        )
+        .unwrap();
+        let changelog = config.changelog.unwrap();
+        assert_eq!(changelog.section_order(), ["Enhancements", "Bug fixes"]);
+        assert_eq!(
+            changelog.areas(),
+            [
+                ChangelogArea {
+                    name: "CLI".to_string(),
+                    labels: vec!["area:cli".to_string()],
+                    scopes: vec!["cli".to_string()],
+                    paths: Vec::new(),
+                },
+                ChangelogArea {
+                    name: "Docs".to_string(),
+                    labels: Vec::new(),
+                    scopes: Vec::new(),
+                    paths: vec!["docs/**".to_string()],
+                },
+            ]
+        );
+
+        let result = Config::from_toml_str(
+            r#"
+[[changelog.areas]]
+name = "Docs"
+paths = ["docs/[**"]
+"#,
+        );
+        assert_snapshot!(
+            result.unwrap_err(),
+            @"Invalid configuration file: changelog.areas contains an invalid path pattern 'docs/[**': invalid range pattern"
+        );
+    }
+
+    #[test]
+    fn test_parse_changelog_template() {
+        let inline = Config::from_toml_str(
+            r#"
+[changelog]
+template = "Release {{ version }}\n"
+"#,
+        )
+        .unwrap();
+        assert_eq!(
+            inline.changelog.unwrap().template,
+            Some(ChangelogTemplate::Inline(
+                "Release {{ version }}\n".to_string()
+            ))
+        );
+
+        let file = Config::from_toml_str(
+            r#"
+[changelog]
+template = { path = "changelog.md.jinja" }
+"#,
+        )
+        .unwrap();
+        assert_eq!(
+            file.changelog.unwrap().template,
+            Some(ChangelogTemplate::File {
+                path: PathBuf::from("changelog.md.jinja"),
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_forge_config() {
+        let toml = r#"
+[forge]
+kind = "gitea"
+url = "https://git.example.com/"
+"#;
+        let forge = Config::from_toml_str(toml).unwrap().forge.unwrap();
+        assert_eq!(forge.kind, Some(ForgeKind::Gitea));
+        assert_eq!(forge.url.as_deref(), Some("https://git.example.com/"));
+
+        let error = Config::from_toml_str("[forge]\nurl = \"git.example.com\"\n").unwrap_err();
+        assert_snapshot!(error, @"Invalid configuration file: forge.url must start with 'https://' or 'http://', got: 'git.example.com'");
+
+        let toml = r#"
+[github]
+api-url = "https://github.example.com/api/v3"
+max-retry-wait = 300
+token-command = "gh auth token"
+"#;
+        let github = Config::from_toml_str(toml).unwrap().github.unwrap();
+        assert_eq!(
+            github.api_url.as_deref(),
+            Some("https://github.example.com/api/v3")
+        );
+        assert_eq!(github.max_retry_wait, Some(300));
+        assert_eq!(github.token_command.as_deref(), Some("gh auth token"));
+
+        let error = Config::from_toml_str("[github]\ntoken-command = \" \"\n").unwrap_err();
+        assert_snapshot!(error, @"Invalid configuration file: github.token-command cannot be empty");
+
+        let error =
+            Config::from_toml_str("[github]\napi-url = \"github.example.com\"\n").unwrap_err();
+        assert_snapshot!(error, @"Invalid configuration file: github.api-url must start with 'https://' or 'http://', got: 'github.example.com'");
+
+        let error = Config::from_toml_str("[forge]\nkind = \"bitbucket\"\n").unwrap_err();
+        assert_snapshot!(error, @r#"
+        TOML parse error at line 2, column 8
+          |
+        2 | kind = "bitbucket"
+          |        ^^^^^^^^^^^
+        unknown variant `bitbucket`, expected one of `github`, `gitlab`, `gitea`
+        "#);
+    }
 }
@@ -2,6 +2,8 @@ use std::path::PathBuf;
 
 use thiserror::Error;
 
+use crate::ReleaseHook;
+
 #[derive(Error, Debug)]
 pub enum ProjectError {
     #[error("Invalid configuration file: {0}")]
@@ -19,12 +21,6 @@ pub enum ProjectError {
     #[error(transparent)]
     ConfigParseError(#[from] toml::de::Error),
 
-    #[error("Not in a git repository: {path}")]
-    NotInGitRepository { path: PathBuf },
-
-    #[error("Git command '{command}' failed: {stderr}")]
-    GitCommandFailed { command: String, stderr: String },
-
     #[error("Workspace member '{member}' is missing seal.toml at path: {path}")]
     MemberMissingSealToml { member: String, path: PathBuf },
 
@@ -60,6 +56,9 @@ pub enum ConfigValidationError {
     )]
     InvalidProjectName { name: String },
 
+    #[error("release.remote cannot be empty")]
+    EmptyRemote,
+
     #[error("release.push = true requires branch-name to be set")]
     PushRequiresBranchName,
 
@@ -74,11 +73,53 @@ pub enum ConfigValidationError {
     #[error("release.pull-request.base cannot be empty")]
     EmptyPullRequestBase,
 
+    #[error("release.allow-branches contains an invalid pattern '{pattern}': {reason}")]
+    InvalidBranchPattern { pattern: String, reason: String },
+
+    #[error("release.{field} cannot contain empty commands")]
+    EmptyCommand { field: String },
+
+    #[error("release.{field} entry `{command}` cannot set both `shell = true` and `args`")]
+    ShellCommandWithArgs { field: String, command: String },
+
+    #[error("release.{field} entry `{command}` has unbalanced quotes or a trailing escape")]
+    InvalidCommandQuoting { field: String, command: String },
+
+    #[error("release.hooks.{hook} requires {requirement}")]
+    HookMissingPrerequisite {
+        hook: ReleaseHook,
+        requirement: &'static str,
+    },
+
+    #[error("release.sign, release.author, and release.committer require release.commit-message")]
+    CommitSettingsRequireCommitMessage,
+
+    #[error("release.{field} requires a non-empty name and email")]
+    EmptyCommitIdentity { field: &'static str },
+
     #[error("release.changelog.changelog-heading cannot be empty")]
     EmptyChangelogHeading,
 
     #[error("release.changelog.changelog-heading cannot start with '#', got: '{value}'")]
     ChangelogHeadingStartsWithHash { value: String },
+
+    #[error("changelog.tag-format must contain '{{version}}' placeholder, got: '{value}'")]
+    TagFormatMissingVersionPlaceholder { value: String },
+
+    #[error("{field} must start with 'https://' or 'http://', got: '{value}'")]
+    InvalidUrl { field: &'static str, value: String },
+
+    #[error("github.token-command cannot be empty")]
+    EmptyTokenCommand,
+
+    #[error("changelog.release-notes.fence cannot be empty")]
+    EmptyReleaseNotesFence,
+
+    #[error("changelog.areas cannot contain an area with an empty name")]
+    EmptyAreaName,
+
+    #[error("changelog.areas contains an invalid path pattern '{pattern}': {reason}")]
+    InvalidAreaPathPattern { pattern: String, reason: String },
 }
 
 #[cfg(test)]
@@ -88,18 +129,18 @@ mod tests {
 
     #[test]
     fn test_project_error_display() {
-        let err = ProjectError::NotInGitRepository {
+        let err = ProjectError::NoParentDirectory {
             path: PathBuf::from("/tmp/test"),
         };
-        assert_snapshot!(err.to_string(), @"Not in a git repository: /tmp/test");
+        assert_snapshot!(err.to_string(), @"No parent directory found for path: /tmp/test");
 
-        let err = ProjectError::GitCommandFailed {
-            command: "git status".to_string(),
-            stderr: "fatal: not a git repository".to_string(),
+        let err = ProjectError::MemberPathNotFound {
+            member: "api".to_string(),
+            path: PathBuf::from("/tmp/test/api"),
         };
         assert_snapshot!(
             err.to_string(),
-            @"Git command 'git status' failed: fatal: not a git repository"
+            @"Workspace member 'api' path does not exist: /tmp/test/api"
         );
     }
 
@@ -1,16 +1,17 @@
 mod config;
 mod error;
-mod git;
 mod project;
 mod project_name;
 mod workspace_member;
 
 pub use config::{
-    BranchName, ChangelogConfig, ChangelogHeading, CommitMessage, Config, PreCommitFailure,
-    PullRequestConfig, ReleaseConfig, VersionFile, VersionFileTextFormat,
+    BranchName, ChangelogArea, ChangelogConfig, ChangelogHeading, ChangelogTemplate,
+    CommitIdentity, CommitMessage, CommitSigning, Config, DEFAULT_REMOTE, ForgeConfig, ForgeKind,
+    GitHubConfig, PreCommitFailure, PullRequestConfig, ReleaseCommand, ReleaseCommandOptions,
+    ReleaseConfig, ReleaseHook, ReleaseHooksConfig, ReleaseNotesConfig, UnreleasedConfig,
+    VersionFile, VersionFileTextFormat,
 };
 pub use error::{ConfigValidationError, ProjectError};
-pub use git::{find_git_root, get_current_branch};
 pub use project::ProjectWorkspace;
 pub use project_name::ProjectName;
 pub use workspace_member::WorkspaceMember;